@@ -7,7 +7,12 @@ use ats_smart_contract::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1};
 #[allow(deprecated)]
 use ats_smart_contract::bid_order::{BidOrderV2, BidOrderV3};
 use ats_smart_contract::contract_info::ContractInfoV3;
-use ats_smart_contract::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use ats_smart_contract::fill_log::FillV1;
+use ats_smart_contract::msg::{
+    AccountFeeTierResponse, AccountLimitsResponse, AccruedFeesResponse, ConfigHistoryResponse,
+    ExecuteMsg, FillsResponse, InstantiateMsg, ListAsksResponse, ListBidsResponse,
+    OrderBookDepthResponse, PendingChangesResponse, PriceBandResponse, QueryMsg,
+};
 use ats_smart_contract::version_info::VersionInfoV1;
 
 #[allow(deprecated)]
@@ -23,8 +28,19 @@ fn main() {
     export_schema(&schema_for!(BidOrderV2), &out_dir);
     export_schema(&schema_for!(BidOrderV3), &out_dir);
     export_schema(&schema_for!(ContractInfoV3), &out_dir);
+    export_schema(&schema_for!(FillV1), &out_dir);
     export_schema(&schema_for!(VersionInfoV1), &out_dir);
     export_schema(&schema_for!(ExecuteMsg), &out_dir);
     export_schema(&schema_for!(InstantiateMsg), &out_dir);
     export_schema(&schema_for!(QueryMsg), &out_dir);
+    export_schema(&schema_for!(ListAsksResponse), &out_dir);
+    export_schema(&schema_for!(ListBidsResponse), &out_dir);
+    export_schema(&schema_for!(OrderBookDepthResponse), &out_dir);
+    export_schema(&schema_for!(FillsResponse), &out_dir);
+    export_schema(&schema_for!(AccountFeeTierResponse), &out_dir);
+    export_schema(&schema_for!(AccountLimitsResponse), &out_dir);
+    export_schema(&schema_for!(AccruedFeesResponse), &out_dir);
+    export_schema(&schema_for!(PendingChangesResponse), &out_dir);
+    export_schema(&schema_for!(PriceBandResponse), &out_dir);
+    export_schema(&schema_for!(ConfigHistoryResponse), &out_dir);
 }
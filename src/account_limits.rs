@@ -0,0 +1,164 @@
+use crate::ask_order::ASKS_V1;
+use crate::bid_order::BIDS_V3;
+use crate::common::AccountLimits;
+use crate::error::ContractError;
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Uint128};
+
+/// Open orders of an account and what they hold in escrow
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccountUsage {
+    pub open_orders: u32,
+    /// Base of open asks and quote and fee of open bids, per denom
+    pub escrow: Vec<Coin>,
+}
+
+impl AccountUsage {
+    fn add_escrow(&mut self, amount: Uint128, denom: &str) {
+        match self.escrow.iter_mut().find(|coin| coin.denom.eq(denom)) {
+            Some(coin) => coin.amount += amount,
+            None => self.escrow.push(Coin {
+                denom: denom.to_owned(),
+                amount,
+            }),
+        }
+    }
+
+    /// Returns the amount the account's open orders hold in escrow in the denom
+    pub fn get_escrow(&self, denom: &str) -> Uint128 {
+        self.escrow
+            .iter()
+            .find(|coin| coin.denom.eq(denom))
+            .map_or(Uint128::zero(), |coin| coin.amount)
+    }
+}
+
+/// Sums up the open orders of an account from the owner indexes of the order books.
+pub fn get_account_usage(storage: &dyn Storage, owner: &Addr) -> StdResult<AccountUsage> {
+    let mut usage = AccountUsage::default();
+
+    let asks =
+        ASKS_V1
+            .idx
+            .owner
+            .prefix(owner.to_owned())
+            .range(storage, None, None, Order::Ascending);
+    for item in asks {
+        let (_, ask_order) = item?;
+        usage.open_orders += 1;
+        usage.add_escrow(ask_order.size, &ask_order.base);
+    }
+
+    let bids =
+        BIDS_V3
+            .idx
+            .owner
+            .prefix(owner.to_owned())
+            .range(storage, None, None, Order::Ascending);
+    for item in bids {
+        let (_, bid_order) = item?;
+        usage.open_orders += 1;
+        usage.add_escrow(
+            bid_order.get_remaining_quote() + bid_order.get_remaining_fee(),
+            &bid_order.quote.denom,
+        );
+    }
+
+    Ok(usage)
+}
+
+/// Checks that an order escrowing the additional amount keeps its owner within their account
+/// limits, naming the exceeded limit. Amending a resting order doesn't open another order.
+pub fn check_account_limits(
+    storage: &dyn Storage,
+    limits: &AccountLimits,
+    owner: &Addr,
+    opens_order: bool,
+    escrow: &Coin,
+) -> Result<(), ContractError> {
+    let max_escrow = limits
+        .max_escrow
+        .iter()
+        .find(|coin| coin.denom.eq(&escrow.denom));
+
+    let max_open_orders = match opens_order {
+        true => limits.max_open_orders,
+        false => None,
+    };
+
+    if max_open_orders.is_none() && max_escrow.is_none() {
+        return Ok(());
+    }
+
+    let usage = get_account_usage(storage, owner)?;
+
+    if let Some(max_open_orders) = max_open_orders {
+        if usage.open_orders >= max_open_orders {
+            return Err(ContractError::AccountLimitExceeded {
+                limit: String::from("max_open_orders"),
+            });
+        }
+    }
+
+    if let Some(max_escrow) = max_escrow {
+        let total_escrow = usage
+            .get_escrow(&escrow.denom)
+            .checked_add(escrow.amount)
+            .map_err(|_| ContractError::TotalOverflow)?;
+        if total_escrow.gt(&max_escrow.amount) {
+            return Err(ContractError::AccountLimitExceeded {
+                limit: String::from("max_escrow"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_account_limits;
+    use crate::common::AccountLimits;
+    use crate::error::ContractError;
+    use cosmwasm_std::{coin, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn check_account_limits_without_open_orders() {
+        let deps = mock_provenance_dependencies();
+        let limits = AccountLimits {
+            max_open_orders: Some(0),
+            max_escrow: vec![coin(100, "quote_1")],
+        };
+        let owner = Addr::unchecked("bidder");
+
+        match check_account_limits(&deps.storage, &limits, &owner, true, &coin(100, "quote_1")) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::AccountLimitExceeded { limit }) => {
+                assert_eq!(limit, "max_open_orders")
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let limits = AccountLimits {
+            max_open_orders: None,
+            ..limits
+        };
+        assert!(
+            check_account_limits(&deps.storage, &limits, &owner, true, &coin(100, "quote_1"))
+                .is_ok()
+        );
+        assert!(
+            check_account_limits(&deps.storage, &limits, &owner, true, &coin(101, "quote_1"))
+                .is_err()
+        );
+        // denoms without a limit aren't checked
+        assert!(check_account_limits(
+            &deps.storage,
+            &limits,
+            &owner,
+            true,
+            &coin(Uint128::MAX.u128(), "quote_2")
+        )
+        .is_ok());
+    }
+}
@@ -1,16 +1,75 @@
-use crate::contract_info::require_version;
+use crate::common::{next_order_sequence, FeeTerms, TimeInForce};
+use crate::contract_info::{get_contract_info, require_version};
 use crate::error::ContractError;
 use crate::msg::MigrateMsg;
+use crate::util::to_price_key;
 use crate::version_info::get_version_info;
-use cosmwasm_std::{Addr, Coin, DepsMut, Uint128};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Coin, DepsMut, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use rust_decimal::Decimal;
 use schemars::JsonSchema;
-use semver::Version;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 pub const NAMESPACE_ORDER_ASK: &str = "ask";
+pub const NAMESPACE_ORDER_ASK_OWNER: &str = "ask__owner";
+pub const NAMESPACE_ORDER_ASK_PRICE: &str = "ask__price";
+pub const NAMESPACE_ORDER_ASK_EXPIRATION: &str = "ask__expiration";
 
-pub const ASKS_V1: Map<&[u8], AskOrderV1> = Map::new(NAMESPACE_ORDER_ASK);
+pub const ASKS_V1: IndexedMap<&[u8], AskOrderV1, AskOrderIndexes> = IndexedMap::new(
+    NAMESPACE_ORDER_ASK,
+    AskOrderIndexes {
+        owner: MultiIndex::new(
+            ask_order_owner_index,
+            NAMESPACE_ORDER_ASK,
+            NAMESPACE_ORDER_ASK_OWNER,
+        ),
+        price: MultiIndex::new(
+            ask_order_price_index,
+            NAMESPACE_ORDER_ASK,
+            NAMESPACE_ORDER_ASK_PRICE,
+        ),
+        expiration: MultiIndex::new(
+            ask_order_expiration_index,
+            NAMESPACE_ORDER_ASK,
+            NAMESPACE_ORDER_ASK_EXPIRATION,
+        ),
+    },
+);
+
+/// Secondary indexes maintained alongside `ASKS_V1`.
+pub struct AskOrderIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, AskOrderV1, &'a [u8]>,
+    /// Asks by quote denom, ascending price (see `to_price_key`) and ascending sequence.
+    pub price: MultiIndex<'a, (String, Vec<u8>), AskOrderV1, &'a [u8]>,
+    /// Asks by expiration time in nanoseconds, asks without an expiration are keyed last.
+    pub expiration: MultiIndex<'a, u64, AskOrderV1, &'a [u8]>,
+}
+
+impl<'a> IndexList<AskOrderV1> for AskOrderIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<AskOrderV1>> + '_> {
+        let v: Vec<&dyn Index<AskOrderV1>> = vec![&self.owner, &self.price, &self.expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+fn ask_order_owner_index(_pk: &[u8], ask_order: &AskOrderV1) -> Addr {
+    ask_order.owner.to_owned()
+}
+
+fn ask_order_price_index(_pk: &[u8], ask_order: &AskOrderV1) -> (String, Vec<u8>) {
+    let mut priority_key = to_price_key(Decimal::from_str(&ask_order.price).unwrap_or_default());
+    priority_key.extend_from_slice(&ask_order.sequence.to_be_bytes());
+
+    (ask_order.quote.to_owned(), priority_key)
+}
+
+fn ask_order_expiration_index(_pk: &[u8], ask_order: &AskOrderV1) -> u64 {
+    ask_order
+        .expires_at
+        .map_or(u64::MAX, |expires_at| expires_at.nanos())
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub enum AskOrderStatus {
@@ -36,6 +95,34 @@ pub struct AskOrderV1 {
     pub quote: String,
     pub price: String,
     pub size: Uint128,
+    /// Creation order of the ask, used for time priority when matching
+    #[serde(default)]
+    pub sequence: u64,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Time from which the ask can no longer be matched and may be expired by anyone
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+    /// Fee terms in effect when the ask was created, set for existing asks on migration
+    #[serde(default)]
+    pub fee_terms: Option<FeeTerms>,
+}
+
+impl AskOrderV1 {
+    /// Returns false for convertible asks still waiting on issuer approval, which cannot be matched
+    pub fn is_ready(&self) -> bool {
+        !matches!(
+            self.class,
+            AskOrderClass::Convertible {
+                status: AskOrderStatus::PendingIssuerApproval
+            }
+        )
+    }
+
+    /// Returns true once the ask has passed its expiration time
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= time)
+    }
 }
 
 pub fn migrate_ask_orders(deps: DepsMut, _msg: &MigrateMsg) -> Result<(), ContractError> {
@@ -46,6 +133,73 @@ pub fn migrate_ask_orders(deps: DepsMut, _msg: &MigrateMsg) -> Result<(), Contra
     // The last version of ask order (`AskOrderV1`) was introduced in 0.15.0:
     require_version(">=0.15.0", &current_version)?;
 
+    // Order sequences were introduced in 1.1.0, the creation order of existing asks wasn't
+    // recorded, so they are sequenced by id ahead of the existing bids
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        set_missing_ask_sequences(store)?;
+    }
+
+    // Secondary indexes were introduced in 1.1.0, build them for existing orders
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        rebuild_ask_order_indexes(store)?;
+    }
+
+    // Order fee terms were introduced in 1.1.0, fees could not change while there were open
+    // orders before, so existing asks were created with the current ask fee terms
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        set_missing_ask_fee_terms(store)?;
+    }
+
+    Ok(())
+}
+
+/// Gives every stored ask order without a sequence the next order sequence, in id order.
+pub fn set_missing_ask_sequences(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let ask_orders = ASKS_V1
+        .range(store, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, ask_order)) if ask_order.sequence > 0))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, mut ask_order) in ask_orders {
+        ask_order.sequence = next_order_sequence(store)?;
+        ASKS_V1.save(store, &id, &ask_order)?;
+    }
+
+    Ok(())
+}
+
+/// Sets the current ask fee terms on every stored ask order that has none.
+pub fn set_missing_ask_fee_terms(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let ask_orders = ASKS_V1
+        .range(store, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, ask_order)) if ask_order.fee_terms.is_some()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if ask_orders.is_empty() {
+        return Ok(());
+    }
+
+    let fee_terms = get_contract_info(store)?.ask_fee_terms();
+    for (id, mut ask_order) in ask_orders {
+        ask_order.fee_terms = Some(fee_terms.to_owned());
+        ASKS_V1.save(store, &id, &ask_order)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the secondary index entries of every stored ask order.
+pub fn rebuild_ask_order_indexes(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let ask_orders = ASKS_V1
+        .range(store, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, ask_order) in ask_orders {
+        for index in ASKS_V1.idx.get_indexes() {
+            index.save(store, &id, &ask_order)?;
+        }
+    }
+
     Ok(())
 }
 
@@ -53,9 +207,14 @@ pub fn migrate_ask_orders(deps: DepsMut, _msg: &MigrateMsg) -> Result<(), Contra
 mod tests {
     #[allow(deprecated)]
     use super::migrate_ask_orders;
+    use super::{AskOrderClass, AskOrderV1, ASKS_V1, NAMESPACE_ORDER_ASK};
+    use crate::common::FeeTerms;
     use crate::error::ContractError;
     use crate::msg::MigrateMsg;
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
     use crate::version_info::{set_version_info, VersionInfoV1, CRATE_NAME};
+    use cosmwasm_std::{Addr, Order, Uint128};
+    use cw_storage_plus::Map;
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -82,6 +241,7 @@ mod tests {
                 bid_fee_account: None,
                 ask_required_attributes: None,
                 bid_required_attributes: None,
+                ..Default::default()
             },
         );
 
@@ -126,6 +286,7 @@ mod tests {
                 bid_fee_account: None,
                 ask_required_attributes: None,
                 bid_required_attributes: None,
+                ..Default::default()
             },
         );
 
@@ -133,4 +294,83 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    pub fn ask_migration_builds_owner_index() -> Result<(), ContractError> {
+        // Setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        set_version_info(
+            &mut deps.storage,
+            &VersionInfoV1 {
+                definition: CRATE_NAME.to_string(),
+                version: "1.0.0".to_string(), // version before secondary indexes
+            },
+        )?;
+
+        // Store an ask order the way it was stored before secondary indexes
+        let ask_order = AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: "ask-1".into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(200),
+            ..Default::default()
+        };
+        Map::<&[u8], AskOrderV1>::new(NAMESPACE_ORDER_ASK).save(
+            &mut deps.storage,
+            ask_order.id.as_bytes(),
+            &ask_order,
+        )?;
+
+        // Not yet indexed
+        assert!(ASKS_V1
+            .idx
+            .owner
+            .prefix(Addr::unchecked("asker"))
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .next()
+            .is_none());
+
+        migrate_ask_orders(
+            deps.as_mut(),
+            &MigrateMsg {
+                approvers: None,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                ..Default::default()
+            },
+        )?;
+
+        // Indexed, sequenced, with the current fee terms set, after migration
+        let indexed_asks = ASKS_V1
+            .idx
+            .owner
+            .prefix(Addr::unchecked("asker"))
+            .range(&deps.storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            indexed_asks,
+            vec![(
+                b"ask-1".to_vec(),
+                AskOrderV1 {
+                    sequence: 1,
+                    fee_terms: Some(FeeTerms {
+                        fee_info: None,
+                        maker_taker_fee_info: None,
+                    }),
+                    ..ask_order
+                }
+            )]
+        );
+
+        Ok(())
+    }
 }
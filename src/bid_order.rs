@@ -1,20 +1,78 @@
-use crate::common::{Action, Event};
-use crate::contract_info::require_version;
+use crate::common::{next_order_sequence, Action, Event, FeeTerms, TimeInForce};
+use crate::contract_info::{get_contract_info, require_version};
 use crate::error::ContractError;
 use crate::msg::MigrateMsg;
+use crate::util::to_descending_price_key;
 use crate::version_info::get_version_info;
-use cosmwasm_std::{Addr, Coin, DepsMut, Env, Order, Response, Uint128};
-use cw_storage_plus::Map;
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, Order, Response, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, MultiIndex};
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::{Decimal, RoundingStrategy};
 use schemars::JsonSchema;
 use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 pub const NAMESPACE_ORDER_BID: &str = "bid";
+pub const NAMESPACE_ORDER_BID_OWNER: &str = "bid__owner";
+pub const NAMESPACE_ORDER_BID_PRICE: &str = "bid__price";
+pub const NAMESPACE_ORDER_BID_EXPIRATION: &str = "bid__expiration";
 
 pub const BIDS_V2: Map<&[u8], BidOrderV2> = Map::new(NAMESPACE_ORDER_BID);
-pub const BIDS_V3: Map<&[u8], BidOrderV3> = Map::new(NAMESPACE_ORDER_BID);
+pub const BIDS_V3: IndexedMap<&[u8], BidOrderV3, BidOrderIndexes> = IndexedMap::new(
+    NAMESPACE_ORDER_BID,
+    BidOrderIndexes {
+        owner: MultiIndex::new(
+            bid_order_owner_index,
+            NAMESPACE_ORDER_BID,
+            NAMESPACE_ORDER_BID_OWNER,
+        ),
+        price: MultiIndex::new(
+            bid_order_price_index,
+            NAMESPACE_ORDER_BID,
+            NAMESPACE_ORDER_BID_PRICE,
+        ),
+        expiration: MultiIndex::new(
+            bid_order_expiration_index,
+            NAMESPACE_ORDER_BID,
+            NAMESPACE_ORDER_BID_EXPIRATION,
+        ),
+    },
+);
+
+/// Secondary indexes maintained alongside `BIDS_V3`.
+pub struct BidOrderIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, BidOrderV3, &'a [u8]>,
+    /// Bids by quote denom, descending price (see `to_descending_price_key`) and ascending sequence.
+    pub price: MultiIndex<'a, (String, Vec<u8>), BidOrderV3, &'a [u8]>,
+    /// Bids by expiration time in nanoseconds, bids without an expiration are keyed last.
+    pub expiration: MultiIndex<'a, u64, BidOrderV3, &'a [u8]>,
+}
+
+impl<'a> IndexList<BidOrderV3> for BidOrderIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<BidOrderV3>> + '_> {
+        let v: Vec<&dyn Index<BidOrderV3>> = vec![&self.owner, &self.price, &self.expiration];
+        Box::new(v.into_iter())
+    }
+}
+
+fn bid_order_owner_index(_pk: &[u8], bid_order: &BidOrderV3) -> Addr {
+    bid_order.owner.to_owned()
+}
+
+fn bid_order_price_index(_pk: &[u8], bid_order: &BidOrderV3) -> (String, Vec<u8>) {
+    let mut priority_key =
+        to_descending_price_key(Decimal::from_str(&bid_order.price).unwrap_or_default());
+    priority_key.extend_from_slice(&bid_order.sequence.to_be_bytes());
+
+    (bid_order.quote.denom.to_owned(), priority_key)
+}
+
+fn bid_order_expiration_index(_pk: &[u8], bid_order: &BidOrderV3) -> u64 {
+    bid_order
+        .expires_at
+        .map_or(u64::MAX, |expires_at| expires_at.nanos())
+}
 
 #[deprecated(since = "0.18.2")]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -84,6 +142,18 @@ pub struct BidOrderV3 {
     pub owner: Addr,
     pub price: String,
     pub quote: Coin,
+    /// Creation order of the bid, used for time priority when matching
+    #[serde(default)]
+    pub sequence: u64,
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Time from which the bid can no longer be matched and may be expired by anyone
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+    /// Fee terms in effect when the bid was created or last modified, set for existing bids on
+    /// migration
+    #[serde(default)]
+    pub fee_terms: Option<FeeTerms>,
 }
 
 #[allow(deprecated)]
@@ -99,11 +169,20 @@ impl From<BidOrderV2> for BidOrderV3 {
             owner: old_bid.owner,
             price: old_bid.price,
             quote: old_bid.quote,
+            sequence: 0,
+            time_in_force: TimeInForce::GoodTilCancelled,
+            expires_at: None,
+            fee_terms: None,
         }
     }
 }
 
 impl BidOrderV3 {
+    /// Returns true once the bid has passed its expiration time
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        matches!(self.expires_at, Some(expires_at) if expires_at <= time)
+    }
+
     pub fn calculate_fee(&self, gross_proceeds: Uint128) -> Result<Option<Coin>, ContractError> {
         match &self.fee {
             Some(bid_order_fee) => {
@@ -119,11 +198,11 @@ impl BidOrderV3 {
                     .to_u128()
                     .ok_or(ContractError::TotalOverflow)?;
 
-                // the bid fee due is the difference between the expected remaining fee and the current remaining fee
+                // the bid fee due is the difference between the expected remaining fee and the current
+                // remaining fee, none is due if earlier fills were charged a minimum fee above it
                 let bid_fee = self
                     .get_remaining_fee()
-                    .checked_sub(Uint128::new(expected_remaining_fee))
-                    .map_err(|_| ContractError::BidOrderFeeInsufficientFunds)?;
+                    .saturating_sub(Uint128::new(expected_remaining_fee));
 
                 let bid_fee = Coin {
                     denom: bid_order_fee.denom.to_owned(),
@@ -241,24 +320,95 @@ pub fn migrate_bid_orders(
             let bid_order_v2: BidOrderV2 = BIDS_V2.load(store, &existing_bid_order_v2_id)?;
             let bid_order_v3: BidOrderV3 = bid_order_v2.into();
 
-            BIDS_V3.save(store, &existing_bid_order_v2_id, &bid_order_v3)?
+            // the stored value is still a `BidOrderV2`, so there is no previous `BidOrderV3` to unindex
+            BIDS_V3.replace(store, &existing_bid_order_v2_id, Some(&bid_order_v3), None)?
         }
     }
 
+    // Order sequences were introduced in 1.1.0, the creation order of existing bids wasn't
+    // recorded, so they are sequenced by id after the existing asks
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        set_missing_bid_sequences(store)?;
+    }
+
+    // Secondary indexes were introduced in 1.1.0, build them for existing orders
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        rebuild_bid_order_indexes(store)?;
+    }
+
+    // Order fee terms were introduced in 1.1.0, fees could not change while there were open
+    // orders before, so existing bids were created with the current bid fee terms
+    if VersionReq::parse("<1.1.0")?.matches(&current_version) {
+        set_missing_bid_fee_terms(store)?;
+    }
+
     Ok(response)
 }
 
+/// Gives every stored bid order without a sequence the next order sequence, in id order.
+pub fn set_missing_bid_sequences(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let bid_orders = BIDS_V3
+        .range(store, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, bid_order)) if bid_order.sequence > 0))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, mut bid_order) in bid_orders {
+        bid_order.sequence = next_order_sequence(store)?;
+        BIDS_V3.save(store, &id, &bid_order)?;
+    }
+
+    Ok(())
+}
+
+/// Sets the current bid fee terms on every stored bid order that has none.
+pub fn set_missing_bid_fee_terms(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let bid_orders = BIDS_V3
+        .range(store, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, bid_order)) if bid_order.fee_terms.is_some()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if bid_orders.is_empty() {
+        return Ok(());
+    }
+
+    let fee_terms = get_contract_info(store)?.bid_fee_terms();
+    for (id, mut bid_order) in bid_orders {
+        bid_order.fee_terms = Some(fee_terms.to_owned());
+        BIDS_V3.save(store, &id, &bid_order)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the secondary index entries of every stored bid order.
+pub fn rebuild_bid_order_indexes(store: &mut dyn Storage) -> Result<(), ContractError> {
+    let bid_orders = BIDS_V3
+        .range(store, None, None, Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (id, bid_order) in bid_orders {
+        for index in BIDS_V3.idx.get_indexes() {
+            index.save(store, &id, &bid_order)?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(deprecated)]
     use super::{migrate_bid_orders, BidOrderV2, BidOrderV3};
-    use crate::bid_order::{BIDS_V2, BIDS_V3};
+    use crate::bid_order::{BIDS_V2, BIDS_V3, NAMESPACE_ORDER_BID};
+    use crate::common::FeeTerms;
     use crate::common::{Action, Event};
     use crate::error::ContractError;
     use crate::msg::MigrateMsg;
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
     use crate::version_info::{set_version_info, VersionInfoV1, CRATE_NAME};
     use cosmwasm_std::testing::mock_env;
-    use cosmwasm_std::{Addr, Coin, Response, Uint128};
+    use cosmwasm_std::{Addr, Coin, Order, Response, Uint128};
+    use cw_storage_plus::Map;
     use provwasm_mocks::mock_provenance_dependencies;
     use rust_decimal::prelude::FromStr;
     use rust_decimal::Decimal;
@@ -281,6 +431,7 @@ mod tests {
                 amount: Uint128::new(1000),
                 denom: "quote_1".to_string(),
             },
+            ..Default::default()
         };
 
         assert_eq!(bid_order.get_remaining_base(), Uint128::new(80));
@@ -324,6 +475,7 @@ mod tests {
                     bid_fee_account: None,
                     ask_required_attributes: None,
                     bid_required_attributes: None,
+                    ..Default::default()
                 },
                 response,
             )
@@ -373,6 +525,7 @@ mod tests {
                     bid_fee_account: None,
                     ask_required_attributes: None,
                     bid_required_attributes: None,
+                    ..Default::default()
                 },
                 response,
             )
@@ -389,6 +542,7 @@ mod tests {
     pub fn migrate_bid_order_v2_to_bid_order_v3() -> Result<(), ContractError> {
         // Setup
         let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
 
         set_version_info(
             &mut deps.storage,
@@ -589,6 +743,7 @@ mod tests {
                     bid_fee_account: None,
                     ask_required_attributes: None,
                     bid_required_attributes: None,
+                    ..Default::default()
                 },
                 response,
             )?
@@ -617,6 +772,12 @@ mod tests {
                     denom: "quote_1".to_owned(),
                     amount: Uint128::new(100)
                 },
+                sequence: 1,
+                fee_terms: Some(FeeTerms {
+                    fee_info: None,
+                    maker_taker_fee_info: None,
+                }),
+                ..Default::default()
             },
             bid_1_v3
         );
@@ -640,6 +801,12 @@ mod tests {
                     denom: "quote_2".to_owned(),
                     amount: Uint128::new(200)
                 },
+                sequence: 2,
+                fee_terms: Some(FeeTerms {
+                    fee_info: None,
+                    maker_taker_fee_info: None,
+                }),
+                ..Default::default()
             },
             bid_2_v3
         );
@@ -652,6 +819,7 @@ mod tests {
     pub fn migrate_contract_v0_19_0_bid_order_v2_to_bid_order_v3() -> Result<(), ContractError> {
         // Setup
         let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
 
         set_version_info(
             &mut deps.storage,
@@ -858,6 +1026,7 @@ mod tests {
                 amount: Uint128::new(6720),
                 denom: "quote_2".to_string(),
             },
+            ..Default::default()
         };
         BIDS_V3.save(&mut deps.storage, &bid3.id.as_bytes(), &bid3)?;
 
@@ -875,6 +1044,7 @@ mod tests {
                     bid_fee_account: None,
                     ask_required_attributes: None,
                     bid_required_attributes: None,
+                    ..Default::default()
                 },
                 response,
             )?
@@ -904,6 +1074,12 @@ mod tests {
                     denom: "quote_1".to_owned(),
                     amount: Uint128::new(100)
                 },
+                sequence: 1,
+                fee_terms: Some(FeeTerms {
+                    fee_info: None,
+                    maker_taker_fee_info: None,
+                }),
+                ..Default::default()
             },
             bid_1_v3
         );
@@ -927,12 +1103,109 @@ mod tests {
                     denom: "quote_2".to_owned(),
                     amount: Uint128::new(200)
                 },
+                sequence: 2,
+                fee_terms: Some(FeeTerms {
+                    fee_info: None,
+                    maker_taker_fee_info: None,
+                }),
+                ..Default::default()
             },
             bid_2_v3
         );
 
-        // Should be the same as before
-        assert_eq!(bid3, bid_3_v3);
+        // Should be the same as before, sequenced after the converted bids, with the current fee
+        // terms set
+        assert_eq!(
+            BidOrderV3 {
+                sequence: 3,
+                fee_terms: Some(FeeTerms {
+                    fee_info: None,
+                    maker_taker_fee_info: None,
+                }),
+                ..bid3
+            },
+            bid_3_v3
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn bid_migration_builds_owner_index() -> Result<(), ContractError> {
+        // Setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        set_version_info(
+            &mut deps.storage,
+            &VersionInfoV1 {
+                definition: CRATE_NAME.to_string(),
+                version: "1.0.0".to_string(), // version before secondary indexes
+            },
+        )?;
+
+        // Store a bid order the way it was stored before secondary indexes
+        let bid_order = BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".to_string(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: "bid-1".to_string(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".to_string(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".to_string(),
+            },
+            ..Default::default()
+        };
+        Map::<&[u8], BidOrderV3>::new(NAMESPACE_ORDER_BID).save(
+            &mut deps.storage,
+            bid_order.id.as_bytes(),
+            &bid_order,
+        )?;
+
+        migrate_bid_orders(
+            deps.as_mut(),
+            mock_env(),
+            &MigrateMsg {
+                approvers: None,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                ..Default::default()
+            },
+            Response::new(),
+        )?;
+
+        // Indexed, sequenced, with the current fee terms set, after migration
+        let indexed_bids = BIDS_V3
+            .idx
+            .owner
+            .prefix(Addr::unchecked("bidder"))
+            .range(&deps.storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            indexed_bids,
+            vec![(
+                b"bid-1".to_vec(),
+                BidOrderV3 {
+                    sequence: 1,
+                    fee_terms: Some(FeeTerms {
+                        fee_info: None,
+                        maker_taker_fee_info: None,
+                    }),
+                    ..bid_order
+                }
+            )]
+        );
 
         Ok(())
     }
@@ -1,11 +1,332 @@
-use cosmwasm_std::{Addr, Coin, Timestamp};
+use crate::error::ContractError;
+use cosmwasm_std::{Addr, Coin, Storage, Timestamp, Uint128};
+use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::cmp::{max, min};
+use std::fmt;
+
+const ORDER_SEQUENCE_NAMESPACE: &str = "order_sequence";
+const ORDER_SEQUENCE: Item<u64> = Item::new(ORDER_SEQUENCE_NAMESPACE);
+
+/// Returns the next order sequence number, shared by asks and bids to order them by creation.
+///
+/// Sequences start at 1, orders created before sequencing was introduced are sequenced by id on
+/// migration.
+pub fn next_order_sequence(store: &mut dyn Storage) -> Result<u64, ContractError> {
+    let sequence = ORDER_SEQUENCE.may_load(store)?.unwrap_or_default() + 1;
+    ORDER_SEQUENCE.save(store, &sequence)?;
+    Ok(sequence)
+}
+
+/// How an order interacts with the resting book when it is created
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// The order rests on the book until it is matched
+    #[default]
+    Limit,
+    /// The order is matched against resting orders right away, any unfilled size is refunded
+    ImmediateOrCancel,
+    /// The order is matched against resting orders right away and in full, or not at all
+    FillOrKill,
+}
+
+/// How long an order remains on the book
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInForce {
+    /// Good 'til cancelled, the order rests until it is filled, canceled, expired or rejected
+    #[default]
+    GoodTilCancelled,
+    /// Good 'til date, the order can't be matched after its `expires_at` timestamp
+    GoodTilDate,
+}
+
+impl TimeInForce {
+    /// Default time in force when none is requested, orders with an expiration are good 'til date
+    pub fn from_expiration(expires_at: &Option<Timestamp>) -> Self {
+        match expires_at {
+            Some(_) => TimeInForce::GoodTilDate,
+            None => TimeInForce::GoodTilCancelled,
+        }
+    }
+}
+
+/// Which order actions the contract accepts, set by the admin to stop trading during an incident
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractStatus {
+    /// Orders are created, approved, modified, matched and removed
+    #[default]
+    Active,
+    /// Orders can only be canceled, expired or rejected
+    CancelOnly,
+    /// No order actions are accepted
+    Halted,
+}
+
+impl fmt::Display for ContractStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContractStatus::Active => write!(f, "active"),
+            ContractStatus::CancelOnly => write!(f, "cancel_only"),
+            ContractStatus::Halted => write!(f, "halted"),
+        }
+    }
+}
+
+/// Contract status and per-side pauses of new and modified orders while the contract is active
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct TradingStatus {
+    pub status: ContractStatus,
+    /// Asks can't be created, approved or modified, resting asks are still matched
+    pub asks_paused: bool,
+    /// Bids can't be created or modified, resting bids are still matched
+    pub bids_paused: bool,
+}
+
+/// Band around the last fill price that matches must execute within, and the circuit breaker that
+/// halts matching after repeated breaches, applied to each quote denom's market
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceBand {
+    /// Largest deviation from the last fill price as a fraction, 0.1 allows prices within 10%
+    pub rate: String,
+    /// Matches outside the band since the last fill that halt matching
+    pub max_breaches: u32,
+    /// Seconds matching stays halted
+    pub halt_duration: u64,
+}
+
+/// Account attribute requirements of an order side, clauses can be nested
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributePolicy {
+    /// The account has the attribute, with one of the values if any are listed
+    Attribute {
+        name: String,
+        values: Option<Vec<String>>,
+    },
+    /// Every clause is satisfied
+    AllOf(Vec<AttributePolicy>),
+    /// At least one clause is satisfied
+    AnyOf(Vec<AttributePolicy>),
+    /// No clause is satisfied
+    NoneOf(Vec<AttributePolicy>),
+}
+
+impl AttributePolicy {
+    /// Returns the name of the clause used in error messages
+    pub fn get_clause_name(&self) -> String {
+        match self {
+            AttributePolicy::Attribute { name, .. } => format!("attribute({})", name),
+            AttributePolicy::AllOf(_) => String::from("all_of"),
+            AttributePolicy::AnyOf(_) => String::from("any_of"),
+            AttributePolicy::NoneOf(_) => String::from("none_of"),
+        }
+    }
+}
+
+/// Order size and notional (price × size) limits of a quote denom's market, limits that aren't set
+/// aren't enforced
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OrderLimits {
+    pub quote: String,
+    /// Smallest notional in quote an order can have
+    pub min_notional: Option<Uint128>,
+    /// Largest notional in quote an order can have
+    pub max_notional: Option<Uint128>,
+    /// Largest size in base an order can have
+    pub max_size: Option<Uint128>,
+}
+
+/// Open order limits of an account, limits that aren't set aren't enforced
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct AccountLimits {
+    /// Most asks and bids the account can have open
+    pub max_open_orders: Option<u32>,
+    /// Most the account's open orders can hold in escrow, per denom
+    #[serde(default)]
+    pub max_escrow: Vec<Coin>,
+}
+
+/// Account limits of an address, in place of the default account limits
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccountLimitsOverride {
+    pub address: String,
+    pub limits: AccountLimits,
+}
+
+/// What happens when an ask and bid of the same owner are matched
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SelfTradePrevention {
+    /// The match fails
+    #[default]
+    Reject,
+    /// The order that rested on the book first is canceled
+    CancelResting,
+    /// The order created last is canceled
+    CancelNewest,
+    /// Both orders are reduced by the match size without trading
+    DecrementBoth,
+}
+
+impl fmt::Display for SelfTradePrevention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelfTradePrevention::Reject => write!(f, "reject"),
+            SelfTradePrevention::CancelResting => write!(f, "cancel_resting"),
+            SelfTradePrevention::CancelNewest => write!(f, "cancel_newest"),
+            SelfTradePrevention::DecrementBoth => write!(f, "decrement_both"),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct FeeInfo {
     pub account: Addr,
     pub rate: String,
+    /// Limits of the fee charged per fill and how it is split between accounts
+    #[serde(default)]
+    pub policy: FeePolicy,
+}
+
+impl FeeInfo {
+    /// Returns the accounts the fee is split between, the fee account when no recipients are set
+    pub fn get_recipients(&self) -> Vec<FeeRecipient> {
+        if self.policy.recipients.is_empty() {
+            vec![FeeRecipient {
+                account: self.account.to_owned(),
+                weight: 1,
+            }]
+        } else {
+            self.policy.recipients.to_owned()
+        }
+    }
+}
+
+/// Per fill fee limits by quote denom and weighted fee recipients.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct FeePolicy {
+    /// Smallest fee charged per fill, by quote denom
+    #[serde(default)]
+    pub min_fees: Vec<Coin>,
+    /// Largest fee charged per fill, by quote denom
+    #[serde(default)]
+    pub max_fees: Vec<Coin>,
+    /// Accounts receiving a share of the fee by weight, in place of the fee account when set
+    #[serde(default)]
+    pub recipients: Vec<FeeRecipient>,
+}
+
+impl FeePolicy {
+    /// Returns the minimum fee per fill for the quote denom
+    pub fn get_min_fee(&self, quote_denom: &str) -> Option<Uint128> {
+        find_amount(&self.min_fees, quote_denom)
+    }
+
+    /// Returns the maximum fee per fill for the quote denom
+    pub fn get_max_fee(&self, quote_denom: &str) -> Option<Uint128> {
+        find_amount(&self.max_fees, quote_denom)
+    }
+
+    /// Limits a fee amount to the policy's minimum and maximum for the quote denom
+    pub fn limit_fee(&self, quote_denom: &str, fee: Uint128) -> Uint128 {
+        let fee = match self.get_min_fee(quote_denom) {
+            Some(min_fee) => max(fee, min_fee),
+            None => fee,
+        };
+
+        match self.get_max_fee(quote_denom) {
+            Some(max_fee) => min(fee, max_fee),
+            None => fee,
+        }
+    }
+}
+
+fn find_amount(coins: &[Coin], denom: &str) -> Option<Uint128> {
+    coins
+        .iter()
+        .find(|coin| coin.denom.eq(denom))
+        .map(|coin| coin.amount)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeRecipient {
+    pub account: Addr,
+    pub weight: u64,
+}
+
+/// Splits a fee between weighted recipients, each share is rounded down and the remainder goes to
+/// the first recipient so the shares always add up to the fee.
+pub fn split_fee(recipients: &[FeeRecipient], fee: Uint128) -> Vec<(Addr, Uint128)> {
+    let total_weight = Uint128::from(
+        recipients
+            .iter()
+            .map(|recipient| recipient.weight)
+            .sum::<u64>(),
+    );
+
+    if total_weight.is_zero() {
+        return vec![];
+    }
+
+    let mut shares: Vec<(Addr, Uint128)> = recipients
+        .iter()
+        .map(|recipient| {
+            (
+                recipient.account.to_owned(),
+                fee.multiply_ratio(recipient.weight, total_weight),
+            )
+        })
+        .collect();
+
+    let remainder = fee - shares.iter().map(|(_, share)| *share).sum::<Uint128>();
+    if let Some((_, share)) = shares.first_mut() {
+        *share += remainder;
+    }
+
+    shares
+}
+
+/// Fee rates charged on each fill by whether the order was resting (maker) or aggressing (taker).
+///
+/// A negative maker rate is a rebate, paid to the maker out of the fees accrued to the account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MakerTakerFeeInfo {
+    pub account: Addr,
+    pub maker_rate: String,
+    pub taker_rate: String,
+}
+
+impl MakerTakerFeeInfo {
+    /// Returns the fee account as the only fee recipient
+    pub fn get_recipients(&self) -> Vec<FeeRecipient> {
+        vec![FeeRecipient {
+            account: self.account.to_owned(),
+            weight: 1,
+        }]
+    }
+}
+
+/// A volume tier of the fee tier table, accounts whose rolling traded quote volume reaches the
+/// threshold are charged the tier's ask and bid rates when lower than their orders' fee rates.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTier {
+    pub volume_threshold: Uint128,
+    pub ask_rate: String,
+    pub bid_rate: String,
+}
+
+/// Fee terms in effect when an order was created, the order's fills are charged by these terms so
+/// the contract fees can change while the order is open.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FeeTerms {
+    /// Fee rate and account of the order's side, the ask or bid fee info
+    pub fee_info: Option<FeeInfo>,
+    /// Maker/taker fee schedule, charged in place of the side's fee rate when set
+    pub maker_taker_fee_info: Option<MakerTakerFeeInfo>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -57,17 +378,31 @@ pub enum ContractAction {
     ModifyContract,
 
     CreateAsk,
+    ModifyAsk,
     ApproveAsk,
     CancelAsk,
     ExpireAsk,
     RejectAsk,
 
     CreateBid,
+    CreateMarketBid,
+    ModifyBid,
     CancelBid,
     ExpireBid,
     RejectBid,
 
     Execute, // Execute match
+    RejectMatch,
+    MatchOrders,
+    ExpireOrders,
+    Batch,
+
+    WithdrawFees,
+    SetTradingStatus,
+    ProposeAdmin,
+    AcceptAdmin,
+    CancelPendingChange,
+    ApplyPendingChanges,
 }
 
 impl ToString for ContractAction {
@@ -1,37 +1,66 @@
+use crate::account_limits::check_account_limits;
 use crate::ask_order::{migrate_ask_orders, AskOrderClass, AskOrderStatus, AskOrderV1, ASKS_V1};
 use crate::bid_order::{migrate_bid_orders, BidOrderV3, BIDS_V3};
-use crate::common::{Action, ContractAction, FeeInfo};
+use crate::common::{
+    next_order_sequence, split_fee, Action, ContractAction, FeeInfo, FeePolicy, FeeTerms, FeeTier,
+    MakerTakerFeeInfo, OrderType, SelfTradePrevention, TimeInForce, TradingStatus,
+};
 use crate::contract_info::{
-    get_contract_info, migrate_contract_info, set_contract_info, ContractInfoV3,
+    get_contract_info, migrate_contract_info, set_contract_info, set_fee_policy,
+    to_attribute_policy, to_maker_taker_fee_info, to_price_band, validate_account_limits,
+    validate_account_limits_overrides, validate_fee_tiers, validate_order_limits,
+    with_required_attributes, ContractInfoV3,
 };
 use crate::error::ContractError;
 use crate::error::ContractError::InvalidPricePrecisionSizePair;
+use crate::execute::admin::{accept_admin, propose_admin};
+use crate::execute::batch::batch;
+use crate::execute::create_market_bid::create_market_bid;
+use crate::execute::expire_orders::expire_orders;
+use crate::execute::match_orders::{fill_incoming_order, match_orders, IncomingOrder};
 use crate::execute::modify_contract::modify_contract;
+use crate::execute::modify_order::{modify_ask, modify_bid};
+use crate::execute::pending_changes::{apply_pending_changes, cancel_pending_change};
+use crate::execute::set_trading_status::{check_trading_status, set_trading_status};
+use crate::execute::withdraw_fees::withdraw_fees;
+use crate::fee_ledger::{accrue_fee, pay_from_accrued_fee};
+use crate::fill_log::record_fill;
+use crate::governance::ContractChange;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, Validate};
+use crate::price_band::{
+    check_price_band, record_price_band_breach, record_trade_price, PriceBandCheck,
+};
+use crate::query::account_limits::get_account_limits;
+use crate::query::accrued_fees::get_accrued_fees;
+use crate::query::config_history::{get_config_history, get_pending_changes};
+use crate::query::fee_tier::get_account_fee_tier;
+use crate::query::fills::{get_order_fills, get_recent_trades};
+use crate::query::list_orders::{list_asks, list_asks_by_owner, list_bids, list_bids_by_owner};
+use crate::query::order_book::get_order_book_depth;
+use crate::query::price_band::get_price_band;
 use crate::util::{
-    add_transfer, get_attributes, is_invalid_price_precision, is_restricted_marker,
+    add_transfer, check_account_attributes, is_restricted_marker, parse_order_price,
     transfer_marker_coins,
 };
 use crate::version_info::{
     get_version_info, migrate_version_info, set_version_info, VersionInfoV1, CRATE_NAME,
     PACKAGE_VERSION,
 };
+use crate::volume::{get_volume_fee_tier, record_volume};
 use cosmwasm_std::{
     attr, coin, coins, entry_point, to_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, StdResult, Uint128,
+    Response, StdError, StdResult, Storage, Uint128,
 };
-use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
 use rust_decimal::prelude::{FromPrimitive, FromStr, ToPrimitive, Zero};
 use rust_decimal::{Decimal, RoundingStrategy};
-use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::cmp::{max, min, Ordering};
 
 // smart contract initialization entrypoint
 #[entry_point]
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     msg.validate()?;
@@ -50,8 +79,14 @@ pub fn instantiate(
         executors.push(address);
     }
 
+    // validate admin, the instantiating account unless set
+    let admin = match &msg.admin {
+        Some(admin) => deps.api.addr_validate(admin)?,
+        None => info.sender,
+    };
+
     // validate and set ask fee
-    let ask_fee = match (&msg.ask_fee_account, &msg.ask_fee_rate) {
+    let mut ask_fee = match (&msg.ask_fee_account, &msg.ask_fee_rate) {
         (Some(account), Some(rate)) => match (account.as_str(), rate.as_str()) {
             ("", "") => None,
             (_, _) => {
@@ -62,6 +97,7 @@ pub fn instantiate(
                 Some(FeeInfo {
                     account: deps.api.addr_validate(account)?,
                     rate: rate.to_string(),
+                    policy: FeePolicy::default(),
                 })
             }
         },
@@ -69,7 +105,7 @@ pub fn instantiate(
     };
 
     // validate and set bid fee
-    let bid_fee = match (&msg.bid_fee_account, &msg.bid_fee_rate) {
+    let mut bid_fee = match (&msg.bid_fee_account, &msg.bid_fee_rate) {
         (Some(account), Some(rate)) => match (account.as_str(), rate.as_str()) {
             ("", "") => None,
             (_, _) => {
@@ -80,12 +116,69 @@ pub fn instantiate(
                 Some(FeeInfo {
                     account: deps.api.addr_validate(account)?,
                     rate: rate.to_string(),
+                    policy: FeePolicy::default(),
                 })
             }
         },
         (_, _) => None,
     };
 
+    // validate and set fee policies
+    set_fee_policy(
+        deps.api,
+        &mut ask_fee,
+        &msg.ask_fee_policy,
+        "ask_fee_policy",
+    )?;
+    set_fee_policy(
+        deps.api,
+        &mut bid_fee,
+        &msg.bid_fee_policy,
+        "bid_fee_policy",
+    )?;
+
+    // validate and set maker/taker fee schedule
+    let maker_taker_fee = match (
+        &msg.maker_taker_fee_account,
+        &msg.maker_fee_rate,
+        &msg.taker_fee_rate,
+    ) {
+        (Some(account), Some(maker_rate), Some(taker_rate)) => {
+            to_maker_taker_fee_info(deps.api, account, maker_rate, taker_rate)?
+        }
+        (_, _, _) => None,
+    };
+
+    // validate fee tiers
+    validate_fee_tiers(&msg.fee_tiers)?;
+
+    // validate order and account limits
+    validate_order_limits(&msg.order_limits)?;
+    validate_account_limits(&msg.account_limits, "account_limits")?;
+    validate_account_limits_overrides(deps.api, &msg.account_limits_overrides)?;
+
+    // validate attribute policies
+    let ask_attribute_policy =
+        match &with_required_attributes(&msg.ask_required_attributes, &msg.ask_attribute_policy) {
+            Some(attribute_policy) => {
+                to_attribute_policy(attribute_policy, "ask_attribute_policy")?
+            }
+            None => None,
+        };
+    let bid_attribute_policy =
+        match &with_required_attributes(&msg.bid_required_attributes, &msg.bid_attribute_policy) {
+            Some(attribute_policy) => {
+                to_attribute_policy(attribute_policy, "bid_attribute_policy")?
+            }
+            None => None,
+        };
+
+    // validate price band
+    let price_band = match &msg.price_band {
+        Some(price_band) => to_price_band(price_band)?,
+        None => None,
+    };
+
     // set contract info
     let contract_info = ContractInfoV3 {
         name: msg.name,
@@ -95,10 +188,21 @@ pub fn instantiate(
         supported_quote_denoms: msg.supported_quote_denoms,
         approvers,
         executors,
+        admin: Some(admin),
+        pending_admin: None,
+        governance_delay: msg.governance_delay,
         ask_fee_info: ask_fee,
         bid_fee_info: bid_fee,
-        ask_required_attributes: msg.ask_required_attributes,
-        bid_required_attributes: msg.bid_required_attributes,
+        maker_taker_fee_info: maker_taker_fee,
+        fee_tiers: msg.fee_tiers,
+        trading_status: TradingStatus::default(),
+        self_trade_prevention: msg.self_trade_prevention,
+        price_band,
+        order_limits: msg.order_limits,
+        account_limits: msg.account_limits,
+        account_limits_overrides: msg.account_limits_overrides,
+        ask_attribute_policy,
+        bid_attribute_policy,
         price_precision: msg.price_precision,
         size_increment: msg.size_increment,
     };
@@ -138,6 +242,9 @@ pub fn execute(
     // validate execute message
     msg.validate()?;
 
+    // check the contract's trading status allows the message
+    check_trading_status(deps.storage, &msg)?;
+
     match msg {
         ExecuteMsg::ApproveAsk { id, base, size } => approve_ask(deps, env, info, id, base, size),
         ExecuteMsg::CreateAsk {
@@ -146,6 +253,9 @@ pub fn execute(
             quote,
             price,
             size,
+            order_type,
+            time_in_force,
+            expires_at,
         } => create_ask(
             deps,
             env,
@@ -158,7 +268,13 @@ pub fn execute(
                 quote,
                 price,
                 size,
+                sequence: 0,
+                time_in_force: time_in_force
+                    .unwrap_or_else(|| TimeInForce::from_expiration(&expires_at)),
+                expires_at,
+                fee_terms: None,
             },
+            order_type.unwrap_or_default(),
         ),
         ExecuteMsg::CreateBid {
             id,
@@ -168,6 +284,9 @@ pub fn execute(
             quote,
             quote_size,
             size,
+            order_type,
+            time_in_force,
+            expires_at,
         } => create_bid(
             deps,
             env,
@@ -188,6 +307,45 @@ pub fn execute(
                     amount: quote_size,
                     denom: quote,
                 },
+                sequence: 0,
+                time_in_force: time_in_force
+                    .unwrap_or_else(|| TimeInForce::from_expiration(&expires_at)),
+                expires_at,
+                fee_terms: None,
+            },
+            order_type.unwrap_or_default(),
+        ),
+        ExecuteMsg::CreateMarketBid {
+            id,
+            base,
+            fee,
+            quote,
+            quote_size,
+            worst_price,
+        } => create_market_bid(
+            deps,
+            env,
+            &info,
+            BidOrderV3 {
+                base: Coin {
+                    amount: Uint128::zero(),
+                    denom: base,
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee,
+                id,
+                owner: info.sender.to_owned(),
+                price: worst_price,
+                quote: Coin {
+                    amount: quote_size,
+                    denom: quote,
+                },
+                sequence: 0,
+                time_in_force: TimeInForce::GoodTilCancelled,
+                expires_at: None,
+                fee_terms: None,
             },
         ),
         ExecuteMsg::CancelAsk { id } => cancel_ask(deps, env, info, id),
@@ -206,12 +364,33 @@ pub fn execute(
         ExecuteMsg::ExpireBid { id } => {
             reverse_bid(deps, env, info, id, ContractAction::ExpireBid, None)
         }
+        ExecuteMsg::ExpireOrders { limit } => expire_orders(deps, env, &info, limit),
+        ExecuteMsg::MatchOrders { limit } => match_orders(deps, env, &info, limit),
+        ExecuteMsg::ModifyAsk { id, price, size } => modify_ask(deps, env, &info, id, price, size),
+        ExecuteMsg::ModifyBid {
+            id,
+            price,
+            size,
+            quote_size,
+            fee,
+        } => modify_bid(deps, env, &info, id, price, size, quote_size, fee),
         ExecuteMsg::RejectAsk { id, size } => {
             reverse_ask(deps, env, info, id, ContractAction::RejectAsk, size)
         }
         ExecuteMsg::RejectBid { id, size } => {
             reverse_bid(deps, env, info, id, ContractAction::RejectBid, size)
         }
+        ExecuteMsg::Batch { ops } => batch(deps, env, info, ops),
+        ExecuteMsg::WithdrawFees { denom } => withdraw_fees(deps, env, &info, denom),
+        ExecuteMsg::SetTradingStatus {
+            status,
+            asks_paused,
+            bids_paused,
+        } => set_trading_status(deps, env, &info, status, asks_paused, bids_paused),
+        ExecuteMsg::ProposeAdmin { admin } => propose_admin(deps, env, &info, admin),
+        ExecuteMsg::AcceptAdmin {} => accept_admin(deps, env, &info),
+        ExecuteMsg::CancelPendingChange { id } => cancel_pending_change(deps, env, &info, id),
+        ExecuteMsg::ApplyPendingChanges {} => apply_pending_changes(deps, env, &info),
         ExecuteMsg::ModifyContract {
             approvers,
             executors,
@@ -219,20 +398,64 @@ pub fn execute(
             ask_fee_account,
             bid_fee_rate,
             bid_fee_account,
+            ask_fee_policy,
+            bid_fee_policy,
+            maker_fee_rate,
+            taker_fee_rate,
+            maker_taker_fee_account,
+            fee_tiers,
             ask_required_attributes,
             bid_required_attributes,
+            add_supported_quote_denoms,
+            remove_supported_quote_denoms,
+            add_convertible_base_denoms,
+            remove_convertible_base_denoms,
+            price_precision,
+            size_increment,
+            self_trade_prevention,
+            price_band,
+            order_limits,
+            account_limits,
+            account_limits_overrides,
+            ask_attribute_policy,
+            bid_attribute_policy,
         } => modify_contract(
             deps,
             env,
             &info,
-            approvers,
-            executors,
-            ask_fee_rate,
-            ask_fee_account,
-            bid_fee_rate,
-            bid_fee_account,
-            ask_required_attributes,
-            bid_required_attributes,
+            ContractChange {
+                approvers,
+                executors,
+                ask_fee_rate,
+                ask_fee_account,
+                bid_fee_rate,
+                bid_fee_account,
+                ask_fee_policy,
+                bid_fee_policy,
+                maker_fee_rate,
+                taker_fee_rate,
+                maker_taker_fee_account,
+                fee_tiers,
+                add_supported_quote_denoms,
+                remove_supported_quote_denoms,
+                add_convertible_base_denoms,
+                remove_convertible_base_denoms,
+                price_precision,
+                size_increment,
+                self_trade_prevention,
+                price_band,
+                order_limits,
+                account_limits,
+                account_limits_overrides,
+                ask_attribute_policy: with_required_attributes(
+                    &ask_required_attributes,
+                    &ask_attribute_policy,
+                ),
+                bid_attribute_policy: with_required_attributes(
+                    &bid_required_attributes,
+                    &bid_attribute_policy,
+                ),
+            },
         ),
     }
 }
@@ -342,9 +565,17 @@ fn create_ask(
     env: Env,
     info: &MessageInfo,
     mut ask_order: AskOrderV1,
+    order_type: OrderType,
 ) -> Result<Response, ContractError> {
     let contract_info = get_contract_info(deps.storage)?;
 
+    // error if the order expires before it can rest on the book
+    if ask_order.is_expired(env.block.time) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("expires_at")],
+        });
+    }
+
     // error if order base is not contract base nor contract convertible base
     if ask_order.base.ne(&contract_info.base_denom)
         && !contract_info
@@ -391,38 +622,37 @@ fn create_ask(
         });
     }
 
-    let ask_price =
-        Decimal::from_str(&ask_order.price).map_err(|_| ContractError::InvalidFields {
-            fields: vec![String::from("price")],
-        })?;
-
-    if ask_price.is_zero() || ask_price.is_sign_negative() {
-        return Err(ContractError::InvalidFields {
-            fields: vec![String::from("price")],
-        });
-    }
+    // error if price is not positive or smaller than allowed price precision
+    let ask_price = parse_order_price(&ask_order.price, contract_info.price_precision)?;
 
-    // error if price smaller than allow price precision
-    if is_invalid_price_precision(ask_price.clone(), contract_info.price_precision.clone()) {
-        return Err(ContractError::InvalidFields {
-            fields: vec![String::from("price")],
-        });
-    }
+    // error if the order is outside the quote denom's size and notional limits
+    contract_info.check_order_limits(
+        &ask_order.quote,
+        Some(ask_order.size),
+        ask_price
+            .checked_mul(Decimal::from(ask_order.size.u128()))
+            .ok_or(ContractError::TotalOverflow)?,
+    )?;
 
     // error if asker does not have required account attributes
-    if !contract_info.ask_required_attributes.is_empty() {
-        let querier = AttributeQuerier::new(&deps.querier);
-        let attributes = get_attributes(info.sender.to_string(), &querier)?;
-        let attributes_names: HashSet<String> =
-            attributes.into_iter().map(|item| item.name).collect();
-        if contract_info
-            .ask_required_attributes
-            .iter()
-            .any(|item| !attributes_names.contains(item))
-        {
-            return Err(ContractError::Unauthorized);
-        }
-    }
+    check_account_attributes(
+        &deps.querier,
+        &info.sender,
+        env.block.time,
+        &contract_info.ask_attribute_policy,
+    )?;
+
+    // error if the order takes the asker over their open order limits
+    check_account_limits(
+        deps.storage,
+        contract_info.get_account_limits(&ask_order.owner),
+        &ask_order.owner,
+        true,
+        &Coin {
+            denom: ask_order.base.to_owned(),
+            amount: ask_order.size,
+        },
+    )?;
 
     if ask_order.base.ne(&contract_info.base_denom) {
         ask_order.class = AskOrderClass::Convertible {
@@ -439,6 +669,9 @@ fn create_ask(
         });
     }
 
+    ask_order.sequence = next_order_sequence(deps.storage)?;
+    ask_order.fee_terms = Some(contract_info.ask_fee_terms());
+
     ASKS_V1.save(deps.storage, ask_order.id.as_bytes(), &ask_order)?;
 
     let mut response = Response::new().add_attributes(vec![
@@ -458,11 +691,21 @@ fn create_ask(
             ask_order.base.to_owned(),
             env.contract.address.to_owned(),
             ask_order.owner,
-            env.contract.address,
+            env.contract.address.to_owned(),
         )?);
     }
 
-    Ok(response)
+    match order_type {
+        OrderType::Limit => Ok(response),
+        _ => fill_incoming_order(
+            deps,
+            &env,
+            &contract_info,
+            IncomingOrder::Ask(ask_order.id),
+            order_type,
+            response,
+        ),
+    }
 }
 
 // create bid entrypoint
@@ -471,26 +714,19 @@ fn create_bid(
     env: Env,
     info: &MessageInfo,
     mut bid_order: BidOrderV3,
+    order_type: OrderType,
 ) -> Result<Response, ContractError> {
     let contract_info = get_contract_info(deps.storage)?;
 
-    let bid_price =
-        Decimal::from_str(&bid_order.price).map_err(|_| ContractError::InvalidFields {
-            fields: vec![String::from("price")],
-        })?;
-
-    if bid_price.is_zero() || bid_price.is_sign_negative() {
+    // error if the order expires before it can rest on the book
+    if bid_order.is_expired(env.block.time) {
         return Err(ContractError::InvalidFields {
-            fields: vec![String::from("price")],
+            fields: vec![String::from("expires_at")],
         });
     }
 
-    // error if price smaller than allow price precision
-    if is_invalid_price_precision(bid_price.clone(), contract_info.price_precision.clone()) {
-        return Err(ContractError::InvalidFields {
-            fields: vec![String::from("price")],
-        });
-    }
+    // error if price is not positive or smaller than allowed price precision
+    let bid_price = parse_order_price(&bid_order.price, contract_info.price_precision)?;
 
     // error if order size is not multiple of size_increment
     if (bid_order.base.amount.u128() % contract_info.size_increment.u128()).ne(&0) {
@@ -514,45 +750,13 @@ fn create_bid(
         return Err(ContractError::SentFundsOrderMismatch);
     }
 
-    // Get the bid fee rate (0 if not set)
-    let bid_fee_rate = match contract_info.bid_fee_info {
-        Some(bid_fee_info) => {
-            Decimal::from_str(&bid_fee_info.rate).map_err(|_| ContractError::InvalidFields {
-                fields: vec![String::from("ContractInfo.bid_fee_info.rate")],
-            })?
-        }
-        None => Decimal::from(0),
-    };
-
-    // Calculate the expected fees (bid_fee_rate * total)
-    let calculated_fee_size = bid_fee_rate
-        .checked_mul(total)
-        .ok_or(ContractError::TotalOverflow)?
-        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
-        .to_u128()
-        .ok_or(ContractError::TotalOverflow)?;
-
-    match &mut bid_order.fee {
-        Some(fee) => {
-            // If the user sent fees, then make sure the amount + denom match
-            if fee.amount.ne(&Uint128::new(calculated_fee_size)) {
-                return Err(ContractError::InvalidFeeSize {
-                    fee_rate: bid_fee_rate.to_string(),
-                });
-            }
-            if fee.denom.ne(&bid_order.quote.denom) {
-                return Err(ContractError::SentFundsOrderMismatch);
-            }
-        }
-        None => {
-            // If the user did not send fees, make sure the calculated fees was 0
-            if calculated_fee_size.ne(&0) {
-                return Err(ContractError::InvalidFeeSize {
-                    fee_rate: bid_fee_rate.to_string(),
-                });
-            }
-        }
-    }
+    // error if the sent fee does not match the bid fee rate applied to the total
+    check_bid_fee(
+        &contract_info,
+        &bid_order.fee,
+        &bid_order.quote.denom,
+        total,
+    )?;
 
     // error if order quote is not supported quote denom
     if !&contract_info
@@ -562,25 +766,40 @@ fn create_bid(
         return Err(ContractError::UnsupportedQuoteDenom);
     }
 
+    // error if the order is outside the quote denom's size and notional limits
+    contract_info.check_order_limits(&bid_order.quote.denom, Some(bid_order.base.amount), total)?;
+
     // error if order base denom not equal to contract base denom
     if bid_order.base.denom.ne(&contract_info.base_denom) {
         return Err(ContractError::InconvertibleBaseDenom);
     }
 
     // error if bidder does not have required account attributes
-    if !contract_info.bid_required_attributes.is_empty() {
-        let querier = AttributeQuerier::new(&deps.querier);
-        let attributes = get_attributes(info.sender.to_string(), &querier)?;
-        let attributes_names: HashSet<String> =
-            attributes.into_iter().map(|item| item.name).collect();
-        if contract_info
-            .bid_required_attributes
-            .iter()
-            .any(|item| !attributes_names.contains(item))
-        {
-            return Err(ContractError::Unauthorized);
-        }
-    }
+    check_account_attributes(
+        &deps.querier,
+        &info.sender,
+        env.block.time,
+        &contract_info.bid_attribute_policy,
+    )?;
+
+    // error if the order takes the bidder over their open order limits
+    check_account_limits(
+        deps.storage,
+        contract_info.get_account_limits(&bid_order.owner),
+        &bid_order.owner,
+        true,
+        &Coin {
+            denom: bid_order.quote.denom.to_owned(),
+            amount: match &bid_order.fee {
+                Some(fee) => bid_order
+                    .quote
+                    .amount
+                    .checked_add(fee.amount)
+                    .map_err(|_| ContractError::TotalOverflow)?,
+                None => bid_order.quote.amount,
+            },
+        },
+    )?;
 
     // is bid quote a marker
     let is_quote_restricted_marker =
@@ -614,6 +833,9 @@ fn create_bid(
         });
     }
 
+    bid_order.sequence = next_order_sequence(deps.storage)?;
+    bid_order.fee_terms = Some(contract_info.bid_fee_terms());
+
     BIDS_V3.save(deps.storage, bid_order.id.as_bytes(), &bid_order)?;
 
     let mut response = Response::new().add_attributes(vec![
@@ -642,11 +864,89 @@ fn create_bid(
             bid_order.quote.denom.to_owned(),
             env.contract.address.to_owned(),
             bid_order.owner,
-            env.contract.address,
+            env.contract.address.to_owned(),
         )?);
     }
 
-    Ok(response)
+    match order_type {
+        OrderType::Limit => Ok(response),
+        _ => fill_incoming_order(
+            deps,
+            &env,
+            &contract_info,
+            IncomingOrder::Bid(bid_order.id),
+            order_type,
+            response,
+        ),
+    }
+}
+
+/// Errors unless the bid fee equals the contract bid fee rate (or taker fee rate) applied to the
+/// quote total.
+pub(crate) fn check_bid_fee(
+    contract_info: &ContractInfoV3,
+    fee: &Option<Coin>,
+    quote_denom: &str,
+    total: Decimal,
+) -> Result<(), ContractError> {
+    // Get the bid fee rate (0 if not set), bids escrow the taker rate under a maker/taker schedule
+    let bid_fee_rate = match (
+        &contract_info.maker_taker_fee_info,
+        &contract_info.bid_fee_info,
+    ) {
+        (Some(maker_taker_fee_info), _) => Decimal::from_str(&maker_taker_fee_info.taker_rate)
+            .map_err(|_| ContractError::InvalidFields {
+                fields: vec![String::from("ContractInfo.maker_taker_fee_info.taker_rate")],
+            })?,
+        (None, Some(bid_fee_info)) => {
+            Decimal::from_str(&bid_fee_info.rate).map_err(|_| ContractError::InvalidFields {
+                fields: vec![String::from("ContractInfo.bid_fee_info.rate")],
+            })?
+        }
+        (None, None) => Decimal::from(0),
+    };
+
+    // Calculate the expected fees (bid_fee_rate * total)
+    let mut calculated_fee_size = bid_fee_rate
+        .checked_mul(total)
+        .ok_or(ContractError::TotalOverflow)?
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_u128()
+        .ok_or(ContractError::TotalOverflow)?;
+
+    // bids escrow at least the minimum fee of a fill
+    if let (None, Some(bid_fee_info)) = (
+        &contract_info.maker_taker_fee_info,
+        &contract_info.bid_fee_info,
+    ) {
+        if let Some(min_fee) = bid_fee_info.policy.get_min_fee(quote_denom) {
+            calculated_fee_size = max(calculated_fee_size, min_fee.u128());
+        }
+    }
+
+    match fee {
+        Some(fee) => {
+            // If the user sent fees, then make sure the amount + denom match
+            if fee.amount.ne(&Uint128::new(calculated_fee_size)) {
+                return Err(ContractError::InvalidFeeSize {
+                    fee_rate: bid_fee_rate.to_string(),
+                });
+            }
+            if fee.denom.ne(quote_denom) {
+                return Err(ContractError::SentFundsOrderMismatch);
+            }
+        }
+        None => {
+            // If the user did not send fees, make sure the calculated fees was 0
+            if calculated_fee_size.ne(&0) {
+                return Err(ContractError::InvalidFeeSize {
+                    fee_rate: bid_fee_rate.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
 }
 
 // cancel ask entrypoint
@@ -676,7 +976,7 @@ fn cancel_ask(
     }
 
     // remove the ask order from storage
-    ASKS_V1.remove(deps.storage, id.as_bytes());
+    ASKS_V1.remove(deps.storage, id.as_bytes())?;
 
     // is ask base a marker
     let is_base_restricted_marker = is_restricted_marker(&deps.querier, base.clone());
@@ -750,10 +1050,23 @@ fn reverse_ask(
     }
 
     // retrieve the order
-    let mut ask_order = ASKS_V1
+    let ask_order = ASKS_V1
         .load(deps.storage, id.as_bytes())
         .map_err(|error| ContractError::LoadOrderFailed { error })?;
 
+    reverse_ask_order(deps, &env, &contract_info, ask_order, action, cancel_size)
+}
+
+/// Returns all or part of an ask order's base to its owner (and any converted base to the
+/// approver), then updates or removes the order.
+pub(crate) fn reverse_ask_order(
+    deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    mut ask_order: AskOrderV1,
+    action: ContractAction,
+    cancel_size: Option<Uint128>,
+) -> Result<Response, ContractError> {
     // determine the effective cancel size
     let effective_cancel_size = match cancel_size {
         None => ask_order.size,
@@ -793,7 +1106,7 @@ fn reverse_ask(
 
     response = response.add_attributes(vec![
         attr("action", action.to_string()),
-        attr("id", id),
+        attr("id", &ask_order.id),
         attr("reverse_size", effective_cancel_size),
     ]);
 
@@ -815,13 +1128,13 @@ fn reverse_ask(
             converted_base.denom,
             approver,
             env.contract.address.to_owned(),
-            env.contract.address,
+            env.contract.address.to_owned(),
         );
     }
 
     // remove the ask order from storage if remaining size is 0, otherwise, store updated order
     if ask_order.size.is_zero() {
-        ASKS_V1.remove(deps.storage, ask_order.id.as_bytes());
+        ASKS_V1.remove(deps.storage, ask_order.id.as_bytes())?;
         response = response.add_attributes(vec![attr("order_open", "false")]);
     } else {
         ASKS_V1.save(deps.storage, ask_order.id.as_bytes(), &ask_order)?;
@@ -853,7 +1166,7 @@ fn reverse_bid(
     let contract_info = get_contract_info(deps.storage)?;
 
     //load the bid order
-    let mut bid_order = BIDS_V3
+    let bid_order = BIDS_V3
         .load(deps.storage, id.as_bytes())
         .map_err(|error| ContractError::LoadOrderFailed { error })?;
 
@@ -865,6 +1178,19 @@ fn reverse_bid(
         return Err(ContractError::Unauthorized);
     }
 
+    reverse_bid_order(deps, &env, &contract_info, bid_order, action, cancel_size)
+}
+
+/// Returns all or part of a bid order's remaining quote and fee to its owner, then updates or
+/// removes the order.
+pub(crate) fn reverse_bid_order(
+    deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    mut bid_order: BidOrderV3,
+    action: ContractAction,
+    cancel_size: Option<Uint128>,
+) -> Result<Response, ContractError> {
     // determine the effective cancel size
     let effective_cancel_size = match cancel_size {
         None => bid_order.get_remaining_base(),
@@ -960,7 +1286,7 @@ fn reverse_bid(
 
     response = response.add_attributes(vec![
         attr("action", action.to_string()),
-        attr("id", id),
+        attr("id", &bid_order.id),
         attr("reverse_size", effective_cancel_size),
     ]);
 
@@ -974,7 +1300,7 @@ fn reverse_bid(
                 bid_order.quote.denom.to_owned(),
                 bid_order.owner.to_owned(),
                 env.contract.address.to_owned(),
-                env.contract.address,
+                env.contract.address.to_owned(),
             );
         }
     }
@@ -982,7 +1308,7 @@ fn reverse_bid(
     // remove the bid order from storage if remaining size is 0, otherwise, store updated order
     match bid_order.get_remaining_base().is_zero() {
         true => {
-            BIDS_V3.remove(deps.storage, bid_order.id.as_bytes());
+            BIDS_V3.remove(deps.storage, bid_order.id.as_bytes())?;
             response = response.add_attributes(vec![attr("order_open", "false")]);
         }
         false => {
@@ -994,7 +1320,8 @@ fn reverse_bid(
     Ok(response)
 }
 
-// match and execute an ask and bid order
+// match and execute an ask and bid order, a match outside the price band is rejected without
+// failing and returns a `reject_match` action instead of a fill
 fn execute_match(
     deps: DepsMut,
     env: Env,
@@ -1016,7 +1343,31 @@ fn execute_match(
         return Err(ContractError::ExecuteWithFunds);
     }
 
-    let mut ask_order = ASKS_V1
+    settle_match(
+        deps,
+        &env,
+        &contract_info,
+        &ask_id,
+        &bid_id,
+        price,
+        execute_size,
+    )
+}
+
+/// Settles a match of an ask and bid order at the given price and size.
+///
+/// Transfers base to the bidder and net proceeds to the asker (or approver), pays fees, refunds
+/// the bidder for any price improvement, and updates or removes both orders.
+pub(crate) fn settle_match(
+    mut deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    ask_id: &str,
+    bid_id: &str,
+    price: String,
+    execute_size: Uint128,
+) -> Result<Response, ContractError> {
+    let ask_order = ASKS_V1
         .load(deps.storage, ask_id.as_bytes())
         .map_err(|error| ContractError::LoadOrderFailed { error })?;
 
@@ -1024,6 +1375,11 @@ fn execute_match(
         .load(deps.storage, bid_id.as_bytes())
         .map_err(|error| ContractError::LoadOrderFailed { error })?;
 
+    // expired orders can no longer be matched
+    if ask_order.is_expired(env.block.time) || bid_order.is_expired(env.block.time) {
+        return Err(ContractError::OrderExpired);
+    }
+
     // Validate the requested quote denom in the ask order matches the offered quote denom in the bid order
     if ask_order.quote.ne(&bid_order.quote.denom) {
         return Err(ContractError::UnsupportedQuoteDenom);
@@ -1068,204 +1424,55 @@ fn execute_match(
         return Err(ContractError::InvalidExecuteSize);
     }
 
-    // calculate gross proceeds using execute price, (price * size), error if overflows
-    let actual_gross_proceeds = execute_price
-        .checked_mul(Decimal::from(execute_size.u128()))
-        .ok_or(ContractError::TotalOverflow)?;
-
-    // error if gross proceeds is not an integer
-    if actual_gross_proceeds.fract().ne(&Decimal::zero()) {
-        return Err(ContractError::NonIntegerTotal);
+    // matching is halted by the circuit breaker, a match outside the price band is rejected and
+    // recorded as a breach rather than failing, so that the breach is kept
+    match check_price_band(
+        deps.storage,
+        &contract_info.price_band,
+        &ask_order.quote,
+        execute_price,
+        env.block.time,
+    )? {
+        PriceBandCheck::Within => {}
+        PriceBandCheck::Outside => {
+            return record_match_price_band_breach(
+                deps,
+                env,
+                contract_info,
+                &ask_order.quote,
+                ask_id,
+                bid_id,
+                execute_price,
+            )
+        }
+        PriceBandCheck::Halted { halted_until } => {
+            return Err(ContractError::MarketHalted {
+                halted_until: halted_until.to_string(),
+            })
+        }
     }
 
-    let mut net_proceeds = Uint128::new(
-        actual_gross_proceeds
-            .to_u128()
-            .ok_or(ContractError::TotalOverflow)?,
-    );
-
-    ask_order.size -= execute_size;
-
-    if let AskOrderClass::Convertible {
-        status: AskOrderStatus::Ready { converted_base, .. },
-    } = &mut ask_order.class
-    {
-        converted_base.amount = ask_order.size;
+    // orders of the same owner never trade, the self-trade prevention policy decides the outcome
+    if ask_order.owner.eq(&bid_order.owner) {
+        return prevent_self_trade(deps, env, contract_info, ask_order, bid_order, execute_size);
     }
 
-    // is base a restricted marker
-    let is_base_restricted_marker = is_restricted_marker(&deps.querier, ask_order.base.clone());
-
     // is quote a restricted marker
     let is_quote_restricted_marker =
         is_restricted_marker(&deps.querier, bid_order.quote.denom.clone());
 
-    let mut response = Response::new();
-    response = response.add_attributes(vec![
-        attr("action", ContractAction::Execute.to_string()),
-        attr("ask_id", &ask_id),
-        attr("bid_id", &bid_id),
-        attr("base", &bid_order.base.denom),
-        attr("quote", &ask_order.quote),
-        attr("price", &execute_price.to_string()),
-        attr("size", &execute_size.to_string()),
-    ]);
-
-    // calculate ask fees and create message if applicable
-    let ask_fee = match contract_info.ask_fee_info {
-        // calculate ask fee using total
-        Some(ask_fee_info) => {
-            match Decimal::from_str(&ask_fee_info.rate)
-                .map_err(|_| ContractError::InvalidFields {
-                    fields: vec![String::from("ContractInfo.ask_fee_info.rate")],
-                })?
-                .checked_mul(actual_gross_proceeds)
-                .ok_or(ContractError::TotalOverflow)?
-                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
-                .to_u128()
-                .ok_or(ContractError::TotalOverflow)?
-            {
-                0u128 => None,
-                fee_total => {
-                    let ask_fee = Coin {
-                        denom: bid_order.quote.denom.to_owned(),
-                        amount: Uint128::new(fee_total),
-                    };
-
-                    response = add_transfer(
-                        response,
-                        is_quote_restricted_marker.to_owned(),
-                        fee_total,
-                        bid_order.quote.denom.to_owned(),
-                        ask_fee_info.account,
-                        env.contract.address.to_owned(),
-                        env.contract.address.to_owned(),
-                    );
-
-                    // subtract the fees and assign to net proceeds
-                    net_proceeds =
-                        net_proceeds
-                            .checked_sub(Uint128::new(fee_total))
-                            .map_err(|error| {
-                                ContractError::Std(StdError::Overflow { source: error })
-                            })?;
-
-                    Some(ask_fee)
-                }
-            }
-        }
-        None => None,
-    };
-
-    response = response.add_attribute(
-        "ask_fee",
-        match ask_fee {
-            None => Uint128::zero(),
-            Some(fee) => fee.amount,
-        },
-    );
-
-    // get bid fees and create message if applicable
-    let actual_bid_fee = match &bid_order.fee {
-        Some(_) => bid_order.calculate_fee(Uint128::new(
-            actual_gross_proceeds
-                .to_u128()
-                .ok_or(ContractError::TotalOverflow)?,
-        ))?,
-        None => None,
-    };
-
-    // add bid fee message
-    match &actual_bid_fee {
-        Some(bid_fee) => match contract_info.bid_fee_info {
-            Some(bid_fee_info) => {
-                response = add_transfer(
-                    response,
-                    is_quote_restricted_marker.to_owned(),
-                    bid_fee.amount.to_owned().u128(),
-                    bid_fee.denom.to_owned(),
-                    bid_fee_info.account,
-                    env.contract.address.to_owned(),
-                    env.contract.address.to_owned(),
-                );
-            }
-            None => return Err(ContractError::BidFeeAccountMissing),
-        },
-        None => (),
-    }
-
-    response = response.add_attribute(
-        "bid_fee",
-        match &actual_bid_fee {
-            None => Uint128::zero(),
-            Some(fee) => fee.amount,
-        },
-    );
-
-    // add 'send quote to asker' and 'send base to bidder' messages
-    match &ask_order.class {
-        AskOrderClass::Basic => {
-            response = add_transfer(
-                response,
-                is_quote_restricted_marker.to_owned(),
-                net_proceeds.into(),
-                bid_order.quote.denom.to_owned(),
-                ask_order.owner.to_owned(),
-                env.contract.address.to_owned(),
-                env.contract.address.to_owned(),
-            );
-            response = add_transfer(
-                response,
-                is_base_restricted_marker.to_owned(),
-                execute_size.into(),
-                ask_order.base.to_owned(),
-                bid_order.owner.to_owned(),
-                env.contract.address.to_owned(),
-                env.contract.address.to_owned(),
-            );
-        }
-        AskOrderClass::Convertible {
-            status:
-                AskOrderStatus::Ready {
-                    approver,
-                    converted_base,
-                },
-        } => {
-            response = add_transfer(
-                response,
-                is_base_restricted_marker.to_owned(),
-                execute_size.into(),
-                converted_base.to_owned().denom,
-                bid_order.owner.to_owned(),
-                env.contract.address.to_owned(),
-                env.contract.address.to_owned(),
-            );
-            response = add_transfer(
-                response,
-                is_base_restricted_marker,
-                execute_size.into(),
-                ask_order.base.to_owned(),
-                approver.to_owned(),
-                env.contract.address.to_owned(),
-                env.contract.address.to_owned(),
-            );
-
-            response = add_transfer(
-                response,
-                is_quote_restricted_marker.to_owned(),
-                net_proceeds.into(),
-                bid_order.quote.denom.clone(),
-                approver.to_owned(),
-                env.contract.address.to_owned(),
-                env.contract.address.to_owned(),
-            );
-        }
-        AskOrderClass::Convertible { status } => {
-            return Err(ContractError::AskOrderNotReady {
-                current_status: format!("{:?}", status),
-            });
-        }
-    };
+    // price improvement refunds are calculated on the bid as it was before the fill
+    let unfilled_bid_order = bid_order.to_owned();
+
+    let (mut response, settled_fill) = settle_fill(
+        deps.branch(),
+        env,
+        contract_info,
+        ask_order,
+        &mut bid_order,
+        price,
+        execute_size,
+    )?;
 
     // determine refunds to bidder
     if execute_price.lt(&bid_price) {
@@ -1281,22 +1488,25 @@ fn execute_match(
 
         // calculate refund
         let bid_quote_refund = original_gross_proceeds
-            .checked_sub(actual_gross_proceeds)
+            .checked_sub(settled_fill.gross_proceeds)
             .ok_or(ContractError::TotalOverflow)?
             .to_u128()
             .ok_or(ContractError::NonIntegerTotal)?;
 
         // calculate fee based on original gross proceeds
         let bid_fee_refund = {
-            let original_bid_fee = bid_order.calculate_fee(Uint128::new(
+            let original_bid_fee = unfilled_bid_order.calculate_fee(Uint128::new(
                 original_gross_proceeds
                     .to_u128()
                     .ok_or(ContractError::TotalOverflow)?,
             ))?;
 
-            match (&actual_bid_fee, original_bid_fee) {
-                (Some(actual_bid_fee), Some(mut original_bid_fee)) => {
-                    let refund_amount = original_bid_fee.amount - actual_bid_fee.amount;
+            match (&settled_fill.escrowed_bid_fee, original_bid_fee) {
+                (Some(escrowed_bid_fee), Some(mut original_bid_fee)) => {
+                    let refund_amount = min(
+                        original_bid_fee.amount - escrowed_bid_fee.amount,
+                        settled_fill.unspent_bid_fee,
+                    );
 
                     if refund_amount.gt(&Uint128::zero()) {
                         original_bid_fee.amount = refund_amount;
@@ -1327,28 +1537,11 @@ fn execute_match(
                     fee_refund.denom.to_owned(),
                     bid_order.owner.to_owned(),
                     env.contract.address.to_owned(),
-                    env.contract.address,
+                    env.contract.address.to_owned(),
                 );
             }
         }
 
-        bid_order.update_remaining_amounts(&Action::Fill {
-            base: Coin {
-                denom: bid_order.base.denom.to_owned(),
-                amount: execute_size,
-            },
-            fee: actual_bid_fee,
-            price,
-            quote: Coin {
-                denom: bid_order.quote.denom.to_owned(),
-                amount: Uint128::new(
-                    actual_gross_proceeds
-                        .to_u128()
-                        .ok_or(ContractError::TotalOverflow)?,
-                ),
-            },
-        })?;
-
         bid_order.update_remaining_amounts(&Action::Refund {
             fee: bid_fee_refund,
             quote: Coin {
@@ -1356,41 +1549,714 @@ fn execute_match(
                 amount: Uint128::new(bid_quote_refund),
             },
         })?;
+    }
+
+    // finally update or remove the bid order from storage
+    if bid_order.get_remaining_base().eq(&Uint128::zero()) {
+        BIDS_V3.remove(deps.storage, bid_id.as_bytes())?;
     } else {
-        bid_order.update_remaining_amounts(&Action::Fill {
-            base: Coin {
-                denom: bid_order.base.denom.to_owned(),
-                amount: execute_size,
-            },
-            fee: actual_bid_fee,
-            price,
+        BIDS_V3.update(deps.storage, bid_id.as_bytes(), |_| -> StdResult<_> {
+            Ok(bid_order)
+        })?;
+    }
+
+    Ok(response)
+}
+
+/// A fill settled by `settle_fill`
+pub(crate) struct SettledFill {
+    /// Quote traded by the fill, the execute price times the size
+    pub gross_proceeds: Decimal,
+    /// Bid fee escrowed for the quote traded
+    pub escrowed_bid_fee: Option<Coin>,
+    /// Escrowed bid fee left after the fill, before any refund for price improvement
+    pub unspent_bid_fee: Uint128,
+}
+
+/// Settles a single fill of an ask and bid order at the given price and size, shared by matches
+/// of resting orders and market bids.
+///
+/// Transfers base to the bidder and net proceeds to the asker (or approver), pays the fees of the
+/// side that rested first as maker, records the fill, both accounts' volume and the trade price,
+/// and updates or removes the ask. The bid is updated with the fill but not stored, which is left
+/// to the caller.
+pub(crate) fn settle_fill(
+    deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    mut ask_order: AskOrderV1,
+    bid_order: &mut BidOrderV3,
+    price: String,
+    execute_size: Uint128,
+) -> Result<(Response, SettledFill), ContractError> {
+    let execute_price = Decimal::from_str(&price).map_err(|_| ContractError::InvalidFields {
+        fields: vec![String::from("ExecuteMsg.price")],
+    })?;
+
+    // calculate gross proceeds using execute price, (price * size), error if overflows
+    let gross_proceeds = execute_price
+        .checked_mul(Decimal::from(execute_size.u128()))
+        .ok_or(ContractError::TotalOverflow)?;
+
+    // error if gross proceeds is not an integer
+    if gross_proceeds.fract().ne(&Decimal::zero()) {
+        return Err(ContractError::NonIntegerTotal);
+    }
+
+    let gross_proceeds_amount = Uint128::new(
+        gross_proceeds
+            .to_u128()
+            .ok_or(ContractError::TotalOverflow)?,
+    );
+    let mut net_proceeds = gross_proceeds_amount;
+
+    ask_order.size -= execute_size;
+
+    if let AskOrderClass::Convertible {
+        status: AskOrderStatus::Ready { converted_base, .. },
+    } = &mut ask_order.class
+    {
+        converted_base.amount = ask_order.size;
+    }
+
+    // is base a restricted marker
+    let is_base_restricted_marker = is_restricted_marker(&deps.querier, ask_order.base.clone());
+
+    // is quote a restricted marker
+    let is_quote_restricted_marker =
+        is_restricted_marker(&deps.querier, bid_order.quote.denom.clone());
+
+    let mut response = Response::new();
+    response = response.add_attributes(vec![
+        attr("action", ContractAction::Execute.to_string()),
+        attr("ask_id", &ask_order.id),
+        attr("bid_id", &bid_order.id),
+        attr("base", &bid_order.base.denom),
+        attr("quote", &ask_order.quote),
+        attr("price", &execute_price.to_string()),
+        attr("size", &execute_size.to_string()),
+    ]);
+
+    // the escrowed bid fee is pro-rated on the quote spent by this fill
+    let escrowed_bid_fee = bid_order.calculate_fee(gross_proceeds_amount)?;
+
+    // fee tiers are reached by the volume traded before this fill
+    let ask_fee_tier = get_volume_fee_tier(
+        deps.storage,
+        contract_info,
+        &ask_order.owner,
+        env.block.time,
+    )?;
+    let bid_fee_tier = get_volume_fee_tier(
+        deps.storage,
+        contract_info,
+        &bid_order.owner,
+        env.block.time,
+    )?;
+
+    // calculate the fees and create messages if applicable, the order that rested first is the maker
+    // and each order is charged by the fee terms it was created with
+    let fill_fees;
+    (response, fill_fees) = add_fill_fees(
+        response,
+        deps.storage,
+        &ask_order
+            .fee_terms
+            .to_owned()
+            .unwrap_or_else(|| contract_info.ask_fee_terms()),
+        &bid_order
+            .fee_terms
+            .to_owned()
+            .unwrap_or_else(|| contract_info.bid_fee_terms()),
+        ask_fee_tier.as_ref(),
+        bid_fee_tier.as_ref(),
+        &bid_order.quote.denom,
+        gross_proceeds,
+        ask_order.sequence <= bid_order.sequence,
+        &escrowed_bid_fee,
+        bid_order.get_remaining_fee(),
+    )?;
+
+    // a minimum fee may charge more than the escrowed fee pro-rated on this fill, the escrowed fee
+    // left afterwards limits the fee refunded for price improvement
+    let unspent_bid_fee = bid_order.get_remaining_fee().saturating_sub(max(
+        fill_fees
+            .bid_fee
+            .as_ref()
+            .map(|fee| fee.amount)
+            .unwrap_or_default(),
+        escrowed_bid_fee
+            .as_ref()
+            .map(|fee| fee.amount)
+            .unwrap_or_default(),
+    ));
+
+    // subtract the fees and add any maker rebate to the net proceeds
+    if let Some(ask_fee) = &fill_fees.ask_fee {
+        net_proceeds = net_proceeds
+            .checked_sub(ask_fee.amount)
+            .map_err(|error| ContractError::Std(StdError::Overflow { source: error }))?;
+    }
+    net_proceeds += fill_fees.ask_rebate;
+
+    // add 'send quote to asker' and 'send base to bidder' messages
+    response = add_fill_transfers(
+        response,
+        env,
+        &ask_order,
+        &bid_order.owner,
+        &bid_order.quote.denom,
+        net_proceeds,
+        execute_size,
+        is_base_restricted_marker,
+        is_quote_restricted_marker,
+    )?;
+
+    // return the escrowed bid fee left unused by the maker rate along with any maker rebate
+    let bid_maker_refund = fill_fees
+        .bid_fee_refund
+        .as_ref()
+        .map(|fee| fee.amount)
+        .unwrap_or_default()
+        + fill_fees.bid_rebate;
+    if !bid_maker_refund.is_zero() {
+        response = add_transfer(
+            response,
+            is_quote_restricted_marker,
+            bid_maker_refund.u128(),
+            bid_order.quote.denom.to_owned(),
+            bid_order.owner.to_owned(),
+            env.contract.address.to_owned(),
+            env.contract.address.to_owned(),
+        );
+    }
+
+    let fill = Action::Fill {
+        base: Coin {
+            denom: bid_order.base.denom.to_owned(),
+            amount: execute_size,
+        },
+        fee: fill_fees.bid_fee,
+        price,
+        quote: Coin {
+            denom: bid_order.quote.denom.to_owned(),
+            amount: gross_proceeds_amount,
+        },
+    };
+
+    bid_order.update_remaining_amounts(&fill)?;
+
+    if let Some(bid_fee_refund) = fill_fees.bid_fee_refund {
+        bid_order.update_remaining_amounts(&Action::Refund {
             quote: Coin {
-                denom: bid_order.quote.denom.to_owned(),
-                amount: Uint128::new(
-                    actual_gross_proceeds
-                        .to_u128()
-                        .ok_or(ContractError::TotalOverflow)?,
-                ),
+                denom: bid_fee_refund.denom.to_owned(),
+                amount: Uint128::zero(),
             },
+            fee: Some(bid_fee_refund),
         })?;
     }
 
-    // finally update or remove the orders from storage
+    // keep the fill in the order history
+    record_fill(
+        deps.storage,
+        env.block.to_owned().into(),
+        &ask_order.id,
+        &bid_order.id,
+        fill,
+    )?;
+
+    // add the traded quote to both accounts' rolling volume
+    record_volume(
+        deps.storage,
+        &ask_order.owner,
+        env.block.time,
+        gross_proceeds_amount,
+    )?;
+    record_volume(
+        deps.storage,
+        &bid_order.owner,
+        env.block.time,
+        gross_proceeds_amount,
+    )?;
+
+    // the fill price is the center of the market's price band
+    record_trade_price(deps.storage, &ask_order.quote, execute_price)?;
+
+    // update or remove the ask order from storage
     if ask_order.size.is_zero() {
-        ASKS_V1.remove(deps.storage, ask_id.as_bytes());
+        ASKS_V1.remove(deps.storage, ask_order.id.as_bytes())?;
     } else {
+        let ask_id = ask_order.id.to_owned();
         ASKS_V1.update(deps.storage, ask_id.as_bytes(), |_| -> StdResult<_> {
             Ok(ask_order)
         })?;
     }
 
-    if bid_order.get_remaining_base().eq(&Uint128::zero()) {
-        BIDS_V3.remove(deps.storage, bid_id.as_bytes());
+    Ok((
+        response,
+        SettledFill {
+            gross_proceeds,
+            escrowed_bid_fee,
+            unspent_bid_fee,
+        },
+    ))
+}
+
+/// Fees of a single fill, all in the quote denom.
+pub(crate) struct FillFees {
+    /// Fee charged to the asker, deducted from its proceeds
+    pub ask_fee: Option<Coin>,
+    /// Fee charged to the bidder out of its escrowed fee
+    pub bid_fee: Option<Coin>,
+    /// Maker rebate paid to the asker along with its proceeds
+    pub ask_rebate: Uint128,
+    /// Escrowed bid fee left unused by a lower maker or fee tier rate, returned to the bidder
+    pub bid_fee_refund: Option<Coin>,
+    /// Maker rebate paid to the bidder
+    pub bid_rebate: Uint128,
+}
+
+/// Records a match rejected for being outside the price band, halting matching of the quote denom
+/// once the band's breach limit is reached.
+///
+/// The rejection doesn't fail, so that the breach is kept, and is marked by a `reject_match` action.
+pub(crate) fn record_match_price_band_breach(
+    deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    quote: &str,
+    ask_id: &str,
+    bid_id: &str,
+    execute_price: Decimal,
+) -> Result<Response, ContractError> {
+    let price_band = match &contract_info.price_band {
+        Some(price_band) => price_band,
+        None => return Ok(Response::new()),
+    };
+
+    let state = record_price_band_breach(deps.storage, price_band, quote, env.block.time)?;
+
+    let mut response = Response::new().add_attributes(vec![
+        attr("action", ContractAction::RejectMatch.to_string()),
+        attr("price_band_breach", "true"),
+        attr("ask_id", ask_id),
+        attr("bid_id", bid_id),
+        attr("quote", quote),
+        attr("price", execute_price.to_string()),
+        attr("breach_count", state.breach_count.to_string()),
+    ]);
+
+    if let Some(halted_until) = state
+        .halted_until
+        .filter(|_| state.is_halted(env.block.time))
+    {
+        response = response.add_attribute("halted_until", halted_until.to_string());
+    }
+
+    Ok(response)
+}
+
+/// Applies the contract's self-trade prevention policy to a match of an ask and bid of the same
+/// owner, canceling or reducing the orders instead of trading them.
+fn prevent_self_trade(
+    mut deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    ask_order: AskOrderV1,
+    bid_order: BidOrderV3,
+    execute_size: Uint128,
+) -> Result<Response, ContractError> {
+    let (cancel_ask, cancel_bid, cancel_size) = get_self_trade_cancels(
+        &contract_info.self_trade_prevention,
+        &ask_order,
+        &bid_order,
+        execute_size,
+    )?;
+
+    let mut response = Response::new().add_attributes(vec![
+        attr(
+            "self_trade_prevention",
+            contract_info.self_trade_prevention.to_string(),
+        ),
+        attr("ask_id", &ask_order.id),
+        attr("bid_id", &bid_order.id),
+    ]);
+
+    if cancel_ask {
+        let cancel_response = reverse_ask_order(
+            deps.branch(),
+            env,
+            contract_info,
+            ask_order,
+            ContractAction::CancelAsk,
+            cancel_size,
+        )?;
+        response = response
+            .add_submessages(cancel_response.messages)
+            .add_attributes(cancel_response.attributes);
+    }
+
+    if cancel_bid {
+        let cancel_response = reverse_bid_order(
+            deps,
+            env,
+            contract_info,
+            bid_order,
+            ContractAction::CancelBid,
+            cancel_size,
+        )?;
+        response = response
+            .add_submessages(cancel_response.messages)
+            .add_attributes(cancel_response.attributes);
+    }
+
+    Ok(response)
+}
+
+/// Returns whether the self-trade prevention policy cancels the ask and the bid of a self-trade,
+/// and the size canceled from each, all of their remaining size when `None`.
+pub(crate) fn get_self_trade_cancels(
+    self_trade_prevention: &SelfTradePrevention,
+    ask_order: &AskOrderV1,
+    bid_order: &BidOrderV3,
+    execute_size: Uint128,
+) -> Result<(bool, bool, Option<Uint128>), ContractError> {
+    // the resting order is the one created first
+    let is_ask_resting = ask_order.sequence <= bid_order.sequence;
+
+    match self_trade_prevention {
+        SelfTradePrevention::Reject => Err(ContractError::SelfTrade),
+        SelfTradePrevention::CancelResting => Ok((is_ask_resting, !is_ask_resting, None)),
+        SelfTradePrevention::CancelNewest => Ok((!is_ask_resting, is_ask_resting, None)),
+        SelfTradePrevention::DecrementBoth => Ok((true, true, Some(execute_size))),
+    }
+}
+
+/// Calculates a fee rate applied to a fill's gross proceeds, rounded to a whole amount.
+fn calculate_fee_amount(
+    rate: &str,
+    field: &str,
+    gross_proceeds: Decimal,
+    strategy: RoundingStrategy,
+) -> Result<Uint128, ContractError> {
+    Decimal::from_str(rate)
+        .map_err(|_| ContractError::InvalidFields {
+            fields: vec![field.to_owned()],
+        })?
+        .abs()
+        .checked_mul(gross_proceeds)
+        .ok_or(ContractError::TotalOverflow)?
+        .round_dp_with_strategy(0, strategy)
+        .to_u128()
+        .map(Uint128::new)
+        .ok_or(ContractError::TotalOverflow)
+}
+
+/// Calculates the maker or taker fee of a fill, or the maker rebate for a negative maker rate.
+///
+/// Rebates are rounded down.
+fn calculate_maker_taker_fee(
+    fee_info: &MakerTakerFeeInfo,
+    is_maker: bool,
+    gross_proceeds: Decimal,
+) -> Result<(Uint128, Uint128), ContractError> {
+    if !is_maker {
+        let fee = calculate_fee_amount(
+            &fee_info.taker_rate,
+            "FeeTerms.maker_taker_fee_info.taker_rate",
+            gross_proceeds,
+            RoundingStrategy::MidpointAwayFromZero,
+        )?;
+        return Ok((fee, Uint128::zero()));
+    }
+
+    if fee_info.maker_rate.trim_start().starts_with('-') {
+        let rebate = calculate_fee_amount(
+            &fee_info.maker_rate,
+            "FeeTerms.maker_taker_fee_info.maker_rate",
+            gross_proceeds,
+            RoundingStrategy::ToZero,
+        )?;
+        Ok((Uint128::zero(), rebate))
     } else {
-        BIDS_V3.update(deps.storage, bid_id.as_bytes(), |_| -> StdResult<_> {
-            Ok(bid_order)
-        })?;
+        let fee = calculate_fee_amount(
+            &fee_info.maker_rate,
+            "FeeTerms.maker_taker_fee_info.maker_rate",
+            gross_proceeds,
+            RoundingStrategy::MidpointAwayFromZero,
+        )?;
+        Ok((fee, Uint128::zero()))
     }
+}
+
+/// Calculates the fees of a fill from the fee terms of each order and accrues them to the fee
+/// accounts, which withdraw them with `WithdrawFees`.
+///
+/// Without a maker/taker fee schedule the asker pays its ask fee rate and the bidder pays its
+/// escrowed fee, each lowered to the rate of the account's fee tier when that is less and then
+/// limited by the side's fee policy, and the fee is split between the policy's recipients. With a
+/// schedule the side pays the maker or taker rate instead, and a maker rebate is taken out of the
+/// other side's taker fee before the remainder goes to its fee account.
+///
+/// The ask fee can't exceed the gross proceeds, and the bid fee can't exceed the bid's remaining
+/// escrowed fee.
+#[allow(clippy::too_many_arguments)]
+fn add_fill_fees(
+    mut response: Response,
+    storage: &mut dyn Storage,
+    ask_fee_terms: &FeeTerms,
+    bid_fee_terms: &FeeTerms,
+    ask_fee_tier: Option<&FeeTier>,
+    bid_fee_tier: Option<&FeeTier>,
+    quote_denom: &str,
+    gross_proceeds: Decimal,
+    ask_is_maker: bool,
+    escrowed_bid_fee: &Option<Coin>,
+    remaining_bid_fee: Uint128,
+) -> Result<(Response, FillFees), ContractError> {
+    let gross_proceeds_amount = gross_proceeds
+        .to_u128()
+        .map(Uint128::new)
+        .ok_or(ContractError::TotalOverflow)?;
+
+    // calculate ask fee and rebate using the gross proceeds
+    let (ask_fee, ask_rebate, ask_fee_recipients) =
+        match (&ask_fee_terms.maker_taker_fee_info, &ask_fee_terms.fee_info) {
+            (Some(maker_taker_fee_info), _) => {
+                let (fee, rebate) =
+                    calculate_maker_taker_fee(maker_taker_fee_info, ask_is_maker, gross_proceeds)?;
+                (fee, rebate, Some(maker_taker_fee_info.get_recipients()))
+            }
+            (None, Some(ask_fee_info)) => {
+                let mut fee = calculate_fee_amount(
+                    &ask_fee_info.rate,
+                    "FeeTerms.fee_info.rate",
+                    gross_proceeds,
+                    RoundingStrategy::MidpointAwayFromZero,
+                )?;
+                if let Some(fee_tier) = ask_fee_tier {
+                    fee = min(
+                        fee,
+                        calculate_fee_amount(
+                            &fee_tier.ask_rate,
+                            "ContractInfo.fee_tiers.ask_rate",
+                            gross_proceeds,
+                            RoundingStrategy::MidpointAwayFromZero,
+                        )?,
+                    );
+                }
+                let fee = min(
+                    ask_fee_info.policy.limit_fee(quote_denom, fee),
+                    gross_proceeds_amount,
+                );
+                (fee, Uint128::zero(), Some(ask_fee_info.get_recipients()))
+            }
+            (None, None) => (Uint128::zero(), Uint128::zero(), None),
+        };
+
+    // bids escrow their fee at the taker (or bid fee) rate, only a maker or a fee tier may be
+    // charged less
+    let escrowed_bid_fee_amount = escrowed_bid_fee
+        .as_ref()
+        .map(|fee| fee.amount)
+        .unwrap_or_default();
+    let (bid_fee, bid_rebate, bid_fee_recipients) =
+        match (&bid_fee_terms.maker_taker_fee_info, &bid_fee_terms.fee_info) {
+            (Some(maker_taker_fee_info), _) if !ask_is_maker => {
+                let (fee, rebate) =
+                    calculate_maker_taker_fee(maker_taker_fee_info, true, gross_proceeds)?;
+                (
+                    min(fee, escrowed_bid_fee_amount),
+                    rebate,
+                    Some(maker_taker_fee_info.get_recipients()),
+                )
+            }
+            (Some(maker_taker_fee_info), _) => (
+                escrowed_bid_fee_amount,
+                Uint128::zero(),
+                Some(maker_taker_fee_info.get_recipients()),
+            ),
+            (None, Some(bid_fee_info)) => {
+                let mut fee = escrowed_bid_fee_amount;
+                if let Some(fee_tier) = bid_fee_tier {
+                    fee = min(
+                        fee,
+                        calculate_fee_amount(
+                            &fee_tier.bid_rate,
+                            "ContractInfo.fee_tiers.bid_rate",
+                            gross_proceeds,
+                            RoundingStrategy::MidpointAwayFromZero,
+                        )?,
+                    );
+                }
+                let fee = min(
+                    bid_fee_info.policy.limit_fee(quote_denom, fee),
+                    remaining_bid_fee,
+                );
+                (fee, Uint128::zero(), Some(bid_fee_info.get_recipients()))
+            }
+            (None, None) => (escrowed_bid_fee_amount, Uint128::zero(), None),
+        };
+
+    for (amount, recipients) in [(ask_fee, ask_fee_recipients), (bid_fee, bid_fee_recipients)] {
+        if amount.is_zero() {
+            continue;
+        }
+        match recipients {
+            Some(recipients) => {
+                for (account, share) in split_fee(&recipients, amount) {
+                    if share.is_zero() {
+                        continue;
+                    }
+                    accrue_fee(storage, &account, quote_denom, share)?;
+                }
+            }
+            None => return Err(ContractError::BidFeeAccountMissing),
+        }
+    }
+
+    // a maker rebate is paid out of the fees accrued to the maker's fee account, including the
+    // taker fee of this fill, and skipped when they don't cover it
+    let maker_rebate = ask_rebate + bid_rebate;
+    let ask_rebate = pay_maker_rebate(storage, ask_fee_terms, quote_denom, ask_rebate)?;
+    let bid_rebate = pay_maker_rebate(storage, bid_fee_terms, quote_denom, bid_rebate)?;
+
+    response = response.add_attributes(vec![
+        attr("ask_fee", ask_fee.to_string()),
+        attr("bid_fee", bid_fee.to_string()),
+    ]);
+
+    if ask_fee_terms.maker_taker_fee_info.is_some() || bid_fee_terms.maker_taker_fee_info.is_some()
+    {
+        response = response.add_attributes(vec![
+            attr("maker", if ask_is_maker { "ask" } else { "bid" }),
+            attr("maker_rebate", (ask_rebate + bid_rebate).to_string()),
+        ]);
+    }
+
+    let skipped_rebate = maker_rebate - ask_rebate - bid_rebate;
+    if !skipped_rebate.is_zero() {
+        response = response.add_attribute("maker_rebate_skipped", skipped_rebate.to_string());
+    }
+
+    let to_coin = |amount: Uint128| {
+        (!amount.is_zero()).then(|| Coin {
+            denom: quote_denom.to_owned(),
+            amount,
+        })
+    };
+
+    Ok((
+        response,
+        FillFees {
+            ask_fee: to_coin(ask_fee),
+            bid_fee: to_coin(bid_fee),
+            ask_rebate,
+            bid_fee_refund: to_coin(escrowed_bid_fee_amount.saturating_sub(bid_fee)),
+            bid_rebate,
+        },
+    ))
+}
+
+/// Pays a maker rebate out of the fees accrued to the maker/taker fee account of the maker's fee
+/// terms, returning the rebate paid, nothing when the accrued fees don't cover it.
+fn pay_maker_rebate(
+    storage: &mut dyn Storage,
+    fee_terms: &FeeTerms,
+    quote_denom: &str,
+    rebate: Uint128,
+) -> Result<Uint128, ContractError> {
+    match &fee_terms.maker_taker_fee_info {
+        Some(maker_taker_fee_info) if !rebate.is_zero() => {
+            match pay_from_accrued_fee(storage, &maker_taker_fee_info.account, quote_denom, rebate)?
+            {
+                true => Ok(rebate),
+                false => Ok(Uint128::zero()),
+            }
+        }
+        _ => Ok(Uint128::zero()),
+    }
+}
+
+/// Adds the transfers of a fill's net proceeds to the asker and base to the bidder.
+///
+/// For convertible asks the bidder receives the converted base, and the approver receives the
+/// ask base and the net proceeds.
+#[allow(clippy::too_many_arguments)]
+fn add_fill_transfers(
+    mut response: Response,
+    env: &Env,
+    ask_order: &AskOrderV1,
+    bidder: &Addr,
+    quote_denom: &str,
+    net_proceeds: Uint128,
+    execute_size: Uint128,
+    is_base_restricted_marker: bool,
+    is_quote_restricted_marker: bool,
+) -> Result<Response, ContractError> {
+    match &ask_order.class {
+        AskOrderClass::Basic => {
+            response = add_transfer(
+                response,
+                is_quote_restricted_marker,
+                net_proceeds.into(),
+                quote_denom.to_owned(),
+                ask_order.owner.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+            response = add_transfer(
+                response,
+                is_base_restricted_marker,
+                execute_size.into(),
+                ask_order.base.to_owned(),
+                bidder.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+        }
+        AskOrderClass::Convertible {
+            status:
+                AskOrderStatus::Ready {
+                    approver,
+                    converted_base,
+                },
+        } => {
+            response = add_transfer(
+                response,
+                is_base_restricted_marker,
+                execute_size.into(),
+                converted_base.to_owned().denom,
+                bidder.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+            response = add_transfer(
+                response,
+                is_base_restricted_marker,
+                execute_size.into(),
+                ask_order.base.to_owned(),
+                approver.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+
+            response = add_transfer(
+                response,
+                is_quote_restricted_marker,
+                net_proceeds.into(),
+                quote_denom.to_owned(),
+                approver.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+        }
+        AskOrderClass::Convertible { status } => {
+            return Err(ContractError::AskOrderNotReady {
+                current_status: format!("{:?}", status),
+            });
+        }
+    };
 
     Ok(response)
 }
@@ -1403,15 +2269,15 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
     // build response
     let mut response: Response = Response::new();
 
-    // migrate contract_info
-    migrate_contract_info(deps.branch(), &msg)?;
-
-    // migrate ask orders
+    // migrate ask orders, before contract_info so orders keep the fee terms they were created with
     migrate_ask_orders(deps.branch(), &msg)?;
 
     // migrate bid orders
     response = migrate_bid_orders(deps.branch(), env, &msg, response)?;
 
+    // migrate contract_info
+    migrate_contract_info(deps.branch(), &msg)?;
+
     // lastly, migrate version_info
     migrate_version_info(deps.branch())?;
 
@@ -1420,18 +2286,72 @@ pub fn migrate(mut deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response,
 
 // smart contract query entrypoint
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     msg.validate()?;
 
     match msg {
+        QueryMsg::GetAccountFeeTier { address } => to_binary(&get_account_fee_tier(
+            deps,
+            deps.api.addr_validate(&address)?,
+            env.block.time,
+        )?),
+        QueryMsg::GetAccountLimits { address } => to_binary(&get_account_limits(
+            deps,
+            deps.api.addr_validate(&address)?,
+        )?),
+        QueryMsg::GetAccruedFees { account } => to_binary(&get_accrued_fees(
+            deps,
+            account
+                .map(|account| deps.api.addr_validate(&account))
+                .transpose()?,
+        )?),
         QueryMsg::GetAsk { id } => {
             return to_binary(&ASKS_V1.load(deps.storage, id.as_bytes())?);
         }
+        QueryMsg::GetAsksByOwner {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&list_asks_by_owner(
+            deps,
+            deps.api.addr_validate(&owner)?,
+            start_after,
+            limit,
+        )?),
         QueryMsg::GetBid { id } => {
             return to_binary(&BIDS_V3.load(deps.storage, id.as_bytes())?);
         }
+        QueryMsg::GetBidsByOwner {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&list_bids_by_owner(
+            deps,
+            deps.api.addr_validate(&owner)?,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::GetConfigHistory {
+            start_before,
+            limit,
+        } => to_binary(&get_config_history(deps, start_before, limit)?),
         QueryMsg::GetContractInfo {} => to_binary(&get_contract_info(deps.storage)?),
+        QueryMsg::GetOrderBookDepth { quote, levels } => {
+            to_binary(&get_order_book_depth(deps, quote, levels)?)
+        }
+        QueryMsg::GetOrderFills { id } => to_binary(&get_order_fills(deps, id)?),
+        QueryMsg::GetPendingChanges {} => to_binary(&get_pending_changes(deps)?),
+        QueryMsg::GetPriceBand { quote } => {
+            to_binary(&get_price_band(deps, quote, env.block.time)?)
+        }
+        QueryMsg::GetRecentTrades { limit } => to_binary(&get_recent_trades(deps, limit)?),
         QueryMsg::GetVersionInfo {} => to_binary(&get_version_info(deps.storage)?),
+        QueryMsg::ListAsks { start_after, limit } => {
+            to_binary(&list_asks(deps, start_after, limit)?)
+        }
+        QueryMsg::ListBids { start_after, limit } => {
+            to_binary(&list_bids(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -1442,6 +2362,7 @@ mod tests {
     use cosmwasm_std::{Addr, Storage, Uint128};
 
     use super::*;
+    use crate::tests::test_setup_utils::all_of_attributes;
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -1458,12 +2379,14 @@ mod tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1,12 +1,17 @@
-use cosmwasm_std::{Addr, DepsMut, Storage, Uint128};
+use cosmwasm_std::{Addr, Api, Coin, Deps, DepsMut, Storage, Uint128};
 use cw_storage_plus::Item;
 use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::common::FeeInfo;
+use crate::common::{
+    AccountLimits, AccountLimitsOverride, AttributePolicy, FeeInfo, FeePolicy, FeeRecipient,
+    FeeTerms, FeeTier, MakerTakerFeeInfo, OrderLimits, PriceBand, SelfTradePrevention,
+    TradingStatus,
+};
 use crate::error::ContractError;
+use crate::governance::ContractChange;
 use crate::msg::MigrateMsg;
 use crate::version_info::get_version_info;
 use semver::{Version, VersionReq};
@@ -15,6 +20,18 @@ const CONTRACT_INFO_NAMESPACE: &str = "contract_info";
 
 const CONTRACT_INFO_V3: Item<ContractInfoV3> = Item::new(CONTRACT_INFO_NAMESPACE);
 
+/// Required attribute lists stored in the contract info before attribute policies replaced them.
+const LEGACY_REQUIRED_ATTRIBUTES: Item<LegacyRequiredAttributes> =
+    Item::new(CONTRACT_INFO_NAMESPACE);
+
+#[derive(Serialize, Deserialize)]
+struct LegacyRequiredAttributes {
+    #[serde(default)]
+    ask_required_attributes: Vec<String>,
+    #[serde(default)]
+    bid_required_attributes: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct ContractInfoV3 {
     pub name: String,
@@ -24,14 +41,136 @@ pub struct ContractInfoV3 {
     pub supported_quote_denoms: Vec<String>,
     pub approvers: Vec<Addr>,
     pub executors: Vec<Addr>,
+    /// Authorizes governance messages such as `ModifyContract`, kept apart from the executors
+    #[serde(default)]
+    pub admin: Option<Addr>,
+    /// Proposed admin, who becomes the admin once they accept
+    #[serde(default)]
+    pub pending_admin: Option<Addr>,
+    /// Seconds a `ModifyContract` change waits before it can be applied, applied right away if 0.
+    /// Only changed on migrate, so a pending change can't shorten it.
+    #[serde(default)]
+    pub governance_delay: u64,
     pub ask_fee_info: Option<FeeInfo>,
     pub bid_fee_info: Option<FeeInfo>,
-    pub ask_required_attributes: Vec<String>,
-    pub bid_required_attributes: Vec<String>,
+    /// Maker/taker fee schedule, charged in place of the ask and bid fees when set
+    #[serde(default)]
+    pub maker_taker_fee_info: Option<MakerTakerFeeInfo>,
+    /// Volume tiers ordered by ascending threshold, discounting the ask and bid fee rates
+    #[serde(default)]
+    pub fee_tiers: Vec<FeeTier>,
+    /// Contract status and per-side pauses, checked before every order action
+    #[serde(default)]
+    pub trading_status: TradingStatus,
+    /// Policy applied when an ask and bid of the same owner are matched
+    #[serde(default)]
+    pub self_trade_prevention: SelfTradePrevention,
+    /// Band that match prices must stay within, no band is enforced if not set
+    #[serde(default)]
+    pub price_band: Option<PriceBand>,
+    /// Size and notional limits of new and modified orders, per quote denom
+    #[serde(default)]
+    pub order_limits: Vec<OrderLimits>,
+    /// Open order limits of accounts without an override
+    #[serde(default)]
+    pub account_limits: AccountLimits,
+    /// Open order limits of specific accounts, in place of the default account limits
+    #[serde(default)]
+    pub account_limits_overrides: Vec<AccountLimitsOverride>,
+    /// Attribute requirements of askers, no attributes are required if not set
+    #[serde(default)]
+    pub ask_attribute_policy: Option<AttributePolicy>,
+    /// Attribute requirements of bidders, no attributes are required if not set
+    #[serde(default)]
+    pub bid_attribute_policy: Option<AttributePolicy>,
     pub price_precision: Uint128,
     pub size_increment: Uint128,
 }
 
+impl ContractInfoV3 {
+    /// Returns whether the address is the contract admin
+    pub fn is_admin(&self, address: &Addr) -> bool {
+        self.admin.as_ref() == Some(address)
+    }
+
+    /// Returns the fee terms new ask orders are charged by
+    pub fn ask_fee_terms(&self) -> FeeTerms {
+        FeeTerms {
+            fee_info: self.ask_fee_info.to_owned(),
+            maker_taker_fee_info: self.maker_taker_fee_info.to_owned(),
+        }
+    }
+
+    /// Returns the fee terms new bid orders are charged by
+    pub fn bid_fee_terms(&self) -> FeeTerms {
+        FeeTerms {
+            fee_info: self.bid_fee_info.to_owned(),
+            maker_taker_fee_info: self.maker_taker_fee_info.to_owned(),
+        }
+    }
+
+    /// Returns the highest fee tier reached by an account's rolling traded volume
+    pub fn get_fee_tier(&self, volume: Uint128) -> Option<&FeeTier> {
+        self.fee_tiers
+            .iter()
+            .rev()
+            .find(|fee_tier| fee_tier.volume_threshold.le(&volume))
+    }
+
+    /// Returns the open order limits of an account, its override or the default account limits
+    pub fn get_account_limits(&self, address: &Addr) -> &AccountLimits {
+        self.account_limits_overrides
+            .iter()
+            .find(|account_limits| account_limits.address.eq(address.as_str()))
+            .map_or(&self.account_limits, |account_limits| {
+                &account_limits.limits
+            })
+    }
+
+    /// Checks an order's size and notional against the order limits of its quote denom, naming
+    /// the violated limit. The size of market bids isn't known upfront and isn't checked.
+    pub fn check_order_limits(
+        &self,
+        quote: &str,
+        size: Option<Uint128>,
+        notional: Decimal,
+    ) -> Result<(), ContractError> {
+        let order_limits = match self
+            .order_limits
+            .iter()
+            .find(|order_limits| order_limits.quote.eq(quote))
+        {
+            Some(order_limits) => order_limits,
+            None => return Ok(()),
+        };
+
+        let mut invalid_fields: Vec<String> = vec![];
+
+        if let Some(min_notional) = order_limits.min_notional {
+            if notional.lt(&Decimal::from(min_notional.u128())) {
+                invalid_fields.push(String::from("min_notional"));
+            }
+        }
+        if let Some(max_notional) = order_limits.max_notional {
+            if notional.gt(&Decimal::from(max_notional.u128())) {
+                invalid_fields.push(String::from("max_notional"));
+            }
+        }
+        if let (Some(max_size), Some(size)) = (order_limits.max_size, size) {
+            if size.gt(&max_size) {
+                invalid_fields.push(String::from("max_size"));
+            }
+        }
+
+        match invalid_fields.is_empty() {
+            true => Ok(()),
+            false => Err(ContractError::InvalidFields {
+                fields: invalid_fields,
+            }),
+        }
+    }
+}
+
 /// Enforces the specified contract version requirement.
 pub fn require_version(
     version_requirement: &str,
@@ -46,6 +185,277 @@ pub fn require_version(
     Ok(())
 }
 
+/// Validates a maker/taker fee schedule, an empty account and rates remove the schedule.
+///
+/// The taker rate can't be negative, a negative maker rate is a rebate paid from the account's
+/// accrued fees.
+pub fn to_maker_taker_fee_info(
+    api: &dyn Api,
+    account: &str,
+    maker_rate: &str,
+    taker_rate: &str,
+) -> Result<Option<MakerTakerFeeInfo>, ContractError> {
+    if account.is_empty() && maker_rate.is_empty() && taker_rate.is_empty() {
+        return Ok(None);
+    }
+
+    Decimal::from_str(taker_rate)
+        .ok()
+        .filter(|rate| !rate.is_sign_negative())
+        .ok_or(ContractError::InvalidFields {
+            fields: vec![String::from("taker_fee_rate")],
+        })?;
+
+    Decimal::from_str(maker_rate).map_err(|_| ContractError::InvalidFields {
+        fields: vec![String::from("maker_fee_rate")],
+    })?;
+
+    Ok(Some(MakerTakerFeeInfo {
+        account: api.addr_validate(account)?,
+        maker_rate: maker_rate.to_string(),
+        taker_rate: taker_rate.to_string(),
+    }))
+}
+
+/// Validates a fee policy and sets it on a side's fee info, which must be set.
+///
+/// Recipient weights must be positive, limit denoms unique and a minimum fee no larger than the
+/// maximum fee of the same denom.
+pub fn set_fee_policy(
+    api: &dyn Api,
+    fee_info: &mut Option<FeeInfo>,
+    fee_policy: &Option<FeePolicy>,
+    field: &str,
+) -> Result<(), ContractError> {
+    let fee_policy = match fee_policy {
+        Some(fee_policy) => fee_policy,
+        None => return Ok(()),
+    };
+
+    let invalid_fields = || ContractError::InvalidFields {
+        fields: vec![field.to_owned()],
+    };
+
+    let fee_info = fee_info.as_mut().ok_or_else(invalid_fields)?;
+
+    let has_unique_denoms = |fees: &[Coin]| {
+        fees.iter()
+            .enumerate()
+            .all(|(index, fee)| !fees[..index].iter().any(|other| other.denom.eq(&fee.denom)))
+    };
+
+    let is_min_within_max = fee_policy.min_fees.iter().all(|min_fee| {
+        fee_policy
+            .max_fees
+            .iter()
+            .filter(|max_fee| max_fee.denom.eq(&min_fee.denom))
+            .all(|max_fee| min_fee.amount.le(&max_fee.amount))
+    });
+
+    if !has_unique_denoms(&fee_policy.min_fees)
+        || !has_unique_denoms(&fee_policy.max_fees)
+        || !is_min_within_max
+        || fee_policy
+            .recipients
+            .iter()
+            .any(|recipient| recipient.weight == 0)
+    {
+        return Err(invalid_fields());
+    }
+
+    let mut recipients = Vec::new();
+    for recipient in &fee_policy.recipients {
+        recipients.push(FeeRecipient {
+            account: api.addr_validate(recipient.account.as_str())?,
+            weight: recipient.weight,
+        });
+    }
+
+    fee_info.policy = FeePolicy {
+        min_fees: fee_policy.min_fees.to_owned(),
+        max_fees: fee_policy.max_fees.to_owned(),
+        recipients,
+    };
+
+    Ok(())
+}
+
+/// Validates a fee tier table, thresholds must be strictly ascending and rates not negative.
+pub fn validate_fee_tiers(fee_tiers: &[FeeTier]) -> Result<(), ContractError> {
+    let is_valid_rate = |rate: &str| {
+        Decimal::from_str(rate)
+            .map(|rate| !rate.is_sign_negative())
+            .unwrap_or(false)
+    };
+
+    let is_ascending = fee_tiers
+        .windows(2)
+        .all(|pair| pair[0].volume_threshold.lt(&pair[1].volume_threshold));
+
+    if !is_ascending
+        || !fee_tiers
+            .iter()
+            .all(|fee_tier| is_valid_rate(&fee_tier.ask_rate) && is_valid_rate(&fee_tier.bid_rate))
+    {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("fee_tiers")],
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates an order limits table, each quote denom can be listed once and a min notional can't
+/// exceed the max notional.
+pub fn validate_order_limits(order_limits: &[OrderLimits]) -> Result<(), ContractError> {
+    let is_valid = |(index, limits): (usize, &OrderLimits)| {
+        !limits.quote.is_empty()
+            && !order_limits[..index]
+                .iter()
+                .any(|other| other.quote.eq(&limits.quote))
+            && match (limits.min_notional, limits.max_notional) {
+                (Some(min_notional), Some(max_notional)) => min_notional.le(&max_notional),
+                (_, _) => true,
+            }
+            && limits.max_size.ne(&Some(Uint128::zero()))
+    };
+
+    if !order_limits.iter().enumerate().all(is_valid) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("order_limits")],
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates account limits, each denom's max escrow can be listed once.
+pub fn validate_account_limits(
+    account_limits: &AccountLimits,
+    field: &str,
+) -> Result<(), ContractError> {
+    let max_escrow = &account_limits.max_escrow;
+
+    if max_escrow.iter().enumerate().any(|(index, coin)| {
+        coin.denom.is_empty()
+            || max_escrow[..index]
+                .iter()
+                .any(|other| other.denom.eq(&coin.denom))
+    }) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![field.to_string()],
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates account limits overrides, each address must be valid and listed once.
+pub fn validate_account_limits_overrides(
+    api: &dyn Api,
+    account_limits_overrides: &[AccountLimitsOverride],
+) -> Result<(), ContractError> {
+    let invalid_fields = ContractError::InvalidFields {
+        fields: vec![String::from("account_limits_overrides")],
+    };
+
+    for (index, account_limits) in account_limits_overrides.iter().enumerate() {
+        if api.addr_validate(&account_limits.address).is_err()
+            || account_limits_overrides[..index]
+                .iter()
+                .any(|other| other.address.eq(&account_limits.address))
+        {
+            return Err(invalid_fields);
+        }
+        validate_account_limits(&account_limits.limits, "account_limits_overrides")?;
+    }
+
+    Ok(())
+}
+
+/// Validates an attribute policy, returning `None` for an empty `all_of`, which removes the policy.
+///
+/// Attribute names, groups and value lists can't be empty.
+pub fn to_attribute_policy(
+    attribute_policy: &AttributePolicy,
+    field: &str,
+) -> Result<Option<AttributePolicy>, ContractError> {
+    fn is_valid(attribute_policy: &AttributePolicy) -> bool {
+        match attribute_policy {
+            AttributePolicy::Attribute { name, values } => {
+                !name.is_empty() && !matches!(values, Some(values) if values.is_empty())
+            }
+            AttributePolicy::AllOf(items)
+            | AttributePolicy::AnyOf(items)
+            | AttributePolicy::NoneOf(items) => !items.is_empty() && items.iter().all(is_valid),
+        }
+    }
+
+    match attribute_policy {
+        AttributePolicy::AllOf(items) if items.is_empty() => Ok(None),
+        attribute_policy if is_valid(attribute_policy) => Ok(Some(attribute_policy.to_owned())),
+        _ => Err(ContractError::InvalidFields {
+            fields: vec![field.to_string()],
+        }),
+    }
+}
+
+/// Folds a legacy required attribute list into an attribute policy, requiring all of the
+/// attributes as well as any existing policy.
+fn to_required_attributes_policy(
+    required_attributes: Vec<String>,
+    attribute_policy: Option<AttributePolicy>,
+) -> Option<AttributePolicy> {
+    if required_attributes.is_empty() {
+        return attribute_policy;
+    }
+
+    let mut items: Vec<AttributePolicy> = required_attributes
+        .into_iter()
+        .map(|name| AttributePolicy::Attribute { name, values: None })
+        .collect();
+    items.extend(attribute_policy);
+
+    Some(AttributePolicy::AllOf(items))
+}
+
+/// Returns a message's attribute policy with its deprecated required attribute list folded in as
+/// an `all_of` of the attributes. An empty list on its own removes the policy.
+pub fn with_required_attributes(
+    required_attributes: &Option<Vec<String>>,
+    attribute_policy: &Option<AttributePolicy>,
+) -> Option<AttributePolicy> {
+    match required_attributes {
+        Some(required_attributes) => Some(
+            to_required_attributes_policy(
+                required_attributes.to_owned(),
+                attribute_policy.to_owned(),
+            )
+            .unwrap_or(AttributePolicy::AllOf(vec![])),
+        ),
+        None => attribute_policy.to_owned(),
+    }
+}
+
+/// Validates a price band, returning `None` for a band with a zero rate, which removes the band.
+pub fn to_price_band(price_band: &PriceBand) -> Result<Option<PriceBand>, ContractError> {
+    let rate = Decimal::from_str(&price_band.rate).map_err(|_| ContractError::InvalidFields {
+        fields: vec![String::from("price_band")],
+    })?;
+
+    if rate.is_zero() {
+        return Ok(None);
+    }
+
+    if rate.is_sign_negative() || price_band.max_breaches.eq(&0) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("price_band")],
+        });
+    }
+
+    Ok(Some(price_band.to_owned()))
+}
+
 pub fn set_contract_info(
     store: &mut dyn Storage,
     contract_info: &ContractInfoV3,
@@ -73,6 +483,19 @@ pub fn migrate_contract_info(
     require_version(">=0.16.2", &current_version)?;
 
     let mut contract_info = get_contract_info(store)?;
+
+    // required attribute lists of earlier versions become all_of attribute policies, the lists are
+    // dropped once the contract info is saved
+    let legacy_required_attributes = LEGACY_REQUIRED_ATTRIBUTES.load(store)?;
+    contract_info.ask_attribute_policy = to_required_attributes_policy(
+        legacy_required_attributes.ask_required_attributes,
+        contract_info.ask_attribute_policy,
+    );
+    contract_info.bid_attribute_policy = to_required_attributes_policy(
+        legacy_required_attributes.bid_required_attributes,
+        contract_info.bid_attribute_policy,
+    );
+
     match &msg.approvers {
         None => {}
         Some(approvers) => {
@@ -87,6 +510,62 @@ pub fn migrate_contract_info(
         }
     }
 
+    // setting the admin replaces any pending admin handover
+    match (&msg.admin, &contract_info.admin) {
+        (Some(admin), _) => {
+            contract_info.admin = Some(api.addr_validate(admin)?);
+            contract_info.pending_admin = None;
+        }
+        // a contract without an admin can't be governed, migrating it requires one
+        (None, None) => {
+            return Err(ContractError::InvalidFields {
+                fields: vec![String::from("admin")],
+            })
+        }
+        (None, Some(_)) => {}
+    }
+
+    if let Some(governance_delay) = msg.governance_delay {
+        contract_info.governance_delay = governance_delay;
+    }
+
+    if let Some(self_trade_prevention) = &msg.self_trade_prevention {
+        contract_info.self_trade_prevention = self_trade_prevention.to_owned();
+    }
+
+    if let Some(price_band) = &msg.price_band {
+        contract_info.price_band = to_price_band(price_band)?;
+    }
+
+    if let Some(order_limits) = &msg.order_limits {
+        validate_order_limits(order_limits)?;
+        contract_info.order_limits = order_limits.to_owned();
+    }
+
+    if let Some(account_limits) = &msg.account_limits {
+        validate_account_limits(account_limits, "account_limits")?;
+        contract_info.account_limits = account_limits.to_owned();
+    }
+
+    if let Some(account_limits_overrides) = &msg.account_limits_overrides {
+        validate_account_limits_overrides(api, account_limits_overrides)?;
+        contract_info.account_limits_overrides = account_limits_overrides.to_owned();
+    }
+
+    if let Some(ask_attribute_policy) =
+        &with_required_attributes(&msg.ask_required_attributes, &msg.ask_attribute_policy)
+    {
+        contract_info.ask_attribute_policy =
+            to_attribute_policy(ask_attribute_policy, "ask_attribute_policy")?;
+    }
+
+    if let Some(bid_attribute_policy) =
+        &with_required_attributes(&msg.bid_required_attributes, &msg.bid_attribute_policy)
+    {
+        contract_info.bid_attribute_policy =
+            to_attribute_policy(bid_attribute_policy, "bid_attribute_policy")?;
+    }
+
     match (&msg.ask_fee_account, &msg.ask_fee_rate) {
         (Some(account), Some(rate)) => {
             contract_info.ask_fee_info = match (account.as_str(), rate.as_str()) {
@@ -99,6 +578,12 @@ pub fn migrate_contract_info(
                     Some(FeeInfo {
                         account: api.addr_validate(account)?,
                         rate: rate.to_string(),
+                        // changing the fee rate or account keeps the fee policy
+                        policy: contract_info
+                            .ask_fee_info
+                            .as_ref()
+                            .map(|fee_info| fee_info.policy.to_owned())
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -118,6 +603,12 @@ pub fn migrate_contract_info(
                     Some(FeeInfo {
                         account: api.addr_validate(account)?,
                         rate: rate.to_string(),
+                        // changing the fee rate or account keeps the fee policy
+                        policy: contract_info
+                            .bid_fee_info
+                            .as_ref()
+                            .map(|fee_info| fee_info.policy.to_owned())
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -125,18 +616,31 @@ pub fn migrate_contract_info(
         (_, _) => (),
     };
 
-    match &msg.ask_required_attributes {
-        None => {}
-        Some(ask_required_attributes) => {
-            contract_info.ask_required_attributes = ask_required_attributes.clone();
-        }
+    set_fee_policy(
+        api,
+        &mut contract_info.ask_fee_info,
+        &msg.ask_fee_policy,
+        "ask_fee_policy",
+    )?;
+    set_fee_policy(
+        api,
+        &mut contract_info.bid_fee_info,
+        &msg.bid_fee_policy,
+        "bid_fee_policy",
+    )?;
+
+    if let (Some(account), Some(maker_rate), Some(taker_rate)) = (
+        &msg.maker_taker_fee_account,
+        &msg.maker_fee_rate,
+        &msg.taker_fee_rate,
+    ) {
+        contract_info.maker_taker_fee_info =
+            to_maker_taker_fee_info(api, account, maker_rate, taker_rate)?;
     }
 
-    match &msg.bid_required_attributes {
-        None => {}
-        Some(bid_required_attributes) => {
-            contract_info.bid_required_attributes = bid_required_attributes.clone();
-        }
+    if let Some(fee_tiers) = &msg.fee_tiers {
+        validate_fee_tiers(fee_tiers)?;
+        contract_info.fee_tiers = fee_tiers.to_owned();
     }
 
     set_contract_info(store, &contract_info)?;
@@ -144,16 +648,10 @@ pub fn migrate_contract_info(
     get_contract_info(store)
 }
 
+/// Returns the contract info with a configuration change applied, without storing it.
 pub fn modify_contract_info(
-    deps: DepsMut,
-    approvers: Option<Vec<String>>,
-    executors: Option<Vec<String>>,
-    ask_fee_rate: Option<String>,
-    ask_fee_account: Option<String>,
-    bid_fee_rate: Option<String>,
-    bid_fee_account: Option<String>,
-    ask_required_attributes: Option<Vec<String>>,
-    bid_required_attributes: Option<Vec<String>>,
+    deps: Deps,
+    change: &ContractChange,
 ) -> Result<ContractInfoV3, ContractError> {
     let store = deps.storage;
     let api = deps.api;
@@ -168,7 +666,7 @@ pub fn modify_contract_info(
     }
 
     let mut contract_info = get_contract_info(store)?;
-    match &approvers {
+    match &change.approvers {
         None => {}
         Some(approvers) => {
             let mut new_approvers: Vec<Addr> = Vec::new();
@@ -181,7 +679,7 @@ pub fn modify_contract_info(
         }
     }
 
-    match &executors {
+    match &change.executors {
         None => {}
         Some(executors) => {
             let mut new_executors: Vec<Addr> = Vec::new();
@@ -194,7 +692,7 @@ pub fn modify_contract_info(
         }
     }
 
-    match (&ask_fee_account, &ask_fee_rate) {
+    match (&change.ask_fee_account, &change.ask_fee_rate) {
         (Some(account), Some(rate)) => {
             contract_info.ask_fee_info = match (account.as_str(), rate.as_str()) {
                 ("", "") => None,
@@ -206,6 +704,12 @@ pub fn modify_contract_info(
                     Some(FeeInfo {
                         account: api.addr_validate(account)?,
                         rate: rate.to_string(),
+                        // changing the fee rate or account keeps the fee policy
+                        policy: contract_info
+                            .ask_fee_info
+                            .as_ref()
+                            .map(|fee_info| fee_info.policy.to_owned())
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -213,7 +717,7 @@ pub fn modify_contract_info(
         (_, _) => (),
     };
 
-    match (&bid_fee_account, &bid_fee_rate) {
+    match (&change.bid_fee_account, &change.bid_fee_rate) {
         (Some(account), Some(rate)) => {
             contract_info.bid_fee_info = match (account.as_str(), rate.as_str()) {
                 ("", "") => None,
@@ -225,6 +729,12 @@ pub fn modify_contract_info(
                     Some(FeeInfo {
                         account: api.addr_validate(account)?,
                         rate: rate.to_string(),
+                        // changing the fee rate or account keeps the fee policy
+                        policy: contract_info
+                            .bid_fee_info
+                            .as_ref()
+                            .map(|fee_info| fee_info.policy.to_owned())
+                            .unwrap_or_default(),
                     })
                 }
             }
@@ -232,23 +742,147 @@ pub fn modify_contract_info(
         (_, _) => (),
     };
 
-    match &ask_required_attributes {
-        None => {}
-        Some(ask_required_attributes) => {
-            contract_info.ask_required_attributes = ask_required_attributes.clone();
+    set_fee_policy(
+        api,
+        &mut contract_info.ask_fee_info,
+        &change.ask_fee_policy,
+        "ask_fee_policy",
+    )?;
+    set_fee_policy(
+        api,
+        &mut contract_info.bid_fee_info,
+        &change.bid_fee_policy,
+        "bid_fee_policy",
+    )?;
+
+    if let (Some(account), Some(maker_rate), Some(taker_rate)) = (
+        &change.maker_taker_fee_account,
+        &change.maker_fee_rate,
+        &change.taker_fee_rate,
+    ) {
+        contract_info.maker_taker_fee_info =
+            to_maker_taker_fee_info(api, account, maker_rate, taker_rate)?;
+    }
+
+    if let Some(fee_tiers) = &change.fee_tiers {
+        validate_fee_tiers(fee_tiers)?;
+        contract_info.fee_tiers = fee_tiers.to_owned();
+    }
+
+    add_denoms(
+        &mut contract_info.supported_quote_denoms,
+        &change.add_supported_quote_denoms,
+    );
+    remove_denoms(
+        &mut contract_info.supported_quote_denoms,
+        &change.remove_supported_quote_denoms,
+        "remove_supported_quote_denoms",
+    )?;
+    // the contract must keep at least one quote denom, as required on instantiate
+    if contract_info.supported_quote_denoms.is_empty() {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("remove_supported_quote_denoms")],
+        });
+    }
+
+    if let Some(denoms) = &change.add_convertible_base_denoms {
+        if denoms.contains(&contract_info.base_denom) {
+            return Err(ContractError::InvalidFields {
+                fields: vec![String::from("add_convertible_base_denoms")],
+            });
         }
     }
+    add_denoms(
+        &mut contract_info.convertible_base_denoms,
+        &change.add_convertible_base_denoms,
+    );
+    remove_denoms(
+        &mut contract_info.convertible_base_denoms,
+        &change.remove_convertible_base_denoms,
+        "remove_convertible_base_denoms",
+    )?;
 
-    match &bid_required_attributes {
-        None => {}
-        Some(bid_required_attributes) => {
-            contract_info.bid_required_attributes = bid_required_attributes.clone();
+    if let Some(price_precision) = change.price_precision {
+        contract_info.price_precision = price_precision;
+    }
+
+    if let Some(size_increment) = change.size_increment {
+        contract_info.size_increment = size_increment;
+    }
+
+    if let Some(self_trade_prevention) = &change.self_trade_prevention {
+        contract_info.self_trade_prevention = self_trade_prevention.to_owned();
+    }
+
+    if let Some(price_band) = &change.price_band {
+        contract_info.price_band = to_price_band(price_band)?;
+    }
+
+    if let Some(order_limits) = &change.order_limits {
+        validate_order_limits(order_limits)?;
+        contract_info.order_limits = order_limits.to_owned();
+    }
+
+    if let Some(account_limits) = &change.account_limits {
+        validate_account_limits(account_limits, "account_limits")?;
+        contract_info.account_limits = account_limits.to_owned();
+    }
+
+    if let Some(account_limits_overrides) = &change.account_limits_overrides {
+        validate_account_limits_overrides(api, account_limits_overrides)?;
+        contract_info.account_limits_overrides = account_limits_overrides.to_owned();
+    }
+
+    if let Some(ask_attribute_policy) = &change.ask_attribute_policy {
+        contract_info.ask_attribute_policy =
+            to_attribute_policy(ask_attribute_policy, "ask_attribute_policy")?;
+    }
+
+    if let Some(bid_attribute_policy) = &change.bid_attribute_policy {
+        contract_info.bid_attribute_policy =
+            to_attribute_policy(bid_attribute_policy, "bid_attribute_policy")?;
+    }
+
+    // the pair invariant checked on instantiate must still hold
+    if (contract_info.size_increment.u128()
+        % 10u128.pow(contract_info.price_precision.u128() as u32))
+    .ne(&0)
+    {
+        return Err(ContractError::InvalidPricePrecisionSizePair);
+    }
+
+    Ok(contract_info)
+}
+
+/// Appends the denoms that aren't already listed.
+fn add_denoms(denoms: &mut Vec<String>, added: &Option<Vec<String>>) {
+    for denom in added.iter().flatten() {
+        if !denoms.contains(denom) {
+            denoms.push(denom.to_owned());
         }
     }
+}
 
-    set_contract_info(store, &contract_info)?;
+/// Removes the denoms from the list, each of them must be listed.
+fn remove_denoms(
+    denoms: &mut Vec<String>,
+    removed: &Option<Vec<String>>,
+    field: &str,
+) -> Result<(), ContractError> {
+    for denom in removed.iter().flatten() {
+        match denoms.iter().position(|item| item.eq(denom)) {
+            Some(index) => {
+                denoms.remove(index);
+            }
+            None => {
+                return Err(ContractError::InvalidFields {
+                    fields: vec![field.to_string()],
+                })
+            }
+        }
+    }
 
-    get_contract_info(store)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -258,13 +892,15 @@ mod tests {
     #[allow(deprecated)]
     use super::{
         get_contract_info, migrate_contract_info, require_version, set_contract_info,
-        ContractInfoV3, Version,
+        ContractInfoV3, Version, CONTRACT_INFO_NAMESPACE,
     };
-    use crate::common::FeeInfo;
+    use crate::common::{AttributePolicy, FeeInfo, FeePolicy};
     use crate::error::ContractError;
     use crate::msg::MigrateMsg;
+    use crate::tests::test_setup_utils::{all_of_attributes, setup_test_base_contract_v3};
     use crate::version_info::{get_version_info, set_version_info, VersionInfoV1};
-    use cosmwasm_std::{Addr, Uint128};
+    use cosmwasm_std::{Addr, Storage, Uint128};
+    use serde_json::json;
 
     #[test]
     pub fn set_contract_info_with_valid_data() -> Result<(), ContractError> {
@@ -280,18 +916,22 @@ mod tests {
                 supported_quote_denoms: vec!["quo_base_1".into(), "quo_base_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("ask_fee_acct"),
                     rate: "0.00".to_string(),
+                    policy: FeePolicy::default(),
                 }),
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_acct"),
                     rate: "0.02".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
                 price_precision: Uint128::new(3),
                 size_increment: Uint128::new(1000),
+                ..Default::default()
             },
         )?;
 
@@ -321,6 +961,7 @@ mod tests {
             Some(FeeInfo {
                 account: Addr::unchecked("ask_fee_acct"),
                 rate: "0.00".to_string(),
+                policy: FeePolicy::default(),
             })
         );
         assert_eq!(
@@ -328,15 +969,16 @@ mod tests {
             Some(FeeInfo {
                 account: Addr::unchecked("bid_fee_acct"),
                 rate: "0.02".to_string(),
+                policy: FeePolicy::default(),
             })
         );
         assert_eq!(
-            contract_info.ask_required_attributes,
-            vec!["ask_tag_1", "ask_tag_2"]
+            contract_info.ask_attribute_policy,
+            Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
         );
         assert_eq!(
-            contract_info.bid_required_attributes,
-            vec!["ask_tag_1", "ask_tag_2"]
+            contract_info.bid_attribute_policy,
+            Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
         );
         assert_eq!(contract_info.price_precision, Uint128::new(3));
         assert_eq!(contract_info.size_increment, Uint128::new(1000));
@@ -409,18 +1051,22 @@ mod tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("ask_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.02".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         )?;
 
@@ -435,6 +1081,7 @@ mod tests {
                 bid_fee_account: None,
                 ask_required_attributes: None,
                 bid_required_attributes: None,
+                ..Default::default()
             },
         )?;
 
@@ -448,18 +1095,22 @@ mod tests {
             supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
             approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
             executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+            admin: Some(Addr::unchecked("contract_admin")),
             ask_fee_info: Some(FeeInfo {
                 account: Addr::unchecked("ask_fee_account"),
                 rate: "0.01".into(),
+                policy: FeePolicy::default(),
             }),
             bid_fee_info: Some(FeeInfo {
                 account: Addr::unchecked("bid_fee_account"),
                 rate: "0.02".into(),
+                policy: FeePolicy::default(),
             }),
-            ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-            bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(100),
+            ..Default::default()
         };
 
         assert_eq!(contract_info, expected_contract_info);
@@ -467,6 +1118,53 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn migrate_without_admin_returns_err() -> Result<(), ContractError> {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_version_info(
+            &mut deps.storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )?;
+
+        let mut contract_info = get_contract_info(&deps.storage)?;
+        contract_info.admin = None;
+        set_contract_info(&mut deps.storage, &contract_info)?;
+
+        let mut msg = MigrateMsg {
+            approvers: None,
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            ..Default::default()
+        };
+
+        // a contract without an admin can't be migrated without one
+        match migrate_contract_info(deps.as_mut(), &msg) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["admin"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        msg.admin = Some("contract_admin".into());
+        migrate_contract_info(deps.as_mut(), &msg)?;
+
+        assert_eq!(
+            get_contract_info(&deps.storage)?.admin,
+            Some(Addr::unchecked("contract_admin"))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn migrate_with_data_is_changed() -> Result<(), ContractError> {
         // setup
@@ -490,18 +1188,22 @@ mod tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                pending_admin: Some(Addr::unchecked("proposed_admin")),
                 ask_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("ask_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.02".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         )?;
 
@@ -510,12 +1212,16 @@ mod tests {
             deps.as_mut(),
             &MigrateMsg {
                 approvers: Some(vec!["approver_3".into(), "approver_4".into()]),
+                admin: Some("new_admin".into()),
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_3", "ask_tag_4"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_3", "bid_tag_4"])),
                 ask_fee_rate: Some("0.03".into()),
                 ask_fee_account: Some("new_ask_fee_account".into()),
                 bid_fee_rate: Some("0.04".into()),
                 bid_fee_account: Some("new_bid_fee_account".into()),
-                ask_required_attributes: Some(vec!["ask_tag_3".into(), "ask_tag_4".into()]),
-                bid_required_attributes: Some(vec!["bid_tag_3".into(), "bid_tag_4".into()]),
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                ..Default::default()
             },
         )?;
 
@@ -529,22 +1235,115 @@ mod tests {
             supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
             approvers: vec![Addr::unchecked("approver_3"), Addr::unchecked("approver_4")],
             executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+            admin: Some(Addr::unchecked("new_admin")),
             ask_fee_info: Some(FeeInfo {
                 account: Addr::unchecked("new_ask_fee_account"),
                 rate: "0.03".into(),
+                policy: FeePolicy::default(),
             }),
             bid_fee_info: Some(FeeInfo {
                 account: Addr::unchecked("new_bid_fee_account"),
                 rate: "0.04".into(),
+                policy: FeePolicy::default(),
             }),
-            ask_required_attributes: vec!["ask_tag_3".into(), "ask_tag_4".into()],
-            bid_required_attributes: vec!["bid_tag_3".into(), "bid_tag_4".into()],
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_3", "ask_tag_4"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_3", "bid_tag_4"])),
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(100),
+            ..Default::default()
         };
 
         assert_eq!(contract_info, expected_contract_info);
 
         Ok(())
     }
+
+    #[test]
+    fn migrate_converts_required_attributes_to_attribute_policies() -> Result<(), ContractError> {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_version_info(
+            &mut deps.storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )?;
+
+        // store the contract info as earlier versions did, with required attribute lists
+        let mut contract_info = get_contract_info(&deps.storage)?;
+        contract_info.ask_attribute_policy = None;
+        contract_info.bid_attribute_policy = Some(AttributePolicy::Attribute {
+            name: "bid_tag_3".into(),
+            values: Some(vec!["US".into()]),
+        });
+        let mut legacy_contract_info = serde_json::to_value(&contract_info).unwrap();
+        legacy_contract_info["ask_required_attributes"] = json!(["ask_tag_1", "ask_tag_2"]);
+        legacy_contract_info["bid_required_attributes"] = json!(["bid_tag_1"]);
+        deps.storage.set(
+            CONTRACT_INFO_NAMESPACE.as_bytes(),
+            &serde_json::to_vec(&legacy_contract_info).unwrap(),
+        );
+
+        let msg = MigrateMsg {
+            approvers: None,
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            ..Default::default()
+        };
+        migrate_contract_info(deps.as_mut(), &msg)?;
+
+        // the required attributes are kept alongside an existing policy
+        let expected_ask_attribute_policy = Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]));
+        let expected_bid_attribute_policy = Some(AttributePolicy::AllOf(vec![
+            AttributePolicy::Attribute {
+                name: "bid_tag_1".into(),
+                values: None,
+            },
+            AttributePolicy::Attribute {
+                name: "bid_tag_3".into(),
+                values: Some(vec!["US".into()]),
+            },
+        ]));
+        let contract_info = get_contract_info(&deps.storage)?;
+        assert_eq!(
+            contract_info.ask_attribute_policy,
+            expected_ask_attribute_policy
+        );
+        assert_eq!(
+            contract_info.bid_attribute_policy,
+            expected_bid_attribute_policy
+        );
+
+        // the lists are converted once
+        migrate_contract_info(deps.as_mut(), &msg)?;
+        let contract_info = get_contract_info(&deps.storage)?;
+        assert_eq!(
+            contract_info.ask_attribute_policy,
+            expected_ask_attribute_policy
+        );
+        assert_eq!(
+            contract_info.bid_attribute_policy,
+            expected_bid_attribute_policy
+        );
+
+        // a deprecated list in the migrate msg replaces the policy with an all_of
+        migrate_contract_info(
+            deps.as_mut(),
+            &MigrateMsg {
+                ask_required_attributes: Some(vec!["ask_tag_3".into()]),
+                ..msg
+            },
+        )?;
+        assert_eq!(
+            get_contract_info(&deps.storage)?.ask_attribute_policy,
+            Some(all_of_attributes(&["ask_tag_3"]))
+        );
+
+        Ok(())
+    }
 }
@@ -6,6 +6,9 @@ use uuid::Error as UuidError;
 
 #[derive(Error, Debug)]
 pub enum ContractError {
+    #[error("Account limit exceeded: {limit:?}")]
+    AccountLimitExceeded { limit: String },
+
     #[error("Ask order price does not match Bid order price")]
     AskBidPriceMismatch,
 
@@ -15,9 +18,15 @@ pub enum ContractError {
     #[error("Ask order already marked Ready by approver: {approver:?}")]
     AskOrderReady { approver: String },
 
+    #[error("Account attributes do not satisfy clause: {clause:?}")]
+    AttributePolicyFailed { clause: String },
+
     #[error("Attributes conflict with orders")]
     ConflictingAttributes,
 
+    #[error("Contract status does not allow this action: {status:?}")]
+    ContractStatusRestricted { status: String },
+
     #[error("One base required in order")]
     BaseQuantity,
 
@@ -39,6 +48,9 @@ pub enum ContractError {
     #[error("Cannot send funds when expiring order")]
     ExpireWithFunds,
 
+    #[error("Fill or kill order could not be filled completely")]
+    FillOrKillNotFilled,
+
     #[error("Fee size is not: {fee_rate:?}% of total")]
     InvalidFeeSize { fee_rate: String },
 
@@ -60,9 +72,27 @@ pub enum ContractError {
     #[error("Failed to load order: {error:?}")]
     LoadOrderFailed { error: StdError },
 
+    #[error("Matching is halted by the circuit breaker until {halted_until:?}")]
+    MarketHalted { halted_until: String },
+
+    #[error("Convertible ask orders can not be modified")]
+    ModifyConvertibleAsk,
+
+    #[error("No fees accrued in denomination")]
+    NoAccruedFees,
+
     #[error("Total (price * size) must be an integer")]
     NonIntegerTotal,
 
+    #[error("Orders do not conform to the price precision and size increment: {ids:?}")]
+    NonConformingOrders { ids: Vec<String> },
+
+    #[error("Order has expired")]
+    OrderExpired,
+
+    #[error("Orders are paused: {side:?}")]
+    OrdersPaused { side: String },
+
     #[error("Integer overflow")]
     OverflowError(#[from] cosmwasm_std::OverflowError),
 
@@ -75,6 +105,9 @@ pub enum ContractError {
     #[error("Sent funds does not match order")]
     SentFundsOrderMismatch,
 
+    #[error("Ask and bid orders have the same owner")]
+    SelfTrade,
+
     #[error("{0}")]
     Std(#[from] StdError),
 
@@ -101,6 +134,9 @@ pub enum ContractError {
         source_version: String,
         target_version: String,
     },
+
+    #[error("Cannot send funds when withdrawing fees")]
+    WithdrawWithFunds,
 }
 
 impl From<ContractError> for StdError {
@@ -1 +1,10 @@
+pub mod admin;
+pub mod batch;
+pub mod create_market_bid;
+pub mod expire_orders;
+pub mod match_orders;
 pub mod modify_contract;
+pub mod modify_order;
+pub mod pending_changes;
+pub mod set_trading_status;
+pub mod withdraw_fees;
@@ -0,0 +1,53 @@
+use crate::common::ContractAction;
+use crate::contract_info::{get_contract_info, set_contract_info};
+use crate::error::ContractError;
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Proposes a new contract admin, the first step of the admin handover.
+///
+/// The current admin stays in place until the proposed admin accepts, a later proposal replaces
+/// the pending one.
+pub fn propose_admin(
+    deps: DepsMut,
+    _env: Env,
+    info: &MessageInfo,
+    admin: String,
+) -> Result<Response, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+
+    if !contract_info.is_admin(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let pending_admin = deps.api.addr_validate(&admin)?;
+    contract_info.pending_admin = Some(pending_admin.to_owned());
+
+    set_contract_info(deps.storage, &contract_info)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", ContractAction::ProposeAdmin.to_string()),
+        attr("pending_admin", pending_admin),
+    ]))
+}
+
+/// Accepts a pending admin proposal, the sender must be the proposed admin.
+pub fn accept_admin(
+    deps: DepsMut,
+    _env: Env,
+    info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+
+    if contract_info.pending_admin.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    contract_info.admin = contract_info.pending_admin.take();
+
+    set_contract_info(deps.storage, &contract_info)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", ContractAction::AcceptAdmin.to_string()),
+        attr("admin", &info.sender),
+    ]))
+}
@@ -0,0 +1,49 @@
+use crate::common::ContractAction;
+use crate::contract::execute;
+use crate::contract_info::get_contract_info;
+use crate::error::ContractError;
+use crate::msg::BatchOp;
+use crate::util::net_transfers;
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Runs a list of executor operations atomically in a single transaction.
+///
+/// Each op is dispatched the same way as its standalone execute message and the attributes of
+/// every op are combined in order. Transfers from the contract to the same recipient and denom are
+/// netted into a single message. If any op fails the whole batch fails and no state is changed.
+pub fn batch(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ops: Vec<BatchOp>,
+) -> Result<Response, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+
+    // only executors may run a batch
+    if !contract_info.executors.contains(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExecuteWithFunds);
+    }
+
+    let op_count = ops.len();
+    let mut messages = Vec::new();
+    let mut response = Response::new();
+
+    for op in ops {
+        let op_response = execute(deps.branch(), env.clone(), info.clone(), op.into())?;
+
+        messages.extend(op_response.messages);
+        response = response.add_attributes(op_response.attributes);
+    }
+
+    Ok(response
+        .add_submessages(net_transfers(messages)?)
+        .add_attributes(vec![
+            attr("action", ContractAction::Batch.to_string()),
+            attr("op_count", op_count.to_string()),
+        ]))
+}
@@ -0,0 +1,354 @@
+use crate::bid_order::{BidOrderV3, BIDS_V3};
+use crate::common::{next_order_sequence, Action, ContractAction};
+use crate::contract::{
+    check_bid_fee, get_self_trade_cancels, record_match_price_band_breach, reverse_ask_order,
+    settle_fill,
+};
+use crate::contract_info::get_contract_info;
+use crate::error::ContractError;
+use crate::execute::match_orders::{get_auto_match_contract_info, get_best_ask, MAX_MATCH_LIMIT};
+use crate::price_band::{check_price_band, PriceBandCheck};
+use crate::util::{
+    add_transfer, check_account_attributes, is_restricted_marker, parse_order_price,
+    transfer_marker_coins,
+};
+use cosmwasm_std::{attr, coins, Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::cmp::min;
+use std::str::FromStr;
+
+/// Creates a market bid that buys base from the resting asks in price-time priority.
+///
+/// The bidder escrows at most `quote_size` (plus the bid fee for that amount) and each fill
+/// executes at the ask price, as long as the ask price is at or below the bid's worst price. The
+/// market bid never rests on the book, so unused quote and the unused portion of the bid fee are
+/// refunded to the bidder once matching stops, after at most `MAX_MATCH_LIMIT` fills.
+///
+/// The bidder's own asks are handled by the self-trade prevention policy, where the market bid is
+/// the newest order, and the `reject` policy cancels the market bid. Fills are checked against the
+/// price band like matches of resting orders, and matching stops at the first fill outside the band
+/// or while the market is halted.
+pub fn create_market_bid(
+    mut deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    mut bid_order: BidOrderV3,
+) -> Result<Response, ContractError> {
+    let contract_info = get_auto_match_contract_info(&get_contract_info(deps.storage)?);
+
+    // error if worst price is not positive or smaller than allowed price precision
+    let worst_price =
+        parse_order_price(&bid_order.price, contract_info.price_precision).map_err(|_| {
+            ContractError::InvalidFields {
+                fields: vec![String::from("worst_price")],
+            }
+        })?;
+
+    // error if the sent fee does not match the bid fee rate applied to the quote size
+    check_bid_fee(
+        &contract_info,
+        &bid_order.fee,
+        &bid_order.quote.denom,
+        Decimal::from(bid_order.quote.amount.u128()),
+    )?;
+
+    // error if order quote is not supported quote denom
+    if !&contract_info
+        .supported_quote_denoms
+        .contains(&bid_order.quote.denom)
+    {
+        return Err(ContractError::UnsupportedQuoteDenom);
+    }
+
+    // error if the quote size is outside the quote denom's notional limits
+    contract_info.check_order_limits(
+        &bid_order.quote.denom,
+        None,
+        Decimal::from(bid_order.quote.amount.u128()),
+    )?;
+
+    // error if order base denom not equal to contract base denom
+    if bid_order.base.denom.ne(&contract_info.base_denom) {
+        return Err(ContractError::InconvertibleBaseDenom);
+    }
+
+    // error if bidder does not have required account attributes
+    check_account_attributes(
+        &deps.querier,
+        &info.sender,
+        env.block.time,
+        &contract_info.bid_attribute_policy,
+    )?;
+
+    // is bid quote a marker
+    let is_quote_restricted_marker =
+        is_restricted_marker(&deps.querier, bid_order.quote.denom.clone());
+
+    // determine sent funds requirements
+    if is_quote_restricted_marker && !info.funds.is_empty() {
+        // no funds should be sent if quote is a restricted marker
+        return Err(ContractError::SentFundsOrderMismatch);
+    }
+
+    // the quote and fee held in escrow while the bid fills
+    let escrow = match &bid_order.fee {
+        Some(fee) => bid_order
+            .quote
+            .amount
+            .checked_add(fee.amount)
+            .map_err(|_| ContractError::TotalOverflow)?,
+        _ => bid_order.quote.amount,
+    };
+
+    // sent funds must match order if not a restricted marker
+    if !is_quote_restricted_marker
+        && info
+            .funds
+            .ne(&coins(escrow.u128(), bid_order.quote.denom.to_owned()))
+    {
+        return Err(ContractError::SentFundsOrderMismatch);
+    }
+
+    // the id is shared with bids on the book for event tracking
+    if BIDS_V3
+        .may_load(deps.storage, bid_order.id.as_bytes())?
+        .is_some()
+    {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("id")],
+        });
+    }
+
+    let mut response = Response::new().add_attributes(vec![
+        attr("action", ContractAction::CreateMarketBid.to_string()),
+        attr("base", &bid_order.base.denom),
+        attr("id", &bid_order.id),
+        attr(
+            "fee",
+            match &bid_order.fee {
+                Some(fee) => format!("{:?}", fee),
+                _ => "None".into(),
+            },
+        ),
+        attr("quote", &bid_order.quote.denom),
+        attr("quote_size", bid_order.quote.amount.to_string()),
+        attr("worst_price", &bid_order.price),
+    ]);
+
+    if is_quote_restricted_marker {
+        response = response.add_message(transfer_marker_coins(
+            escrow.u128(),
+            bid_order.quote.denom.to_owned(),
+            env.contract.address.to_owned(),
+            bid_order.owner.to_owned(),
+            env.contract.address.to_owned(),
+        )?);
+    }
+
+    // the market bid is newer than every resting ask, so it takes liquidity as the taker
+    bid_order.sequence = next_order_sequence(deps.storage)?;
+
+    let mut fill_count: u32 = 0;
+
+    while fill_count < MAX_MATCH_LIMIT {
+        let ask_order = match get_best_ask(deps.storage, &bid_order.quote.denom, env.block.time)? {
+            Some(ask_order) => ask_order,
+            None => break,
+        };
+
+        let ask_price =
+            Decimal::from_str(&ask_order.price).map_err(|_| ContractError::InvalidFields {
+                fields: vec![String::from("AskOrder.price")],
+            })?;
+
+        // stop once the best ask is worse than the bidder will accept
+        if ask_price.gt(&worst_price) {
+            break;
+        }
+
+        // the largest size increment the remaining quote can pay for at the ask price
+        let affordable_size = Decimal::from(bid_order.get_remaining_quote().u128())
+            .checked_div(ask_price)
+            .ok_or(ContractError::TotalOverflow)?
+            .floor()
+            .to_u128()
+            .ok_or(ContractError::TotalOverflow)?;
+        let affordable_size =
+            affordable_size - affordable_size % contract_info.size_increment.u128();
+
+        let execute_size = min(ask_order.size, Uint128::new(affordable_size));
+
+        if execute_size.is_zero() {
+            break;
+        }
+
+        // calculate gross proceeds using ask price, (price * size), error if overflows
+        let gross_proceeds = ask_price
+            .checked_mul(Decimal::from(execute_size.u128()))
+            .ok_or(ContractError::TotalOverflow)?;
+
+        // stop if gross proceeds is not an integer, the fill can't settle
+        if !gross_proceeds.fract().is_zero() {
+            break;
+        }
+
+        // a halted market isn't filled, and a fill outside the price band is rejected and recorded
+        // as a breach, in both cases the rest of the market bid is refunded below
+        match check_price_band(
+            deps.storage,
+            &contract_info.price_band,
+            &bid_order.quote.denom,
+            ask_price,
+            env.block.time,
+        )? {
+            PriceBandCheck::Within => {}
+            PriceBandCheck::Outside => {
+                let breach_response = record_match_price_band_breach(
+                    deps.branch(),
+                    &env,
+                    &contract_info,
+                    &bid_order.quote.denom,
+                    &ask_order.id,
+                    &bid_order.id,
+                    ask_price,
+                )?;
+                response = response.add_attributes(breach_response.attributes);
+                break;
+            }
+            PriceBandCheck::Halted { .. } => break,
+        }
+
+        // the bidder's own asks never trade, the self-trade prevention policy decides the outcome
+        if ask_order.owner.eq(&bid_order.owner) {
+            let (cancel_ask, cancel_bid, cancel_size) = get_self_trade_cancels(
+                &contract_info.self_trade_prevention,
+                &ask_order,
+                &bid_order,
+                execute_size,
+            )?;
+
+            response = response.add_attributes(vec![
+                attr(
+                    "self_trade_prevention",
+                    contract_info.self_trade_prevention.to_string(),
+                ),
+                attr("ask_id", &ask_order.id),
+                attr("bid_id", &bid_order.id),
+            ]);
+
+            if cancel_ask {
+                let cancel_response = reverse_ask_order(
+                    deps.branch(),
+                    &env,
+                    &contract_info,
+                    ask_order,
+                    ContractAction::CancelAsk,
+                    cancel_size,
+                )?;
+                response = response
+                    .add_submessages(cancel_response.messages)
+                    .add_attributes(cancel_response.attributes);
+            }
+
+            if cancel_bid {
+                // without a size the rest of the market bid is canceled, and refunded below
+                if cancel_size.is_none() {
+                    break;
+                }
+
+                // the market bid gives up the quote and fee the fill would have spent
+                let quote_refund = Uint128::new(
+                    gross_proceeds
+                        .to_u128()
+                        .ok_or(ContractError::TotalOverflow)?,
+                );
+                let fee_refund = bid_order.calculate_fee(quote_refund)?;
+
+                response = add_transfer(
+                    response,
+                    is_quote_restricted_marker,
+                    quote_refund.u128(),
+                    bid_order.quote.denom.to_owned(),
+                    bid_order.owner.to_owned(),
+                    env.contract.address.to_owned(),
+                    env.contract.address.to_owned(),
+                );
+                if let Some(fee_refund) = fee_refund.as_ref().filter(|fee| !fee.amount.is_zero()) {
+                    response = add_transfer(
+                        response,
+                        is_quote_restricted_marker,
+                        fee_refund.amount.u128(),
+                        fee_refund.denom.to_owned(),
+                        bid_order.owner.to_owned(),
+                        env.contract.address.to_owned(),
+                        env.contract.address.to_owned(),
+                    );
+                }
+
+                bid_order.update_remaining_amounts(&Action::Refund {
+                    fee: fee_refund,
+                    quote: Coin {
+                        denom: bid_order.quote.denom.to_owned(),
+                        amount: quote_refund,
+                    },
+                })?;
+            }
+
+            fill_count += 1;
+            continue;
+        }
+
+        // each fill settles the same way as a match of resting orders, the ask is the maker
+        let price = ask_order.price.to_owned();
+        let (fill_response, _) = settle_fill(
+            deps.branch(),
+            &env,
+            &contract_info,
+            ask_order,
+            &mut bid_order,
+            price,
+            execute_size,
+        )?;
+
+        response = response
+            .add_submessages(fill_response.messages)
+            .add_attributes(fill_response.attributes);
+
+        fill_count += 1;
+    }
+
+    // refund the unspent quote and bid fee
+    let quote_refund = bid_order.get_remaining_quote();
+    let fee_refund = bid_order.get_remaining_fee();
+
+    if !quote_refund.is_zero() {
+        response = add_transfer(
+            response,
+            is_quote_restricted_marker,
+            quote_refund.u128(),
+            bid_order.quote.denom.to_owned(),
+            bid_order.owner.to_owned(),
+            env.contract.address.to_owned(),
+            env.contract.address.to_owned(),
+        );
+    }
+
+    if let (Some(fee), false) = (&bid_order.fee, fee_refund.is_zero()) {
+        response = add_transfer(
+            response,
+            is_quote_restricted_marker,
+            fee_refund.u128(),
+            fee.denom.to_owned(),
+            bid_order.owner.to_owned(),
+            env.contract.address.to_owned(),
+            env.contract.address.to_owned(),
+        );
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("filled_size", bid_order.accumulated_base.to_string()),
+        attr("quote_refund", quote_refund.to_string()),
+        attr("fee_refund", fee_refund.to_string()),
+    ]))
+}
@@ -0,0 +1,91 @@
+use crate::ask_order::ASKS_V1;
+use crate::bid_order::BIDS_V3;
+use crate::common::ContractAction;
+use crate::contract::{reverse_ask_order, reverse_bid_order};
+use crate::contract_info::get_contract_info;
+use crate::error::ContractError;
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Order, Response, StdResult};
+use cw_storage_plus::Bound;
+
+pub const DEFAULT_EXPIRE_LIMIT: u32 = 10;
+pub const MAX_EXPIRE_LIMIT: u32 = 30;
+
+/// Expires asks and then bids that have passed their `expires_at` time, up to `limit` orders.
+///
+/// Anyone may sweep expired orders. Each order is refunded to its owner the same way as an
+/// `ExpireAsk` or `ExpireBid` sent by an executor.
+pub fn expire_orders(
+    mut deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExpireWithFunds);
+    }
+
+    let contract_info = get_contract_info(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_EXPIRE_LIMIT).min(MAX_EXPIRE_LIMIT) as usize;
+
+    // orders expiring at or before the block time, ordered by expiration
+    let expired_before = || {
+        let empty_pk: &[u8] = &[];
+        Some(Bound::exclusive((
+            env.block.time.nanos().saturating_add(1),
+            empty_pk,
+        )))
+    };
+
+    let expired_asks = ASKS_V1
+        .idx
+        .expiration
+        .range(deps.storage, None, expired_before(), Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, ask_order)| ask_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let expired_bids = BIDS_V3
+        .idx
+        .expiration
+        .range(deps.storage, None, expired_before(), Order::Ascending)
+        .take(limit - expired_asks.len())
+        .map(|item| item.map(|(_, bid_order)| bid_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let expired_count = expired_asks.len() + expired_bids.len();
+    let mut response = Response::new();
+
+    for ask_order in expired_asks {
+        let expire_response = reverse_ask_order(
+            deps.branch(),
+            &env,
+            &contract_info,
+            ask_order,
+            ContractAction::ExpireAsk,
+            None,
+        )?;
+        response = response
+            .add_submessages(expire_response.messages)
+            .add_attributes(expire_response.attributes);
+    }
+
+    for bid_order in expired_bids {
+        let expire_response = reverse_bid_order(
+            deps.branch(),
+            &env,
+            &contract_info,
+            bid_order,
+            ContractAction::ExpireBid,
+            None,
+        )?;
+        response = response
+            .add_submessages(expire_response.messages)
+            .add_attributes(expire_response.attributes);
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", ContractAction::ExpireOrders.to_string()),
+        attr("expired_count", expired_count.to_string()),
+    ]))
+}
@@ -0,0 +1,339 @@
+use crate::ask_order::{AskOrderV1, ASKS_V1};
+use crate::bid_order::{BidOrderV3, BIDS_V3};
+use crate::common::{ContractAction, OrderType, SelfTradePrevention};
+use crate::contract::{reverse_ask_order, reverse_bid_order, settle_match};
+use crate::contract_info::{get_contract_info, ContractInfoV3};
+use crate::error::ContractError;
+use crate::price_band::{check_price_band, PriceBandCheck};
+use cosmwasm_std::{
+    attr, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Timestamp, Uint128,
+};
+use rust_decimal::Decimal;
+use std::cmp::min;
+use std::str::FromStr;
+
+pub const DEFAULT_MATCH_LIMIT: u32 = 10;
+pub const MAX_MATCH_LIMIT: u32 = 30;
+
+/// Matches crossing asks and bids for every supported quote denom using price-time priority.
+///
+/// The best ask (lowest price, then oldest) is matched against the best bid (highest price, then
+/// oldest) while the ask price is at or below the bid price. Each fill executes at the price of
+/// the older (resting) order for the smaller of the two remaining sizes, and is settled the same
+/// way as an `ExecuteMatch`. Matching stops after `limit` fills.
+///
+/// Orders of the same owner are handled by the self-trade prevention policy, except that the
+/// `reject` policy cancels the newest order of the pair rather than failing the sweep.
+pub fn match_orders(
+    mut deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let contract_info = get_auto_match_contract_info(&get_contract_info(deps.storage)?);
+
+    // only executors may match orders
+    if !contract_info.executors.contains(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::ExecuteWithFunds);
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_MATCH_LIMIT).min(MAX_MATCH_LIMIT);
+    let mut fill_count: u32 = 0;
+    let mut response = Response::new();
+
+    for quote in &contract_info.supported_quote_denoms {
+        while fill_count < limit {
+            let (ask_order, bid_order) = match get_best_orders(deps.storage, quote, env.block.time)?
+            {
+                Some(orders) => orders,
+                None => break,
+            };
+
+            let (price, size) = match get_crossing_fill(&ask_order, &bid_order)? {
+                Some(fill) => fill,
+                None => break,
+            };
+
+            // a halted market is passed over
+            let price_band_check =
+                get_price_band_check(deps.storage, &contract_info, quote, &price, env.block.time)?;
+            if let PriceBandCheck::Halted { .. } = price_band_check {
+                break;
+            }
+
+            let fill_response = settle_match(
+                deps.branch(),
+                &env,
+                &contract_info,
+                &ask_order.id,
+                &bid_order.id,
+                price,
+                size,
+            )?;
+
+            response = response
+                .add_submessages(fill_response.messages)
+                .add_attributes(fill_response.attributes);
+
+            // the match was rejected and recorded as a breach, the market stops matching
+            if price_band_check.eq(&PriceBandCheck::Outside) {
+                break;
+            }
+            fill_count += 1;
+        }
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", ContractAction::MatchOrders.to_string()),
+        attr("fill_count", fill_count.to_string()),
+    ]))
+}
+
+/// Returns the contract info that orders are matched with automatically, by `MatchOrders`, an
+/// immediate-or-cancel or fill-or-kill order, or a market bid.
+///
+/// A rejected self-trade would fail the whole sweep or incoming order and leave the crossed orders
+/// blocking their market, so auto-matching cancels the newest order of the pair instead.
+pub(crate) fn get_auto_match_contract_info(contract_info: &ContractInfoV3) -> ContractInfoV3 {
+    let mut contract_info = contract_info.to_owned();
+    if contract_info
+        .self_trade_prevention
+        .eq(&SelfTradePrevention::Reject)
+    {
+        contract_info.self_trade_prevention = SelfTradePrevention::CancelNewest;
+    }
+    contract_info
+}
+
+/// A newly created order that takes liquidity from the resting book, identified by its id
+pub(crate) enum IncomingOrder {
+    Ask(String),
+    Bid(String),
+}
+
+/// Matches a newly created immediate-or-cancel or fill-or-kill order against the resting book.
+///
+/// Fills execute at the resting order's price, up to `MAX_MATCH_LIMIT` fills. Any unfilled size of
+/// an immediate-or-cancel order is refunded as a cancellation, while a fill-or-kill order that
+/// can't be filled completely fails the whole transaction. An order of the same owner is handled by
+/// the self-trade prevention policy, where the `reject` policy cancels the incoming order.
+pub(crate) fn fill_incoming_order(
+    mut deps: DepsMut,
+    env: &Env,
+    contract_info: &ContractInfoV3,
+    incoming_order: IncomingOrder,
+    order_type: OrderType,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+    let contract_info = &get_auto_match_contract_info(contract_info);
+    let mut fill_count: u32 = 0;
+
+    while fill_count < MAX_MATCH_LIMIT {
+        let orders = match &incoming_order {
+            IncomingOrder::Ask(id) => match ASKS_V1.may_load(deps.storage, id.as_bytes())? {
+                Some(ask_order) if ask_order.is_ready() => {
+                    get_best_bid(deps.storage, &ask_order.quote, env.block.time)?
+                        .map(|bid_order| (ask_order, bid_order))
+                }
+                _ => None,
+            },
+            IncomingOrder::Bid(id) => match BIDS_V3.may_load(deps.storage, id.as_bytes())? {
+                Some(bid_order) => {
+                    get_best_ask(deps.storage, &bid_order.quote.denom, env.block.time)?
+                        .map(|ask_order| (ask_order, bid_order))
+                }
+                None => None,
+            },
+        };
+
+        let (ask_order, bid_order) = match orders {
+            Some(orders) => orders,
+            None => break,
+        };
+
+        let (price, size) = match get_crossing_fill(&ask_order, &bid_order)? {
+            Some(fill) => fill,
+            None => break,
+        };
+
+        // the incoming order isn't filled in a halted market
+        let price_band_check = get_price_band_check(
+            deps.storage,
+            contract_info,
+            &ask_order.quote,
+            &price,
+            env.block.time,
+        )?;
+        if let PriceBandCheck::Halted { .. } = price_band_check {
+            break;
+        }
+
+        let fill_response = settle_match(
+            deps.branch(),
+            env,
+            contract_info,
+            &ask_order.id,
+            &bid_order.id,
+            price,
+            size,
+        )?;
+
+        response = response
+            .add_submessages(fill_response.messages)
+            .add_attributes(fill_response.attributes);
+
+        // the match was rejected and recorded as a breach, the incoming order stops filling
+        if price_band_check.eq(&PriceBandCheck::Outside) {
+            break;
+        }
+        fill_count += 1;
+    }
+
+    // the incoming order is removed once completely filled, refund or fail on any remainder
+    let cancel_response = match incoming_order {
+        IncomingOrder::Ask(id) => match ASKS_V1.may_load(deps.storage, id.as_bytes())? {
+            Some(_) if order_type.eq(&OrderType::FillOrKill) => {
+                return Err(ContractError::FillOrKillNotFilled)
+            }
+            Some(ask_order) => Some(reverse_ask_order(
+                deps,
+                env,
+                contract_info,
+                ask_order,
+                ContractAction::CancelAsk,
+                None,
+            )?),
+            None => None,
+        },
+        IncomingOrder::Bid(id) => match BIDS_V3.may_load(deps.storage, id.as_bytes())? {
+            Some(_) if order_type.eq(&OrderType::FillOrKill) => {
+                return Err(ContractError::FillOrKillNotFilled)
+            }
+            Some(bid_order) => Some(reverse_bid_order(
+                deps,
+                env,
+                contract_info,
+                bid_order,
+                ContractAction::CancelBid,
+                None,
+            )?),
+            None => None,
+        },
+    };
+
+    if let Some(cancel_response) = cancel_response {
+        response = response
+            .add_submessages(cancel_response.messages)
+            .add_attributes(cancel_response.attributes);
+    }
+
+    Ok(response)
+}
+
+/// Returns the best matchable ask and bid for a quote denom, if both sides have orders.
+fn get_best_orders(
+    storage: &dyn Storage,
+    quote: &str,
+    time: Timestamp,
+) -> StdResult<Option<(AskOrderV1, BidOrderV3)>> {
+    Ok(get_best_ask(storage, quote, time)?.zip(get_best_bid(storage, quote, time)?))
+}
+
+/// Returns the lowest priced, oldest ask that can be matched.
+///
+/// Expired asks are passed over, they remain on the book until swept by `ExpireOrders`.
+pub(crate) fn get_best_ask(
+    storage: &dyn Storage,
+    quote: &str,
+    time: Timestamp,
+) -> StdResult<Option<AskOrderV1>> {
+    ASKS_V1
+        .idx
+        .price
+        .sub_prefix(quote.to_owned())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, ask_order)| ask_order))
+        .find(|item| {
+            item.as_ref().map_or(true, |ask_order| {
+                ask_order.is_ready() && !ask_order.is_expired(time)
+            })
+        })
+        .transpose()
+}
+
+/// Returns the highest priced, oldest bid that can be matched.
+///
+/// Expired bids are passed over, they remain on the book until swept by `ExpireOrders`.
+fn get_best_bid(
+    storage: &dyn Storage,
+    quote: &str,
+    time: Timestamp,
+) -> StdResult<Option<BidOrderV3>> {
+    BIDS_V3
+        .idx
+        .price
+        .sub_prefix(quote.to_owned())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, bid_order)| bid_order))
+        .find(|item| {
+            item.as_ref()
+                .map_or(true, |bid_order| !bid_order.is_expired(time))
+        })
+        .transpose()
+}
+
+/// Checks a fill price against the price band of the quote denom's market.
+fn get_price_band_check(
+    storage: &dyn Storage,
+    contract_info: &ContractInfoV3,
+    quote: &str,
+    price: &str,
+    time: Timestamp,
+) -> Result<PriceBandCheck, ContractError> {
+    let price = Decimal::from_str(price).map_err(|_| ContractError::InvalidFields {
+        fields: vec![String::from("price")],
+    })?;
+
+    check_price_band(storage, &contract_info.price_band, quote, price, time)
+}
+
+/// Returns the execution price and size of a fill if the orders cross.
+///
+/// The price is taken from the order that rested on the book first. No fill is returned when the
+/// resulting quote total is not a whole amount, since the orders can't settle against each other.
+fn get_crossing_fill(
+    ask_order: &AskOrderV1,
+    bid_order: &BidOrderV3,
+) -> Result<Option<(String, Uint128)>, ContractError> {
+    let ask_price =
+        Decimal::from_str(&ask_order.price).map_err(|_| ContractError::InvalidFields {
+            fields: vec![String::from("AskOrder.price")],
+        })?;
+
+    let bid_price =
+        Decimal::from_str(&bid_order.price).map_err(|_| ContractError::InvalidFields {
+            fields: vec![String::from("BidOrder.price")],
+        })?;
+
+    if ask_price.gt(&bid_price) {
+        return Ok(None);
+    }
+
+    let (price, execute_price) = if ask_order.sequence <= bid_order.sequence {
+        (ask_order.price.to_owned(), ask_price)
+    } else {
+        (bid_order.price.to_owned(), bid_price)
+    };
+
+    let size = min(ask_order.size, bid_order.get_remaining_base());
+
+    match execute_price.checked_mul(Decimal::from(size.u128())) {
+        Some(total) if total.fract().is_zero() => Ok(Some((price, size))),
+        _ => Ok(None),
+    }
+}
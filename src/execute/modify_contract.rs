@@ -1,62 +1,154 @@
 use crate::ask_order::ASKS_V1;
 use crate::bid_order::BIDS_V3;
-use crate::common::{ContractAction, FeeInfo};
-use crate::contract_info::{get_contract_info, modify_contract_info};
+use crate::common::{AttributePolicy, ContractAction};
+use crate::contract_info::{
+    get_contract_info, modify_contract_info, set_contract_info, ContractInfoV3,
+};
 use crate::error::ContractError;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
-use rust_decimal::Decimal;
+use crate::governance::{
+    next_change_id, ConfigChangeV1, ContractChange, PendingChangeV1, CONFIG_HISTORY_V1,
+    PENDING_CHANGES_V1,
+};
+use crate::util::parse_order_price;
+use cosmwasm_std::{attr, Deps, DepsMut, Env, MessageInfo, Order, Response, Storage, Uint128};
 use std::collections::HashSet;
-use std::str::FromStr;
 
+/// Changes the contract configuration, authorized by the admin.
+///
+/// Without a governance delay the change is applied right away. Otherwise it is checked against
+/// the current contract and queued until the delay has passed, to be applied by
+/// `ApplyPendingChanges`.
 pub fn modify_contract(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: &MessageInfo,
-    approvers: Option<Vec<String>>,
-    executors: Option<Vec<String>>,
-    ask_fee_rate: Option<String>,
-    ask_fee_account: Option<String>,
-    bid_fee_rate: Option<String>,
-    bid_fee_account: Option<String>,
-    ask_required_attributes: Option<Vec<String>>,
-    bid_required_attributes: Option<Vec<String>>,
+    change: ContractChange,
 ) -> Result<Response, ContractError> {
     let contract_info = get_contract_info(deps.storage)?;
 
-    if !contract_info.executors.contains(&info.sender) {
+    if !contract_info.is_admin(&info.sender) {
         return Err(ContractError::Unauthorized);
     }
 
-    let contains_ask = !ASKS_V1.is_empty(deps.storage);
-    check_required_attributes(
-        contains_ask.to_owned(),
-        ask_required_attributes.to_owned(),
-        "ask_required_attributes".to_string(),
+    // the change must be valid now, a delayed change is checked again when it is applied
+    let new_contract_info = change_contract_info(deps.as_ref(), &change)?;
+    let id = next_change_id(deps.storage)?;
+    let response = Response::new().add_attributes(vec![
+        attr("action", ContractAction::ModifyContract.to_string()),
+        attr("change_id", id.to_string()),
+    ]);
+
+    if contract_info.governance_delay == 0 {
+        set_contract_info(deps.storage, &new_contract_info)?;
+        CONFIG_HISTORY_V1.save(
+            deps.storage,
+            id,
+            &ConfigChangeV1 {
+                id,
+                change,
+                applied_at: env.block.into(),
+                old: contract_info,
+                new: new_contract_info,
+            },
+        )?;
+
+        return Ok(response);
+    }
+
+    let effective_at = env.block.time.plus_seconds(contract_info.governance_delay);
+    PENDING_CHANGES_V1.save(
+        deps.storage,
+        id,
+        &PendingChangeV1 {
+            id,
+            change,
+            proposed_by: info.sender.to_owned(),
+            proposed_at: env.block.into(),
+            effective_at,
+        },
+    )?;
+
+    Ok(response.add_attribute("effective_at", effective_at.to_string()))
+}
+
+/// Applies a configuration change and records it in the configuration history.
+pub(crate) fn apply_contract_change(
+    deps: DepsMut,
+    env: &Env,
+    id: u64,
+    change: ContractChange,
+) -> Result<(), ContractError> {
+    let old = get_contract_info(deps.storage)?;
+    let new = change_contract_info(deps.as_ref(), &change)?;
+    set_contract_info(deps.storage, &new)?;
+
+    CONFIG_HISTORY_V1.save(
+        deps.storage,
+        id,
+        &ConfigChangeV1 {
+            id,
+            change,
+            applied_at: env.block.to_owned().into(),
+            old,
+            new,
+        },
     )?;
-    check_fee_rate(
+
+    Ok(())
+}
+
+/// Checks a configuration change against the open orders and returns the contract info with it
+/// applied, leaving the stored contract info unchanged.
+fn change_contract_info(
+    deps: Deps,
+    change: &ContractChange,
+) -> Result<ContractInfoV3, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+
+    let contains_ask = !ASKS_V1.is_empty(deps.storage);
+    check_attribute_policy(
         contains_ask.to_owned(),
-        contract_info.ask_fee_info.to_owned(),
-        ask_fee_rate.to_owned(),
-        ask_fee_account.to_owned(),
-        "ask_fee".to_string(),
+        &change.ask_attribute_policy,
+        "ask_attribute_policy".to_string(),
     )?;
 
     let contains_bid = !BIDS_V3.is_empty(deps.storage);
-    check_required_attributes(
-        contains_bid.to_owned(),
-        bid_required_attributes.to_owned(),
-        "bid_required_attributes".to_string(),
-    )?;
-    check_fee_rate(
+    check_attribute_policy(
         contains_bid.to_owned(),
-        contract_info.bid_fee_info.to_owned(),
-        bid_fee_rate.to_owned(),
-        bid_fee_account.to_owned(),
-        "bid_fee".to_string(),
+        &change.bid_attribute_policy,
+        "bid_attribute_policy".to_string(),
     )?;
 
+    // fees may change with open orders, orders are charged by the fee terms they were created with
+
+    // a denom can't be removed while open orders use it, they could no longer be matched
+    if let Some(denoms) = &change.remove_supported_quote_denoms {
+        let asks_use_denom = ASKS_V1
+            .range(deps.storage, None, None, Order::Ascending)
+            .any(|item| matches!(item, Ok((_, ask_order)) if denoms.contains(&ask_order.quote)));
+        let bids_use_denom = BIDS_V3
+            .range(deps.storage, None, None, Order::Ascending)
+            .any(|item| matches!(item, Ok((_, bid_order)) if denoms.contains(&bid_order.quote.denom)));
+        if asks_use_denom || bids_use_denom {
+            return Err(ContractError::InvalidFields {
+                fields: vec!["remove_supported_quote_denoms".to_string()],
+            });
+        }
+    }
+
+    if let Some(denoms) = &change.remove_convertible_base_denoms {
+        if ASKS_V1
+            .range(deps.storage, None, None, Order::Ascending)
+            .any(|item| matches!(item, Ok((_, ask_order)) if denoms.contains(&ask_order.base)))
+        {
+            return Err(ContractError::InvalidFields {
+                fields: vec!["remove_convertible_base_denoms".to_string()],
+            });
+        }
+    }
+
     if contains_ask || contains_bid {
-        match &approvers {
+        match &change.approvers {
             None => {}
             Some(approvers) => {
                 let current_approvers: HashSet<String> = contract_info
@@ -74,31 +166,29 @@ pub fn modify_contract(
         }
     }
 
-    modify_contract_info(
-        deps,
-        approvers,
-        executors,
-        ask_fee_rate,
-        ask_fee_account,
-        bid_fee_rate,
-        bid_fee_account,
-        ask_required_attributes,
-        bid_required_attributes,
-    )?;
-
-    let response =
-        Response::new().add_attribute("action", ContractAction::ModifyContract.to_string());
+    // resting orders must conform to the new price precision and size increment
+    if change.price_precision.is_some() || change.size_increment.is_some() {
+        check_resting_orders_conform(
+            deps.storage,
+            change
+                .price_precision
+                .unwrap_or(contract_info.price_precision),
+            change
+                .size_increment
+                .unwrap_or(contract_info.size_increment),
+        )?;
+    }
 
-    Ok(response)
+    modify_contract_info(deps, change)
 }
 
-fn check_required_attributes(
+fn check_attribute_policy(
     contains_attribute_side_order: bool,
-    new_required_attributes: Option<Vec<String>>,
+    new_attribute_policy: &Option<AttributePolicy>,
     error_field_name: String,
 ) -> Result<(), ContractError> {
     if contains_attribute_side_order {
-        match &new_required_attributes {
+        match new_attribute_policy {
             None => {}
             Some(_) => {
                 return Err(ContractError::InvalidFields {
@@ -111,171 +201,37 @@ fn check_required_attributes(
     Ok(())
 }
 
-fn check_fee_rate(
-    contains_fee_side_order: bool,
-    current_fee_info: Option<FeeInfo>,
-    new_fee_rate: Option<String>,
-    new_fee_account: Option<String>,
-    error_field_name: String,
+/// Errors with the ids of the resting orders whose price or remaining size doesn't conform to the
+/// price precision and size increment.
+fn check_resting_orders_conform(
+    storage: &dyn Storage,
+    price_precision: Uint128,
+    size_increment: Uint128,
 ) -> Result<(), ContractError> {
-    if contains_fee_side_order {
-        match (&new_fee_rate, &new_fee_account) {
-            (None, None) => {}
-            (Some(new_fee_rate_str), _) => {
-                let invalid_change = match current_fee_info {
-                    Some(current_fee) => {
-                        // Currently there is a fee, make sure the rate is not changing
+    let conforms = |price: &str, size: Uint128| {
+        (size.u128() % size_increment.u128()).eq(&0)
+            && parse_order_price(price, price_precision).is_ok()
+    };
 
-                        let current_fee_rate_dec = Decimal::from_str(&current_fee.rate).unwrap();
-                        let new_fee_rate_dec = Decimal::from_str(&new_fee_rate_str).unwrap();
-                        current_fee_rate_dec.ne(&new_fee_rate_dec)
-                    }
-                    None => {
-                        // Currently there is no fee
+    let mut ids: Vec<String> = Vec::new();
 
-                        // Error since trying to set a fee with an existing order
-                        true
-                    }
-                };
-                if invalid_change {
-                    return Err(ContractError::InvalidFields {
-                        fields: vec![error_field_name],
-                    });
-                }
-            }
-            (_, _) => {}
-        }
-    }
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod modify_contract_check_tests {
-    use crate::common::FeeInfo;
-    use crate::error::ContractError;
-    use crate::execute::modify_contract::check_fee_rate;
-    use cosmwasm_std::Addr;
-
-    #[test]
-    fn check_fee_rate_contains_order_and_only_account_change_return_ok() {
-        let result = check_fee_rate(
-            true,
-            Some(FeeInfo {
-                rate: "0.010".to_string(), // String is different, but Decimal is same
-                account: Addr::unchecked("old_account"),
-            }),
-            Some("0.01".to_string()), // String is different, but Decimal is same
-            Some("new_account".to_string()),
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => {}
-            Err(error) => {
-                panic!("unexpected error: {:?}", error)
-            }
-        }
-    }
-
-    #[test]
-    fn check_fee_rate_contains_order_and_rate_change_return_err() {
-        let result = check_fee_rate(
-            true,
-            Some(FeeInfo {
-                rate: "0.02".to_string(),
-                account: Addr::unchecked("old_account"),
-            }),
-            Some("0.01".to_string()),
-            Some("new_account".to_string()),
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => panic!("unexpected because invalid version"),
-            Err(error) => match error {
-                ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["test_fee"]);
-                }
-                _ => panic!("unexpected error: {:?}", error),
-            },
+    for item in ASKS_V1.range(storage, None, None, Order::Ascending) {
+        let (_, ask_order) = item?;
+        if !conforms(&ask_order.price, ask_order.size) {
+            ids.push(ask_order.id);
         }
     }
 
-    #[test]
-    fn check_fee_rate_contains_order_and_rate_change_from_none_return_err() {
-        let result = check_fee_rate(
-            true,
-            None,
-            Some("0.01".to_string()),
-            Some("new_account".to_string()),
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => panic!("unexpected because invalid version"),
-            Err(error) => match error {
-                ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["test_fee"]);
-                }
-                _ => panic!("unexpected error: {:?}", error),
-            },
+    for item in BIDS_V3.range(storage, None, None, Order::Ascending) {
+        let (_, bid_order) = item?;
+        if !conforms(&bid_order.price, bid_order.get_remaining_base()) {
+            ids.push(bid_order.id);
         }
     }
 
-    #[test]
-    fn check_fee_rate_contains_order_and_only_rate_change_return_err() {
-        let result = check_fee_rate(
-            true,
-            Some(FeeInfo {
-                rate: "0.02".to_string(),
-                account: Addr::unchecked("old_account"),
-            }),
-            Some("0.01".to_string()),
-            None,
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => panic!("unexpected because invalid version"),
-            Err(error) => match error {
-                ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["test_fee"]);
-                }
-                _ => panic!("unexpected error: {:?}", error),
-            },
-        }
+    if !ids.is_empty() {
+        return Err(ContractError::NonConformingOrders { ids });
     }
 
-    #[test]
-    fn check_fee_rate_contains_order_and_only_account_set_return_ok() {
-        // This should not happen because the msg.validate() checks that
-        //  the fee_rate and fee_account are modified together
-        let result = check_fee_rate(
-            true,
-            None,
-            None,
-            Some("new_account".to_string()),
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => {}
-            Err(error) => {
-                panic!("unexpected error: {:?}", error)
-            }
-        }
-    }
-
-    #[test]
-    fn check_fee_rate_no_order_return_ok() {
-        let result = check_fee_rate(
-            false,
-            None,
-            Some("0.01".to_string()),
-            Some("new_account".to_string()),
-            "test_fee".to_string(),
-        );
-        match result {
-            Ok(_) => {}
-            Err(error) => {
-                panic!("unexpected error: {:?}", error)
-            }
-        }
-    }
+    Ok(())
 }
@@ -0,0 +1,307 @@
+use crate::account_limits::check_account_limits;
+use crate::ask_order::{AskOrderClass, ASKS_V1};
+use crate::bid_order::BIDS_V3;
+use crate::common::{next_order_sequence, ContractAction};
+use crate::contract::check_bid_fee;
+use crate::contract_info::get_contract_info;
+use crate::error::ContractError;
+use crate::util::{add_transfer, is_restricted_marker, parse_order_price, transfer_marker_coins};
+use cosmwasm_std::{attr, coins, Addr, Coin, DepsMut, Env, MessageInfo, Response, Uint128};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
+
+/// Changes the price and size of a resting basic ask in place, keeping its id.
+///
+/// Only the difference in size is escrowed or refunded. The ask keeps its time priority unless
+/// the price changes or the size increases.
+pub fn modify_ask(
+    deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    id: String,
+    price: String,
+    size: Uint128,
+) -> Result<Response, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+
+    let mut ask_order = ASKS_V1
+        .load(deps.storage, id.as_bytes())
+        .map_err(|error| ContractError::LoadOrderFailed { error })?;
+
+    // only the asker may modify the order
+    if !ask_order.owner.eq(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // escrow of convertible asks is tied to their approval
+    if let AskOrderClass::Convertible { .. } = ask_order.class {
+        return Err(ContractError::ModifyConvertibleAsk);
+    }
+
+    if ask_order.is_expired(env.block.time) {
+        return Err(ContractError::OrderExpired);
+    }
+
+    // error if order size is not multiple of size_increment
+    if (size.u128() % contract_info.size_increment.u128()).ne(&0) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("size")],
+        });
+    }
+
+    // error if price is not positive or smaller than allowed price precision
+    let ask_price = parse_order_price(&price, contract_info.price_precision)?;
+    let price_changed = parse_order_price(&ask_order.price, contract_info.price_precision)
+        .map_or(true, |current_price| current_price.ne(&ask_price));
+
+    // error if the order is outside the quote denom's size and notional limits
+    contract_info.check_order_limits(
+        &ask_order.quote,
+        Some(size),
+        ask_price
+            .checked_mul(Decimal::from(size.u128()))
+            .ok_or(ContractError::TotalOverflow)?,
+    )?;
+
+    // error if the added size takes the asker over their escrow limits
+    if size.gt(&ask_order.size) {
+        check_account_limits(
+            deps.storage,
+            contract_info.get_account_limits(&ask_order.owner),
+            &ask_order.owner,
+            false,
+            &Coin {
+                denom: ask_order.base.to_owned(),
+                amount: size - ask_order.size,
+            },
+        )?;
+    }
+
+    // is ask base a marker
+    let is_base_restricted_marker = is_restricted_marker(&deps.querier, ask_order.base.clone());
+
+    let (response, size_increased) = adjust_escrow(
+        Response::new(),
+        &env,
+        info,
+        &ask_order.owner,
+        &ask_order.base,
+        ask_order.size,
+        size,
+        is_base_restricted_marker,
+    )?;
+
+    // changing the price or adding size moves the ask to the back of its new price level
+    if price_changed || size_increased {
+        ask_order.sequence = next_order_sequence(deps.storage)?;
+    }
+
+    ask_order.price = price;
+    ask_order.size = size;
+
+    ASKS_V1.save(deps.storage, ask_order.id.as_bytes(), &ask_order)?;
+
+    Ok(response.add_attributes(vec![
+        attr("action", ContractAction::ModifyAsk.to_string()),
+        attr("id", &ask_order.id),
+        attr("price", &ask_order.price),
+        attr("size", ask_order.size.to_string()),
+    ]))
+}
+
+/// Changes the price, size, quote size and fee of a resting bid in place, keeping its id.
+///
+/// The new amounts describe what remains of the bid, so any previous fills are settled and the
+/// order's accumulated amounts start over. Only the difference between the remaining quote and fee
+/// and the new quote size and fee is escrowed or refunded. The bid keeps its time priority unless
+/// the price changes or the size increases.
+#[allow(clippy::too_many_arguments)]
+pub fn modify_bid(
+    deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    id: String,
+    price: String,
+    size: Uint128,
+    quote_size: Uint128,
+    fee: Option<Coin>,
+) -> Result<Response, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+
+    let mut bid_order = BIDS_V3
+        .load(deps.storage, id.as_bytes())
+        .map_err(|error| ContractError::LoadOrderFailed { error })?;
+
+    // only the bidder may modify the order
+    if !bid_order.owner.eq(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if bid_order.is_expired(env.block.time) {
+        return Err(ContractError::OrderExpired);
+    }
+
+    // error if price is not positive or smaller than allowed price precision
+    let bid_price = parse_order_price(&price, contract_info.price_precision)?;
+    let price_changed = parse_order_price(&bid_order.price, contract_info.price_precision)
+        .map_or(true, |current_price| current_price.ne(&bid_price));
+
+    // error if order size is not multiple of size_increment
+    if (size.u128() % contract_info.size_increment.u128()).ne(&0) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("size")],
+        });
+    }
+
+    // calculate quote total (price * size), error if overflows
+    let total = bid_price
+        .checked_mul(Decimal::from(size.u128()))
+        .ok_or(ContractError::TotalOverflow)?;
+
+    // error if total is not an integer
+    if !total.fract().is_zero() {
+        return Err(ContractError::NonIntegerTotal);
+    }
+
+    // Validate the quote_size matches the size * price
+    if total.ne(&Decimal::from(quote_size.u128())) {
+        return Err(ContractError::SentFundsOrderMismatch);
+    }
+
+    // error if the fee does not match the bid fee rate applied to the total
+    check_bid_fee(&contract_info, &fee, &bid_order.quote.denom, total)?;
+
+    // error if the order is outside the quote denom's size and notional limits
+    contract_info.check_order_limits(&bid_order.quote.denom, Some(size), total)?;
+
+    // is bid quote a marker
+    let is_quote_restricted_marker =
+        is_restricted_marker(&deps.querier, bid_order.quote.denom.clone());
+
+    let current_escrow = bid_order
+        .get_remaining_quote()
+        .checked_add(bid_order.get_remaining_fee())
+        .map_err(|_| ContractError::TotalOverflow)?;
+    let escrow = match &fee {
+        Some(fee) => quote_size
+            .checked_add(fee.amount)
+            .map_err(|_| ContractError::TotalOverflow)?,
+        None => quote_size,
+    };
+
+    // error if the added quote and fee take the bidder over their escrow limits
+    if escrow.gt(&current_escrow) {
+        check_account_limits(
+            deps.storage,
+            contract_info.get_account_limits(&bid_order.owner),
+            &bid_order.owner,
+            false,
+            &Coin {
+                denom: bid_order.quote.denom.to_owned(),
+                amount: escrow - current_escrow,
+            },
+        )?;
+    }
+
+    let (response, _) = adjust_escrow(
+        Response::new(),
+        &env,
+        info,
+        &bid_order.owner,
+        &bid_order.quote.denom,
+        current_escrow,
+        escrow,
+        is_quote_restricted_marker,
+    )?;
+
+    // changing the price or adding size moves the bid to the back of its new price level
+    if price_changed || size.gt(&bid_order.get_remaining_base()) {
+        bid_order.sequence = next_order_sequence(deps.storage)?;
+    }
+
+    bid_order.price = price;
+    bid_order.base.amount = size;
+    bid_order.quote.amount = quote_size;
+    bid_order.fee = fee;
+    // the fee is escrowed again at the current rate, so the bid takes the current fee terms
+    bid_order.fee_terms = Some(contract_info.bid_fee_terms());
+    bid_order.accumulated_base = Uint128::zero();
+    bid_order.accumulated_quote = Uint128::zero();
+    bid_order.accumulated_fee = Uint128::zero();
+
+    BIDS_V3.save(deps.storage, bid_order.id.as_bytes(), &bid_order)?;
+
+    Ok(response.add_attributes(vec![
+        attr("action", ContractAction::ModifyBid.to_string()),
+        attr("id", &bid_order.id),
+        attr(
+            "fee",
+            match &bid_order.fee {
+                Some(fee) => format!("{:?}", fee),
+                _ => "None".into(),
+            },
+        ),
+        attr("price", &bid_order.price),
+        attr("quote_size", bid_order.quote.amount.to_string()),
+        attr("size", bid_order.base.amount.to_string()),
+    ]))
+}
+
+/// Escrows or refunds the difference between the current and new escrowed amounts of a denom.
+///
+/// Funds must be sent for exactly the increase unless the denom is a restricted marker, in which
+/// case the contract transfers it from the owner. Returns whether the escrow increased.
+#[allow(clippy::too_many_arguments)]
+fn adjust_escrow(
+    mut response: Response,
+    env: &Env,
+    info: &MessageInfo,
+    owner: &Addr,
+    denom: &str,
+    current_amount: Uint128,
+    amount: Uint128,
+    is_restricted_marker: bool,
+) -> Result<(Response, bool), ContractError> {
+    let difference = match amount.cmp(&current_amount) {
+        Ordering::Greater => amount - current_amount,
+        _ => Uint128::zero(),
+    };
+
+    // sent funds must match the increase if not a restricted marker
+    let expected_funds = match is_restricted_marker || difference.is_zero() {
+        true => vec![],
+        false => coins(difference.u128(), denom),
+    };
+
+    if info.funds.ne(&expected_funds) {
+        return Err(ContractError::SentFundsOrderMismatch);
+    }
+
+    match amount.cmp(&current_amount) {
+        Ordering::Greater => {
+            if is_restricted_marker {
+                response = response.add_message(transfer_marker_coins(
+                    difference.u128(),
+                    denom,
+                    env.contract.address.to_owned(),
+                    owner.to_owned(),
+                    env.contract.address.to_owned(),
+                )?);
+            }
+            Ok((response, true))
+        }
+        Ordering::Less => {
+            response = add_transfer(
+                response,
+                is_restricted_marker,
+                (current_amount - amount).u128(),
+                denom,
+                owner.to_owned(),
+                env.contract.address.to_owned(),
+                env.contract.address.to_owned(),
+            );
+            Ok((response, false))
+        }
+        Ordering::Equal => Ok((response, false)),
+    }
+}
@@ -0,0 +1,80 @@
+use crate::common::ContractAction;
+use crate::contract_info::get_contract_info;
+use crate::error::ContractError;
+use crate::execute::modify_contract::apply_contract_change;
+use crate::governance::{get_due_changes, PENDING_CHANGES_V1};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Applies the pending configuration changes whose effective time has been reached, in the order
+/// they were proposed.
+///
+/// Anyone may apply due changes. Each change is checked against the open orders when applied, a
+/// change that is no longer valid is dropped without being applied, and the reason is recorded in
+/// the `skip_reason` attribute so it can't hold back the changes queued after it.
+pub fn apply_pending_changes(
+    mut deps: DepsMut,
+    env: Env,
+    _info: &MessageInfo,
+) -> Result<Response, ContractError> {
+    let due_changes = get_due_changes(deps.storage, env.block.time)?;
+    let mut applied_count: u32 = 0;
+    let mut skipped_count: u32 = 0;
+    let mut response = Response::new();
+
+    for pending_change in due_changes {
+        PENDING_CHANGES_V1.remove(deps.storage, pending_change.id);
+        match apply_contract_change(
+            deps.branch(),
+            &env,
+            pending_change.id,
+            pending_change.change,
+        ) {
+            Ok(()) => {
+                applied_count += 1;
+                response = response.add_attribute("change_id", pending_change.id.to_string());
+            }
+            // a storage failure isn't a property of the change, fail the whole message
+            Err(ContractError::Std(error)) => return Err(ContractError::Std(error)),
+            Err(error) => {
+                skipped_count += 1;
+                response = response.add_attributes(vec![
+                    attr("skipped_change_id", pending_change.id.to_string()),
+                    attr("skip_reason", error.to_string()),
+                ]);
+            }
+        }
+    }
+
+    Ok(response.add_attributes(vec![
+        attr("action", ContractAction::ApplyPendingChanges.to_string()),
+        attr("applied_count", applied_count.to_string()),
+        attr("skipped_count", skipped_count.to_string()),
+    ]))
+}
+
+/// Cancels a pending configuration change before it is applied, authorized by the admin.
+pub fn cancel_pending_change(
+    deps: DepsMut,
+    _env: Env,
+    info: &MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let contract_info = get_contract_info(deps.storage)?;
+
+    if !contract_info.is_admin(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if !PENDING_CHANGES_V1.has(deps.storage, id) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("id")],
+        });
+    }
+
+    PENDING_CHANGES_V1.remove(deps.storage, id);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", ContractAction::CancelPendingChange.to_string()),
+        attr("change_id", id.to_string()),
+    ]))
+}
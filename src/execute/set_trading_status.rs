@@ -0,0 +1,96 @@
+use crate::common::{ContractAction, ContractStatus};
+use crate::contract_info::{get_contract_info, set_contract_info};
+use crate::error::ContractError;
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response, Storage};
+
+/// Sets the contract status and the per-side pauses, fields that aren't set are left unchanged.
+pub fn set_trading_status(
+    deps: DepsMut,
+    _env: Env,
+    info: &MessageInfo,
+    status: Option<ContractStatus>,
+    asks_paused: Option<bool>,
+    bids_paused: Option<bool>,
+) -> Result<Response, ContractError> {
+    let mut contract_info = get_contract_info(deps.storage)?;
+
+    if !contract_info.is_admin(&info.sender) {
+        return Err(ContractError::Unauthorized);
+    }
+
+    if let Some(status) = status {
+        contract_info.trading_status.status = status;
+    }
+    if let Some(asks_paused) = asks_paused {
+        contract_info.trading_status.asks_paused = asks_paused;
+    }
+    if let Some(bids_paused) = bids_paused {
+        contract_info.trading_status.bids_paused = bids_paused;
+    }
+
+    set_contract_info(deps.storage, &contract_info)?;
+
+    let trading_status = contract_info.trading_status;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", ContractAction::SetTradingStatus.to_string()),
+        attr("status", trading_status.status.to_string()),
+        attr("asks_paused", trading_status.asks_paused.to_string()),
+        attr("bids_paused", trading_status.bids_paused.to_string()),
+    ]))
+}
+
+/// Returns an error if the contract's trading status doesn't allow an execute message.
+///
+/// Creating, approving, modifying and matching orders requires an active contract, and creating,
+/// approving or modifying an order also requires its side not to be paused. Canceling, expiring
+/// and rejecting orders is also allowed in cancel-only mode. Batches are checked op by op.
+pub fn check_trading_status(storage: &dyn Storage, msg: &ExecuteMsg) -> Result<(), ContractError> {
+    let trading_status = get_contract_info(storage)?.trading_status;
+
+    // whether the message only removes orders, and the side of the order it adds or changes
+    let (removes_order, side) = match msg {
+        ExecuteMsg::CreateAsk { .. }
+        | ExecuteMsg::ApproveAsk { .. }
+        | ExecuteMsg::ModifyAsk { .. } => (false, Some(("ask", trading_status.asks_paused))),
+        ExecuteMsg::CreateBid { .. }
+        | ExecuteMsg::CreateMarketBid { .. }
+        | ExecuteMsg::ModifyBid { .. } => (false, Some(("bid", trading_status.bids_paused))),
+        ExecuteMsg::ExecuteMatch { .. } | ExecuteMsg::MatchOrders { .. } => (false, None),
+        ExecuteMsg::CancelAsk { .. }
+        | ExecuteMsg::CancelBid { .. }
+        | ExecuteMsg::ExpireAsk { .. }
+        | ExecuteMsg::ExpireBid { .. }
+        | ExecuteMsg::ExpireOrders { .. }
+        | ExecuteMsg::RejectAsk { .. }
+        | ExecuteMsg::RejectBid { .. } => (true, None),
+        ExecuteMsg::Batch { .. }
+        | ExecuteMsg::WithdrawFees { .. }
+        | ExecuteMsg::SetTradingStatus { .. }
+        | ExecuteMsg::ProposeAdmin { .. }
+        | ExecuteMsg::AcceptAdmin {}
+        | ExecuteMsg::CancelPendingChange { .. }
+        | ExecuteMsg::ApplyPendingChanges {}
+        | ExecuteMsg::ModifyContract { .. } => return Ok(()),
+    };
+
+    let is_allowed = match trading_status.status {
+        ContractStatus::Active => true,
+        ContractStatus::CancelOnly => removes_order,
+        ContractStatus::Halted => false,
+    };
+    if !is_allowed {
+        return Err(ContractError::ContractStatusRestricted {
+            status: trading_status.status.to_string(),
+        });
+    }
+
+    if let Some((side, true)) = side {
+        return Err(ContractError::OrdersPaused {
+            side: side.to_string(),
+        });
+    }
+
+    Ok(())
+}
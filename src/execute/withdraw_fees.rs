@@ -0,0 +1,45 @@
+use crate::common::ContractAction;
+use crate::error::ContractError;
+use crate::fee_ledger::take_accrued_fee;
+use crate::util::{add_transfer, is_restricted_marker};
+use cosmwasm_std::{attr, DepsMut, Env, MessageInfo, Response};
+
+/// Sends the fees accrued to the sender's fee account in a denom to the sender.
+///
+/// Restricted marker denoms are transferred from the contract with `transfer_marker_coins`.
+pub fn withdraw_fees(
+    deps: DepsMut,
+    env: Env,
+    info: &MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    // return error if funds sent
+    if !info.funds.is_empty() {
+        return Err(ContractError::WithdrawWithFunds);
+    }
+
+    let amount = take_accrued_fee(deps.storage, &info.sender, &denom)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::NoAccruedFees);
+    }
+
+    let is_restricted_marker = is_restricted_marker(&deps.querier, denom.to_owned());
+
+    let response = add_transfer(
+        Response::new().add_attributes(vec![
+            attr("action", ContractAction::WithdrawFees.to_string()),
+            attr("account", info.sender.to_string()),
+            attr("denom", &denom),
+            attr("amount", amount.to_string()),
+        ]),
+        is_restricted_marker,
+        amount.u128(),
+        denom,
+        info.sender.to_owned(),
+        env.contract.address.to_owned(),
+        env.contract.address,
+    );
+
+    Ok(response)
+}
@@ -0,0 +1,96 @@
+use cosmwasm_std::{Addr, Coin, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const NAMESPACE_ACCRUED_FEE: &str = "accrued_fee";
+
+/// Fees accrued in the contract keyed by (fee account, quote denom), withdrawn by the fee account.
+pub const ACCRUED_FEES_V1: Map<(&Addr, &str), Uint128> = Map::new(NAMESPACE_ACCRUED_FEE);
+
+/// Fees accrued to a fee account in one denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccruedFee {
+    pub account: Addr,
+    pub amount: Coin,
+}
+
+/// Adds a fee to an account's accrued fees in the denom.
+pub fn accrue_fee(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    ACCRUED_FEES_V1.update(storage, (account, denom), |accrued| -> StdResult<_> {
+        Ok(accrued.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    Ok(())
+}
+
+/// Pays an amount out of an account's accrued fees in the denom. Returns false, leaving the accrued
+/// fees unchanged, when they don't cover the amount.
+pub fn pay_from_accrued_fee(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<bool> {
+    let accrued = ACCRUED_FEES_V1
+        .may_load(storage, (account, denom))?
+        .unwrap_or_default();
+
+    if accrued < amount {
+        return Ok(false);
+    }
+
+    match accrued - amount {
+        remaining if remaining.is_zero() => ACCRUED_FEES_V1.remove(storage, (account, denom)),
+        remaining => ACCRUED_FEES_V1.save(storage, (account, denom), &remaining)?,
+    }
+
+    Ok(true)
+}
+
+/// Removes and returns an account's accrued fees in the denom.
+pub fn take_accrued_fee(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    denom: &str,
+) -> StdResult<Uint128> {
+    let accrued = ACCRUED_FEES_V1
+        .may_load(storage, (account, denom))?
+        .unwrap_or_default();
+
+    ACCRUED_FEES_V1.remove(storage, (account, denom));
+
+    Ok(accrued)
+}
+
+/// Returns the accrued fees of an account, or of all accounts, by account and denom.
+pub fn get_accrued_fees(
+    storage: &dyn Storage,
+    account: Option<&Addr>,
+) -> StdResult<Vec<AccruedFee>> {
+    let to_accrued_fee = |account: Addr, denom: String, amount: Uint128| AccruedFee {
+        account,
+        amount: Coin { denom, amount },
+    };
+
+    match account {
+        Some(account) => ACCRUED_FEES_V1
+            .prefix(account)
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| {
+                item.map(|(denom, amount)| to_accrued_fee(account.to_owned(), denom, amount))
+            })
+            .collect(),
+        None => ACCRUED_FEES_V1
+            .range(storage, None, None, Order::Ascending)
+            .map(|item| {
+                item.map(|((account, denom), amount)| to_accrued_fee(account, denom, amount))
+            })
+            .collect(),
+    }
+}
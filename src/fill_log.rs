@@ -0,0 +1,134 @@
+use crate::common::{Action, BlockInfo, Event};
+use cosmwasm_std::{Order, StdResult, Storage};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const NAMESPACE_FILL: &str = "fill";
+pub const NAMESPACE_FILL_ASK: &str = "fill__ask";
+pub const NAMESPACE_FILL_BID: &str = "fill__bid";
+const NAMESPACE_FILL_SEQUENCE: &str = "fill_sequence";
+
+/// The most fills kept in the log, the oldest fill is removed once the log is full.
+pub const MAX_FILL_LOG_SIZE: u64 = 1000;
+
+const FILL_SEQUENCE: Item<u64> = Item::new(NAMESPACE_FILL_SEQUENCE);
+
+/// Append-only log of fills keyed by (block height, fill sequence).
+pub const FILLS_V1: IndexedMap<(u64, u64), FillV1, FillIndexes> = IndexedMap::new(
+    NAMESPACE_FILL,
+    FillIndexes {
+        ask: MultiIndex::new(fill_ask_index, NAMESPACE_FILL, NAMESPACE_FILL_ASK),
+        bid: MultiIndex::new(fill_bid_index, NAMESPACE_FILL, NAMESPACE_FILL_BID),
+    },
+);
+
+/// Secondary indexes maintained alongside `FILLS_V1`.
+pub struct FillIndexes<'a> {
+    /// Fills by ask order id
+    pub ask: MultiIndex<'a, String, FillV1, (u64, u64)>,
+    /// Fills by bid order id
+    pub bid: MultiIndex<'a, String, FillV1, (u64, u64)>,
+}
+
+impl<'a> IndexList<FillV1> for FillIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<FillV1>> + '_> {
+        let v: Vec<&dyn Index<FillV1>> = vec![&self.ask, &self.bid];
+        Box::new(v.into_iter())
+    }
+}
+
+fn fill_ask_index(_pk: &[u8], fill: &FillV1) -> String {
+    fill.ask_id.to_owned()
+}
+
+fn fill_bid_index(_pk: &[u8], fill: &FillV1) -> String {
+    fill.bid_id.to_owned()
+}
+
+/// A match between an ask and a bid, the event action is always an `Action::Fill`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FillV1 {
+    pub ask_id: String,
+    pub bid_id: String,
+    pub event: Event,
+    pub sequence: u64,
+}
+
+/// Appends a fill to the log, removing the oldest fill if the log is full.
+pub fn record_fill(
+    storage: &mut dyn Storage,
+    block_info: BlockInfo,
+    ask_id: &str,
+    bid_id: &str,
+    action: Action,
+) -> StdResult<()> {
+    let sequence = FILL_SEQUENCE.may_load(storage)?.unwrap_or_default() + 1;
+    FILL_SEQUENCE.save(storage, &sequence)?;
+
+    FILLS_V1.save(
+        storage,
+        (block_info.height, sequence),
+        &FillV1 {
+            ask_id: ask_id.to_owned(),
+            bid_id: bid_id.to_owned(),
+            event: Event { action, block_info },
+            sequence,
+        },
+    )?;
+
+    // the log holds one fill per sequence, so it is over capacity once past the maximum size
+    if sequence > MAX_FILL_LOG_SIZE {
+        let oldest_key = FILLS_V1
+            .keys(storage, None, None, Order::Ascending)
+            .next()
+            .transpose()?;
+
+        if let Some(oldest_key) = oldest_key {
+            FILLS_V1.remove(storage, oldest_key)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{record_fill, FILLS_V1, MAX_FILL_LOG_SIZE};
+    use crate::common::{Action, BlockInfo};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, Order, StdResult};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn record_fill_removes_oldest_fill_when_full() {
+        let mut deps = mock_provenance_dependencies();
+        let block_info: BlockInfo = mock_env().block.into();
+
+        for _ in 0..MAX_FILL_LOG_SIZE + 2 {
+            record_fill(
+                &mut deps.storage,
+                block_info.to_owned(),
+                "ask_id",
+                "bid_id",
+                Action::Fill {
+                    base: coin(100, "base_1"),
+                    fee: None,
+                    price: "2".into(),
+                    quote: coin(200, "quote_1"),
+                },
+            )
+            .unwrap();
+        }
+
+        let sequences = FILLS_V1
+            .range(&deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, fill)| fill.sequence))
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(sequences.len() as u64, MAX_FILL_LOG_SIZE);
+        assert_eq!(sequences.first(), Some(&3));
+        assert_eq!(sequences.last(), Some(&(MAX_FILL_LOG_SIZE + 2)));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::common::{
+    AccountLimits, AccountLimitsOverride, AttributePolicy, BlockInfo, FeePolicy, FeeTier,
+    OrderLimits, PriceBand, SelfTradePrevention,
+};
+use crate::contract_info::ContractInfoV3;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const NAMESPACE_PENDING_CHANGE: &str = "pending_change";
+pub const NAMESPACE_CONFIG_HISTORY: &str = "config_history";
+const NAMESPACE_CHANGE_SEQUENCE: &str = "change_sequence";
+
+const CHANGE_SEQUENCE: Item<u64> = Item::new(NAMESPACE_CHANGE_SEQUENCE);
+
+/// Contract configuration changes waiting for their effective time, keyed by change id.
+pub const PENDING_CHANGES_V1: Map<u64, PendingChangeV1> = Map::new(NAMESPACE_PENDING_CHANGE);
+
+/// Applied contract configuration changes, keyed by change id.
+pub const CONFIG_HISTORY_V1: Map<u64, ConfigChangeV1> = Map::new(NAMESPACE_CONFIG_HISTORY);
+
+/// A `ModifyContract` payload, fields that aren't set are left unchanged.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct ContractChange {
+    pub approvers: Option<Vec<String>>,
+    pub executors: Option<Vec<String>>,
+    pub ask_fee_rate: Option<String>,
+    pub ask_fee_account: Option<String>,
+    pub bid_fee_rate: Option<String>,
+    pub bid_fee_account: Option<String>,
+    pub ask_fee_policy: Option<FeePolicy>,
+    pub bid_fee_policy: Option<FeePolicy>,
+    pub maker_fee_rate: Option<String>,
+    pub taker_fee_rate: Option<String>,
+    pub maker_taker_fee_account: Option<String>,
+    pub fee_tiers: Option<Vec<FeeTier>>,
+    pub add_supported_quote_denoms: Option<Vec<String>>,
+    pub remove_supported_quote_denoms: Option<Vec<String>>,
+    pub add_convertible_base_denoms: Option<Vec<String>>,
+    pub remove_convertible_base_denoms: Option<Vec<String>>,
+    pub price_precision: Option<Uint128>,
+    pub size_increment: Option<Uint128>,
+    pub self_trade_prevention: Option<SelfTradePrevention>,
+    pub price_band: Option<PriceBand>,
+    pub order_limits: Option<Vec<OrderLimits>>,
+    pub account_limits: Option<AccountLimits>,
+    pub account_limits_overrides: Option<Vec<AccountLimitsOverride>>,
+    pub ask_attribute_policy: Option<AttributePolicy>,
+    pub bid_attribute_policy: Option<AttributePolicy>,
+}
+
+/// A proposed configuration change, applied by `ApplyPendingChanges` once the block time reaches
+/// `effective_at`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingChangeV1 {
+    pub id: u64,
+    pub change: ContractChange,
+    pub proposed_by: Addr,
+    pub proposed_at: BlockInfo,
+    pub effective_at: Timestamp,
+}
+
+/// An applied configuration change with the contract info before and after it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigChangeV1 {
+    pub id: u64,
+    pub change: ContractChange,
+    pub applied_at: BlockInfo,
+    pub old: ContractInfoV3,
+    pub new: ContractInfoV3,
+}
+
+/// Returns the next configuration change id, shared by pending and immediately applied changes.
+pub fn next_change_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let id = CHANGE_SEQUENCE.may_load(storage)?.unwrap_or_default() + 1;
+    CHANGE_SEQUENCE.save(storage, &id)?;
+    Ok(id)
+}
+
+/// Returns the pending changes whose effective time has been reached, in proposal order.
+pub fn get_due_changes(storage: &dyn Storage, time: Timestamp) -> StdResult<Vec<PendingChangeV1>> {
+    PENDING_CHANGES_V1
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, pending_change)) => pending_change.effective_at <= time,
+            Err(_) => true,
+        })
+        .map(|item| item.map(|(_, pending_change)| pending_change))
+        .collect()
+}
+
+/// Returns applied configuration changes, newest first, starting before the change id.
+pub fn get_config_history(
+    storage: &dyn Storage,
+    start_before: Option<u64>,
+    limit: usize,
+) -> StdResult<Vec<ConfigChangeV1>> {
+    CONFIG_HISTORY_V1
+        .range(
+            storage,
+            None,
+            start_before.map(Bound::exclusive),
+            Order::Descending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, config_change)| config_change))
+        .collect()
+}
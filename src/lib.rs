@@ -1,3 +1,4 @@
+pub mod account_limits;
 pub mod ask_order;
 pub mod bid_order;
 pub mod common;
@@ -5,7 +6,13 @@ pub mod contract;
 pub mod contract_info;
 pub mod error;
 pub mod execute;
+pub mod fee_ledger;
+pub mod fill_log;
+pub mod governance;
 pub mod msg;
+pub mod price_band;
+pub mod query;
 pub mod tests;
 pub mod util;
 pub mod version_info;
+pub mod volume;
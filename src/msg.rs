@@ -1,6 +1,15 @@
+use crate::ask_order::AskOrderV1;
+use crate::bid_order::BidOrderV3;
+use crate::common::{
+    AccountLimits, AccountLimitsOverride, AttributePolicy, ContractStatus, FeePolicy, FeeTier,
+    OrderLimits, OrderType, PriceBand, SelfTradePrevention, TimeInForce,
+};
 use crate::error::ContractError;
+use crate::fee_ledger::AccruedFee;
+use crate::fill_log::FillV1;
+use crate::governance::{ConfigChangeV1, PendingChangeV1};
 use crate::util::is_hyphenated_uuid_str;
-use cosmwasm_std::{Coin, Uint128};
+use cosmwasm_std::{Coin, Timestamp, Uint128};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -13,12 +22,41 @@ pub struct InstantiateMsg {
     pub supported_quote_denoms: Vec<String>,
     pub approvers: Vec<String>,
     pub executors: Vec<String>,
+    #[serde(default)]
+    pub admin: Option<String>,
+    #[serde(default)]
+    pub governance_delay: u64,
     pub ask_fee_rate: Option<String>,
     pub ask_fee_account: Option<String>,
     pub bid_fee_rate: Option<String>,
     pub bid_fee_account: Option<String>,
-    pub ask_required_attributes: Vec<String>,
-    pub bid_required_attributes: Vec<String>,
+    pub ask_fee_policy: Option<FeePolicy>,
+    pub bid_fee_policy: Option<FeePolicy>,
+    pub maker_fee_rate: Option<String>,
+    pub taker_fee_rate: Option<String>,
+    pub maker_taker_fee_account: Option<String>,
+    #[serde(default)]
+    pub fee_tiers: Vec<FeeTier>,
+    #[serde(default)]
+    pub self_trade_prevention: SelfTradePrevention,
+    #[serde(default)]
+    pub price_band: Option<PriceBand>,
+    #[serde(default)]
+    pub order_limits: Vec<OrderLimits>,
+    #[serde(default)]
+    pub account_limits: AccountLimits,
+    #[serde(default)]
+    pub account_limits_overrides: Vec<AccountLimitsOverride>,
+    #[serde(default)]
+    pub ask_attribute_policy: Option<AttributePolicy>,
+    #[serde(default)]
+    pub bid_attribute_policy: Option<AttributePolicy>,
+    /// Deprecated, set as an `all_of` policy of the attributes, combined with `ask_attribute_policy`
+    #[serde(default)]
+    pub ask_required_attributes: Option<Vec<String>>,
+    /// Deprecated, set as an `all_of` policy of the attributes, combined with `bid_attribute_policy`
+    #[serde(default)]
+    pub bid_required_attributes: Option<Vec<String>>,
     pub price_precision: Uint128,
     pub size_increment: Uint128,
 }
@@ -70,6 +108,12 @@ impl Validate for InstantiateMsg {
             (Some(_), Some(_)) => (),
             (None, None) => (),
         }
+        validate_maker_taker_fee(
+            &mut invalid_fields,
+            &self.maker_fee_rate,
+            &self.taker_fee_rate,
+            &self.maker_taker_fee_account,
+        );
         if self.price_precision.gt(&Uint128::new(18)) {
             invalid_fields.push("price_precision");
         }
@@ -86,6 +130,7 @@ impl Validate for InstantiateMsg {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -106,6 +151,9 @@ pub enum ExecuteMsg {
         quote: String,
         price: String,
         size: Uint128,
+        order_type: Option<OrderType>,
+        time_in_force: Option<TimeInForce>,
+        expires_at: Option<Timestamp>,
     },
     CreateBid {
         id: String,
@@ -115,7 +163,21 @@ pub enum ExecuteMsg {
         quote: String,
         quote_size: Uint128,
         size: Uint128,
+        order_type: Option<OrderType>,
+        time_in_force: Option<TimeInForce>,
+        expires_at: Option<Timestamp>,
+    },
+    CreateMarketBid {
+        id: String,
+        base: String,
+        fee: Option<Coin>,
+        quote: String,
+        quote_size: Uint128,
+        worst_price: String,
     },
+    /// Settles a match of an ask and bid. A match outside the price band is rejected without
+    /// failing, so the breach counts toward the circuit breaker, and returns a `reject_match`
+    /// action with a `price_band_breach` attribute instead of a fill.
     ExecuteMatch {
         ask_id: String,
         bid_id: String,
@@ -128,6 +190,24 @@ pub enum ExecuteMsg {
     ExpireBid {
         id: String,
     },
+    ExpireOrders {
+        limit: Option<u32>,
+    },
+    MatchOrders {
+        limit: Option<u32>,
+    },
+    ModifyAsk {
+        id: String,
+        price: String,
+        size: Uint128,
+    },
+    ModifyBid {
+        id: String,
+        price: String,
+        size: Uint128,
+        quote_size: Uint128,
+        fee: Option<Coin>,
+    },
     RejectAsk {
         id: String,
         size: Option<Uint128>,
@@ -136,6 +216,25 @@ pub enum ExecuteMsg {
         id: String,
         size: Option<Uint128>,
     },
+    Batch {
+        ops: Vec<BatchOp>,
+    },
+    WithdrawFees {
+        denom: String,
+    },
+    SetTradingStatus {
+        status: Option<ContractStatus>,
+        asks_paused: Option<bool>,
+        bids_paused: Option<bool>,
+    },
+    ProposeAdmin {
+        admin: String,
+    },
+    AcceptAdmin {},
+    CancelPendingChange {
+        id: u64,
+    },
+    ApplyPendingChanges {},
     ModifyContract {
         approvers: Option<Vec<String>>,
         executors: Option<Vec<String>>,
@@ -143,8 +242,29 @@ pub enum ExecuteMsg {
         ask_fee_account: Option<String>,
         bid_fee_rate: Option<String>,
         bid_fee_account: Option<String>,
+        ask_fee_policy: Option<FeePolicy>,
+        bid_fee_policy: Option<FeePolicy>,
+        maker_fee_rate: Option<String>,
+        taker_fee_rate: Option<String>,
+        maker_taker_fee_account: Option<String>,
+        fee_tiers: Option<Vec<FeeTier>>,
+        /// Deprecated, sets `ask_attribute_policy` to an `all_of` policy of the attributes
         ask_required_attributes: Option<Vec<String>>,
+        /// Deprecated, sets `bid_attribute_policy` to an `all_of` policy of the attributes
         bid_required_attributes: Option<Vec<String>>,
+        add_supported_quote_denoms: Option<Vec<String>>,
+        remove_supported_quote_denoms: Option<Vec<String>>,
+        add_convertible_base_denoms: Option<Vec<String>>,
+        remove_convertible_base_denoms: Option<Vec<String>>,
+        price_precision: Option<Uint128>,
+        size_increment: Option<Uint128>,
+        self_trade_prevention: Option<SelfTradePrevention>,
+        price_band: Option<PriceBand>,
+        order_limits: Option<Vec<OrderLimits>>,
+        account_limits: Option<AccountLimits>,
+        account_limits_overrides: Option<Vec<AccountLimitsOverride>>,
+        ask_attribute_policy: Option<AttributePolicy>,
+        bid_attribute_policy: Option<AttributePolicy>,
     },
 }
 
@@ -182,6 +302,9 @@ impl Validate for ExecuteMsg {
                 quote,
                 price,
                 size,
+                order_type: _,
+                time_in_force,
+                expires_at,
             } => {
                 if !is_hyphenated_uuid_str(id) {
                     invalid_fields.push("id");
@@ -198,6 +321,7 @@ impl Validate for ExecuteMsg {
                 if size.lt(&Uint128::new(1)) {
                     invalid_fields.push("size");
                 }
+                validate_time_in_force(&mut invalid_fields, time_in_force, expires_at);
             }
             ExecuteMsg::CreateBid {
                 id,
@@ -207,6 +331,9 @@ impl Validate for ExecuteMsg {
                 quote,
                 quote_size,
                 size,
+                order_type: _,
+                time_in_force,
+                expires_at,
             } => {
                 if !is_hyphenated_uuid_str(id) {
                     invalid_fields.push("id");
@@ -226,6 +353,31 @@ impl Validate for ExecuteMsg {
                 if size.lt(&Uint128::new(1)) {
                     invalid_fields.push("size");
                 }
+                validate_time_in_force(&mut invalid_fields, time_in_force, expires_at);
+            }
+            ExecuteMsg::CreateMarketBid {
+                id,
+                base,
+                fee: _,
+                quote,
+                quote_size,
+                worst_price,
+            } => {
+                if !is_hyphenated_uuid_str(id) {
+                    invalid_fields.push("id");
+                }
+                if base.is_empty() {
+                    invalid_fields.push("base");
+                }
+                if quote.is_empty() {
+                    invalid_fields.push("quote");
+                }
+                if quote_size.lt(&Uint128::new(1)) {
+                    invalid_fields.push("quote_size");
+                }
+                if worst_price.is_empty() {
+                    invalid_fields.push("worst_price");
+                }
             }
             ExecuteMsg::CancelAsk { id } => {
                 if Uuid::parse_str(id).is_err() {
@@ -266,6 +418,51 @@ impl Validate for ExecuteMsg {
                     invalid_fields.push("id");
                 }
             }
+            ExecuteMsg::ExpireOrders { limit } => {
+                if let Some(limit) = limit {
+                    if limit.lt(&1) {
+                        invalid_fields.push("limit");
+                    }
+                }
+            }
+            ExecuteMsg::MatchOrders { limit } => {
+                if let Some(limit) = limit {
+                    if limit.lt(&1) {
+                        invalid_fields.push("limit");
+                    }
+                }
+            }
+            ExecuteMsg::ModifyAsk { id, price, size } => {
+                if !is_hyphenated_uuid_str(id) {
+                    invalid_fields.push("id");
+                }
+                if price.is_empty() {
+                    invalid_fields.push("price");
+                }
+                if size.lt(&Uint128::new(1)) {
+                    invalid_fields.push("size");
+                }
+            }
+            ExecuteMsg::ModifyBid {
+                id,
+                price,
+                size,
+                quote_size,
+                fee: _,
+            } => {
+                if !is_hyphenated_uuid_str(id) {
+                    invalid_fields.push("id");
+                }
+                if price.is_empty() {
+                    invalid_fields.push("price");
+                }
+                if quote_size.lt(&Uint128::new(1)) {
+                    invalid_fields.push("quote_size");
+                }
+                if size.lt(&Uint128::new(1)) {
+                    invalid_fields.push("size");
+                }
+            }
             ExecuteMsg::RejectAsk { id, size } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
@@ -286,6 +483,33 @@ impl Validate for ExecuteMsg {
                     }
                 }
             }
+            ExecuteMsg::Batch { ops } => {
+                if ops.is_empty() {
+                    invalid_fields.push("ops");
+                }
+            }
+            ExecuteMsg::WithdrawFees { denom } => {
+                if denom.is_empty() {
+                    invalid_fields.push("denom");
+                }
+            }
+            ExecuteMsg::SetTradingStatus {
+                status,
+                asks_paused,
+                bids_paused,
+            } => {
+                if status.is_none() && asks_paused.is_none() && bids_paused.is_none() {
+                    invalid_fields.push("status");
+                }
+            }
+            ExecuteMsg::ProposeAdmin { admin } => {
+                if admin.is_empty() {
+                    invalid_fields.push("admin");
+                }
+            }
+            ExecuteMsg::AcceptAdmin {} => {}
+            ExecuteMsg::CancelPendingChange { .. } => {}
+            ExecuteMsg::ApplyPendingChanges {} => {}
             ExecuteMsg::ModifyContract {
                 approvers,
                 executors,
@@ -293,8 +517,27 @@ impl Validate for ExecuteMsg {
                 ask_fee_account,
                 bid_fee_rate,
                 bid_fee_account,
+                ask_fee_policy: _,
+                bid_fee_policy: _,
+                maker_fee_rate,
+                taker_fee_rate,
+                maker_taker_fee_account,
+                fee_tiers: _,
                 ask_required_attributes: _,
                 bid_required_attributes: _,
+                add_supported_quote_denoms,
+                remove_supported_quote_denoms,
+                add_convertible_base_denoms,
+                remove_convertible_base_denoms,
+                price_precision,
+                size_increment,
+                self_trade_prevention: _,
+                price_band: _,
+                order_limits: _,
+                account_limits: _,
+                account_limits_overrides: _,
+                ask_attribute_policy: _,
+                bid_attribute_policy: _,
             } => {
                 match approvers {
                     Some(vector) => {
@@ -332,6 +575,40 @@ impl Validate for ExecuteMsg {
                     (Some(_), Some(_)) => (),
                     (None, None) => (),
                 }
+                validate_maker_taker_fee(
+                    &mut invalid_fields,
+                    maker_fee_rate,
+                    taker_fee_rate,
+                    maker_taker_fee_account,
+                );
+                for (denoms, field) in [
+                    (add_supported_quote_denoms, "add_supported_quote_denoms"),
+                    (
+                        remove_supported_quote_denoms,
+                        "remove_supported_quote_denoms",
+                    ),
+                    (add_convertible_base_denoms, "add_convertible_base_denoms"),
+                    (
+                        remove_convertible_base_denoms,
+                        "remove_convertible_base_denoms",
+                    ),
+                ] {
+                    if let Some(denoms) = denoms {
+                        if denoms.is_empty() || denoms.iter().any(|denom| denom.is_empty()) {
+                            invalid_fields.push(field);
+                        }
+                    }
+                }
+                if let Some(price_precision) = price_precision {
+                    if price_precision.gt(&Uint128::new(18)) {
+                        invalid_fields.push("price_precision");
+                    }
+                }
+                if let Some(size_increment) = size_increment {
+                    if size_increment.lt(&Uint128::new(1)) {
+                        invalid_fields.push("size_increment");
+                    }
+                }
             }
         }
 
@@ -344,13 +621,113 @@ impl Validate for ExecuteMsg {
     }
 }
 
+/// An executor operation that can be run as part of an `ExecuteMsg::Batch`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchOp {
+    /// Settles a match of an ask and bid. A match outside the price band is rejected without
+    /// failing, so the breach counts toward the circuit breaker, and returns a `reject_match`
+    /// action with a `price_band_breach` attribute instead of a fill.
+    ExecuteMatch {
+        ask_id: String,
+        bid_id: String,
+        price: String,
+        size: Uint128,
+    },
+    ExpireAsk {
+        id: String,
+    },
+    ExpireBid {
+        id: String,
+    },
+    RejectAsk {
+        id: String,
+        size: Option<Uint128>,
+    },
+    RejectBid {
+        id: String,
+        size: Option<Uint128>,
+    },
+}
+
+impl From<BatchOp> for ExecuteMsg {
+    fn from(op: BatchOp) -> Self {
+        match op {
+            BatchOp::ExecuteMatch {
+                ask_id,
+                bid_id,
+                price,
+                size,
+            } => ExecuteMsg::ExecuteMatch {
+                ask_id,
+                bid_id,
+                price,
+                size,
+            },
+            BatchOp::ExpireAsk { id } => ExecuteMsg::ExpireAsk { id },
+            BatchOp::ExpireBid { id } => ExecuteMsg::ExpireBid { id },
+            BatchOp::RejectAsk { id, size } => ExecuteMsg::RejectAsk { id, size },
+            BatchOp::RejectBid { id, size } => ExecuteMsg::RejectBid { id, size },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
-    GetAsk { id: String },
-    GetBid { id: String },
+    GetAccountFeeTier {
+        address: String,
+    },
+    GetAccountLimits {
+        address: String,
+    },
+    GetAccruedFees {
+        account: Option<String>,
+    },
+    GetAsk {
+        id: String,
+    },
+    GetAsksByOwner {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    GetBid {
+        id: String,
+    },
+    GetBidsByOwner {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    GetConfigHistory {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
     GetContractInfo {},
+    GetOrderBookDepth {
+        quote: String,
+        levels: u32,
+    },
+    GetOrderFills {
+        id: String,
+    },
+    GetPendingChanges {},
+    GetPriceBand {
+        quote: String,
+    },
+    GetRecentTrades {
+        limit: Option<u32>,
+    },
     GetVersionInfo {},
+    ListAsks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    ListBids {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 impl Validate for QueryMsg {
@@ -369,18 +746,86 @@ impl Validate for QueryMsg {
         let mut invalid_fields: Vec<&str> = vec![];
 
         match self {
+            QueryMsg::GetAccountFeeTier { address } => {
+                if address.is_empty() {
+                    invalid_fields.push("address");
+                }
+            }
+            QueryMsg::GetAccountLimits { address } => {
+                if address.is_empty() {
+                    invalid_fields.push("address");
+                }
+            }
+            QueryMsg::GetAccruedFees { account } => {
+                if let Some(account) = account {
+                    if account.is_empty() {
+                        invalid_fields.push("account");
+                    }
+                }
+            }
             QueryMsg::GetAsk { id } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
                 }
             }
+            QueryMsg::GetAsksByOwner {
+                owner,
+                start_after,
+                limit,
+            } => {
+                if owner.is_empty() {
+                    invalid_fields.push("owner");
+                }
+                validate_list_params(&mut invalid_fields, start_after, limit);
+            }
             QueryMsg::GetBid { id } => {
                 if Uuid::parse_str(id).is_err() {
                     invalid_fields.push("id");
                 }
             }
+            QueryMsg::GetBidsByOwner {
+                owner,
+                start_after,
+                limit,
+            } => {
+                if owner.is_empty() {
+                    invalid_fields.push("owner");
+                }
+                validate_list_params(&mut invalid_fields, start_after, limit);
+            }
+            QueryMsg::GetConfigHistory { limit, .. } => {
+                validate_list_params(&mut invalid_fields, &None, limit);
+            }
             QueryMsg::GetContractInfo {} => {}
+            QueryMsg::GetOrderBookDepth { quote, levels } => {
+                if quote.is_empty() {
+                    invalid_fields.push("quote");
+                }
+                if *levels < 1 {
+                    invalid_fields.push("levels");
+                }
+            }
+            QueryMsg::GetOrderFills { id } => {
+                if Uuid::parse_str(id).is_err() {
+                    invalid_fields.push("id");
+                }
+            }
+            QueryMsg::GetPendingChanges {} => {}
+            QueryMsg::GetPriceBand { quote } => {
+                if quote.is_empty() {
+                    invalid_fields.push("quote");
+                }
+            }
+            QueryMsg::GetRecentTrades { limit } => {
+                validate_list_params(&mut invalid_fields, &None, limit);
+            }
             QueryMsg::GetVersionInfo {} => {}
+            QueryMsg::ListAsks { start_after, limit } => {
+                validate_list_params(&mut invalid_fields, start_after, limit);
+            }
+            QueryMsg::ListBids { start_after, limit } => {
+                validate_list_params(&mut invalid_fields, start_after, limit);
+            }
         }
 
         match invalid_fields.len() {
@@ -392,15 +837,140 @@ impl Validate for QueryMsg {
     }
 }
 
+fn validate_list_params(
+    invalid_fields: &mut Vec<&str>,
+    start_after: &Option<String>,
+    limit: &Option<u32>,
+) {
+    if let Some(start_after) = start_after {
+        if Uuid::parse_str(start_after).is_err() {
+            invalid_fields.push("start_after");
+        }
+    }
+    if let Some(limit) = limit {
+        if *limit < 1 {
+            invalid_fields.push("limit");
+        }
+    }
+}
+
+/// Good 'til date orders require an expiration, good 'til cancelled orders must not have one
+fn validate_time_in_force(
+    invalid_fields: &mut Vec<&str>,
+    time_in_force: &Option<TimeInForce>,
+    expires_at: &Option<Timestamp>,
+) {
+    match (time_in_force, expires_at) {
+        (Some(TimeInForce::GoodTilDate), None) | (Some(TimeInForce::GoodTilCancelled), Some(_)) => {
+            invalid_fields.push("expires_at");
+        }
+        _ => {}
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListAsksResponse {
+    pub asks: Vec<AskOrderV1>,
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListBidsResponse {
+    pub bids: Vec<BidOrderV3>,
+    pub next_start_after: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceLevel {
+    pub price: String,
+    pub size: Uint128,
+    pub order_count: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OrderBookDepthResponse {
+    pub quote: String,
+    pub asks: Vec<PriceLevel>,
+    pub bids: Vec<PriceLevel>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PriceBandResponse {
+    pub quote: String,
+    pub price_band: Option<PriceBand>,
+    /// Price of the last fill, the center of the band
+    pub reference_price: Option<String>,
+    pub lower_bound: Option<String>,
+    pub upper_bound: Option<String>,
+    /// Matches outside the band since the last fill
+    pub breach_count: u32,
+    pub halted_until: Option<Timestamp>,
+    pub is_halted: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingChangesResponse {
+    pub changes: Vec<PendingChangeV1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigHistoryResponse {
+    pub changes: Vec<ConfigChangeV1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FillsResponse {
+    pub fills: Vec<FillV1>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccruedFeesResponse {
+    pub fees: Vec<AccruedFee>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccountFeeTierResponse {
+    /// Quote volume traded by the account in the rolling volume window
+    pub volume: Uint128,
+    pub fee_tier: Option<FeeTier>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccountLimitsResponse {
+    /// The account's override or the default account limits
+    pub limits: AccountLimits,
+    /// Asks and bids the account has open
+    pub open_orders: u32,
+    /// What the account's open orders hold in escrow, per denom
+    pub escrow: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct MigrateMsg {
     pub approvers: Option<Vec<String>>,
+    pub admin: Option<String>,
+    pub governance_delay: Option<u64>,
+    pub self_trade_prevention: Option<SelfTradePrevention>,
+    pub price_band: Option<PriceBand>,
+    pub order_limits: Option<Vec<OrderLimits>>,
+    pub account_limits: Option<AccountLimits>,
+    pub account_limits_overrides: Option<Vec<AccountLimitsOverride>>,
+    pub ask_attribute_policy: Option<AttributePolicy>,
+    pub bid_attribute_policy: Option<AttributePolicy>,
     pub ask_fee_rate: Option<String>,
     pub ask_fee_account: Option<String>,
     pub bid_fee_rate: Option<String>,
     pub bid_fee_account: Option<String>,
+    pub ask_fee_policy: Option<FeePolicy>,
+    pub bid_fee_policy: Option<FeePolicy>,
+    pub maker_fee_rate: Option<String>,
+    pub taker_fee_rate: Option<String>,
+    pub maker_taker_fee_account: Option<String>,
+    pub fee_tiers: Option<Vec<FeeTier>>,
+    /// Deprecated, sets `ask_attribute_policy` to an `all_of` policy of the attributes
     pub ask_required_attributes: Option<Vec<String>>,
+    /// Deprecated, sets `bid_attribute_policy` to an `all_of` policy of the attributes
     pub bid_required_attributes: Option<Vec<String>>,
 }
 
@@ -440,6 +1010,13 @@ impl Validate for MigrateMsg {
             (None, None) => (),
         }
 
+        validate_maker_taker_fee(
+            &mut invalid_fields,
+            &self.maker_fee_rate,
+            &self.taker_fee_rate,
+            &self.maker_taker_fee_account,
+        );
+
         match invalid_fields.len() {
             0 => Ok(()),
             _ => Err(ContractError::InvalidFields {
@@ -452,3 +1029,24 @@ impl Validate for MigrateMsg {
 pub trait Validate {
     fn validate(&self) -> Result<(), ContractError>;
 }
+
+/// The maker/taker fee schedule is set as a whole, so either all or none of its fields are given
+fn validate_maker_taker_fee(
+    invalid_fields: &mut Vec<&str>,
+    maker_fee_rate: &Option<String>,
+    taker_fee_rate: &Option<String>,
+    maker_taker_fee_account: &Option<String>,
+) {
+    if maker_fee_rate.is_none() && taker_fee_rate.is_none() && maker_taker_fee_account.is_none() {
+        return;
+    }
+    if maker_fee_rate.is_none() {
+        invalid_fields.push("maker_fee_rate");
+    }
+    if taker_fee_rate.is_none() {
+        invalid_fields.push("taker_fee_rate");
+    }
+    if maker_taker_fee_account.is_none() {
+        invalid_fields.push("maker_taker_fee_account");
+    }
+}
@@ -0,0 +1,201 @@
+use crate::common::PriceBand;
+use crate::error::ContractError;
+use cosmwasm_std::{StdResult, Storage, Timestamp};
+use cw_storage_plus::Map;
+use rust_decimal::Decimal;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const NAMESPACE_PRICE_BAND: &str = "price_band";
+
+/// Reference price and circuit breaker state of each quote denom's market, keyed by quote denom.
+pub const PRICE_BANDS_V1: Map<&str, PriceBandStateV1> = Map::new(NAMESPACE_PRICE_BAND);
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub struct PriceBandStateV1 {
+    /// Price of the last fill, the center of the band
+    pub reference_price: Option<String>,
+    /// Matches skipped for being outside the band since the last fill
+    pub breach_count: u32,
+    /// Matching is halted until this time once too many matches breach the band
+    pub halted_until: Option<Timestamp>,
+}
+
+impl PriceBandStateV1 {
+    /// Returns whether matching is halted at the time
+    pub fn is_halted(&self, time: Timestamp) -> bool {
+        matches!(self.halted_until, Some(halted_until) if time.lt(&halted_until))
+    }
+
+    /// Returns the lowest and highest price of the band around the reference price, if both are
+    /// set.
+    pub fn get_bounds(
+        &self,
+        price_band: &Option<PriceBand>,
+    ) -> Result<Option<(Decimal, Decimal)>, ContractError> {
+        let (price_band, reference_price) = match (price_band, &self.reference_price) {
+            (Some(price_band), Some(reference_price)) => (price_band, reference_price),
+            (_, _) => return Ok(None),
+        };
+
+        let parse = |value: &str| {
+            Decimal::from_str(value).map_err(|_| ContractError::InvalidFields {
+                fields: vec![String::from("price_band")],
+            })
+        };
+        let reference_price = parse(reference_price)?;
+        let deviation = reference_price
+            .checked_mul(parse(&price_band.rate)?)
+            .ok_or(ContractError::TotalOverflow)?;
+
+        Ok(Some((
+            reference_price
+                .checked_sub(deviation)
+                .ok_or(ContractError::TotalOverflow)?
+                .max(Decimal::ZERO),
+            reference_price
+                .checked_add(deviation)
+                .ok_or(ContractError::TotalOverflow)?,
+        )))
+    }
+}
+
+/// Where a match price falls relative to its market's price band
+#[derive(Clone, Debug, PartialEq)]
+pub enum PriceBandCheck {
+    /// Within the band, or no band or reference price is set
+    Within,
+    /// Outside the band, the match is skipped and counted as a breach
+    Outside,
+    /// The market is halted by the circuit breaker, no match is allowed
+    Halted { halted_until: Timestamp },
+}
+
+pub fn get_price_band_state(storage: &dyn Storage, quote: &str) -> StdResult<PriceBandStateV1> {
+    Ok(PRICE_BANDS_V1.may_load(storage, quote)?.unwrap_or_default())
+}
+
+/// Checks a match price against the price band of the quote denom's market.
+pub fn check_price_band(
+    storage: &dyn Storage,
+    price_band: &Option<PriceBand>,
+    quote: &str,
+    price: Decimal,
+    time: Timestamp,
+) -> Result<PriceBandCheck, ContractError> {
+    let state = get_price_band_state(storage, quote)?;
+
+    if let Some(halted_until) = state.halted_until.filter(|_| state.is_halted(time)) {
+        return Ok(PriceBandCheck::Halted { halted_until });
+    }
+
+    match state.get_bounds(price_band)? {
+        Some((lower_bound, upper_bound)) if price.lt(&lower_bound) || price.gt(&upper_bound) => {
+            Ok(PriceBandCheck::Outside)
+        }
+        _ => Ok(PriceBandCheck::Within),
+    }
+}
+
+/// Counts a match skipped for being outside the band, halting the market once the band's breach
+/// limit is reached.
+///
+/// The reference price is cleared on a halt, the first fill after the halt sets a new one.
+pub fn record_price_band_breach(
+    storage: &mut dyn Storage,
+    price_band: &PriceBand,
+    quote: &str,
+    time: Timestamp,
+) -> Result<PriceBandStateV1, ContractError> {
+    let mut state = get_price_band_state(storage, quote)?;
+    state.breach_count += 1;
+
+    if state.breach_count >= price_band.max_breaches {
+        state.halted_until = Some(time.plus_seconds(price_band.halt_duration));
+        state.breach_count = 0;
+        state.reference_price = None;
+    }
+
+    PRICE_BANDS_V1.save(storage, quote, &state)?;
+
+    Ok(state)
+}
+
+/// Sets a fill price as the reference price of the quote denom's market.
+pub fn record_trade_price(
+    storage: &mut dyn Storage,
+    quote: &str,
+    price: Decimal,
+) -> Result<(), ContractError> {
+    let mut state = get_price_band_state(storage, quote)?;
+    state.reference_price = Some(price.to_string());
+    state.breach_count = 0;
+
+    PRICE_BANDS_V1.save(storage, quote, &state)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_price_band, record_price_band_breach, record_trade_price, PriceBandCheck};
+    use crate::common::PriceBand;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Storage;
+    use provwasm_mocks::mock_provenance_dependencies;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn price_band_halts_after_max_breaches() {
+        let mut deps = mock_provenance_dependencies();
+        let time = mock_env().block.time;
+        let price_band = PriceBand {
+            rate: "0.1".into(),
+            max_breaches: 2,
+            halt_duration: 600,
+        };
+        let check = |storage: &dyn Storage, price: &str, time| {
+            check_price_band(
+                storage,
+                &Some(price_band.to_owned()),
+                "quote_1",
+                Decimal::from_str(price).unwrap(),
+                time,
+            )
+            .unwrap()
+        };
+
+        // no reference price yet
+        assert_eq!(check(&deps.storage, "100", time), PriceBandCheck::Within);
+
+        record_trade_price(&mut deps.storage, "quote_1", Decimal::from(10)).unwrap();
+        assert_eq!(check(&deps.storage, "9", time), PriceBandCheck::Within);
+        assert_eq!(check(&deps.storage, "11", time), PriceBandCheck::Within);
+        assert_eq!(check(&deps.storage, "8.99", time), PriceBandCheck::Outside);
+        assert_eq!(check(&deps.storage, "11.01", time), PriceBandCheck::Outside);
+
+        let state =
+            record_price_band_breach(&mut deps.storage, &price_band, "quote_1", time).unwrap();
+        assert_eq!(state.breach_count, 1);
+        assert_eq!(state.halted_until, None);
+
+        let state =
+            record_price_band_breach(&mut deps.storage, &price_band, "quote_1", time).unwrap();
+        assert_eq!(state.breach_count, 0);
+        assert_eq!(state.halted_until, Some(time.plus_seconds(600)));
+        assert_eq!(state.reference_price, None);
+
+        assert_eq!(
+            check(&deps.storage, "10", time.plus_seconds(599)),
+            PriceBandCheck::Halted {
+                halted_until: time.plus_seconds(600)
+            }
+        );
+        assert_eq!(
+            check(&deps.storage, "100", time.plus_seconds(600)),
+            PriceBandCheck::Within
+        );
+    }
+}
@@ -0,0 +1,8 @@
+pub mod account_limits;
+pub mod accrued_fees;
+pub mod config_history;
+pub mod fee_tier;
+pub mod fills;
+pub mod list_orders;
+pub mod order_book;
+pub mod price_band;
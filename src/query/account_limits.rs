@@ -0,0 +1,16 @@
+use crate::account_limits::get_account_usage;
+use crate::contract_info::get_contract_info;
+use crate::msg::AccountLimitsResponse;
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+/// Returns an account's open order limits with its open orders and what they hold in escrow.
+pub fn get_account_limits(deps: Deps, address: Addr) -> StdResult<AccountLimitsResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    let usage = get_account_usage(deps.storage, &address)?;
+
+    Ok(AccountLimitsResponse {
+        limits: contract_info.get_account_limits(&address).to_owned(),
+        open_orders: usage.open_orders,
+        escrow: usage.escrow,
+    })
+}
@@ -0,0 +1,10 @@
+use crate::fee_ledger::get_accrued_fees as get_ledger_fees;
+use crate::msg::AccruedFeesResponse;
+use cosmwasm_std::{Addr, Deps, StdResult};
+
+/// Returns the fees accrued in the contract and not yet withdrawn, by fee account and denom.
+pub fn get_accrued_fees(deps: Deps, account: Option<Addr>) -> StdResult<AccruedFeesResponse> {
+    Ok(AccruedFeesResponse {
+        fees: get_ledger_fees(deps.storage, account.as_ref())?,
+    })
+}
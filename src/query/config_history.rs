@@ -0,0 +1,26 @@
+use crate::governance::{get_config_history as get_history, PENDING_CHANGES_V1};
+use crate::msg::{ConfigHistoryResponse, PendingChangesResponse};
+use crate::query::list_orders::get_list_limit;
+use cosmwasm_std::{Deps, Order, StdResult};
+
+/// Returns the configuration changes waiting for their effective time, in proposal order.
+pub fn get_pending_changes(deps: Deps) -> StdResult<PendingChangesResponse> {
+    let changes = PENDING_CHANGES_V1
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, pending_change)| pending_change))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingChangesResponse { changes })
+}
+
+/// Returns applied configuration changes with the contract info before and after each, newest
+/// first, starting before the provided change id.
+pub fn get_config_history(
+    deps: Deps,
+    start_before: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ConfigHistoryResponse> {
+    Ok(ConfigHistoryResponse {
+        changes: get_history(deps.storage, start_before, get_list_limit(limit))?,
+    })
+}
@@ -0,0 +1,19 @@
+use crate::contract_info::get_contract_info;
+use crate::msg::AccountFeeTierResponse;
+use crate::volume::get_rolling_volume;
+use cosmwasm_std::{Addr, Deps, StdResult, Timestamp};
+
+/// Returns an account's rolling traded quote volume and the fee tier it has reached.
+pub fn get_account_fee_tier(
+    deps: Deps,
+    address: Addr,
+    time: Timestamp,
+) -> StdResult<AccountFeeTierResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    let volume = get_rolling_volume(deps.storage, &address, time)?;
+
+    Ok(AccountFeeTierResponse {
+        volume,
+        fee_tier: contract_info.get_fee_tier(volume).cloned(),
+    })
+}
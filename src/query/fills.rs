@@ -0,0 +1,42 @@
+use crate::fill_log::FILLS_V1;
+use crate::msg::FillsResponse;
+use crate::query::list_orders::get_list_limit;
+use cosmwasm_std::{Deps, Order, StdResult};
+
+/// Returns the logged fills of an ask or bid order, oldest first.
+///
+/// Fills remain queryable after the order is completely filled and removed, until they are
+/// dropped from the capped fill log.
+pub fn get_order_fills(deps: Deps, id: String) -> StdResult<FillsResponse> {
+    let mut fills = FILLS_V1
+        .idx
+        .ask
+        .prefix(id.to_owned())
+        .range(deps.storage, None, None, Order::Ascending)
+        .chain(
+            FILLS_V1
+                .idx
+                .bid
+                .prefix(id)
+                .range(deps.storage, None, None, Order::Ascending),
+        )
+        .collect::<StdResult<Vec<_>>>()?;
+
+    fills.sort_by_key(|(key, _)| *key);
+    fills.dedup_by_key(|(key, _)| *key);
+
+    Ok(FillsResponse {
+        fills: fills.into_iter().map(|(_, fill)| fill).collect(),
+    })
+}
+
+/// Returns the most recent fills across all orders, newest first.
+pub fn get_recent_trades(deps: Deps, limit: Option<u32>) -> StdResult<FillsResponse> {
+    let fills = FILLS_V1
+        .range(deps.storage, None, None, Order::Descending)
+        .take(get_list_limit(limit))
+        .map(|item| item.map(|(_, fill)| fill))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(FillsResponse { fills })
+}
@@ -0,0 +1,133 @@
+use crate::ask_order::ASKS_V1;
+use crate::bid_order::BIDS_V3;
+use crate::msg::{ListAsksResponse, ListBidsResponse};
+use cosmwasm_std::{Addr, Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+
+pub const DEFAULT_LIST_LIMIT: u32 = 10;
+pub const MAX_LIST_LIMIT: u32 = 30;
+
+/// Returns the effective page size for a list query, clamped to `MAX_LIST_LIMIT`.
+pub fn get_list_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_LIST_LIMIT).min(MAX_LIST_LIMIT) as usize
+}
+
+/// Lists ask orders in ascending id order, starting after the provided order id.
+pub fn list_asks(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListAsksResponse> {
+    let limit = get_list_limit(limit);
+    let start = start_after
+        .as_ref()
+        .map(|id| Bound::exclusive(id.as_bytes()));
+
+    let asks = ASKS_V1
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, ask_order)| ask_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let next_start_after = match asks.len() {
+        len if len == limit => asks.last().map(|ask_order| ask_order.id.to_owned()),
+        _ => None,
+    };
+
+    Ok(ListAsksResponse {
+        asks,
+        next_start_after,
+    })
+}
+
+/// Lists bid orders in ascending id order, starting after the provided order id.
+pub fn list_bids(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListBidsResponse> {
+    let limit = get_list_limit(limit);
+    let start = start_after
+        .as_ref()
+        .map(|id| Bound::exclusive(id.as_bytes()));
+
+    let bids = BIDS_V3
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, bid_order)| bid_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let next_start_after = match bids.len() {
+        len if len == limit => bids.last().map(|bid_order| bid_order.id.to_owned()),
+        _ => None,
+    };
+
+    Ok(ListBidsResponse {
+        bids,
+        next_start_after,
+    })
+}
+
+/// Lists the ask orders owned by an address in ascending id order, starting after the provided order id.
+pub fn list_asks_by_owner(
+    deps: Deps,
+    owner: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListAsksResponse> {
+    let limit = get_list_limit(limit);
+    let start = start_after
+        .as_ref()
+        .map(|id| Bound::exclusive(id.as_bytes()));
+
+    let asks = ASKS_V1
+        .idx
+        .owner
+        .prefix(owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, ask_order)| ask_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let next_start_after = match asks.len() {
+        len if len == limit => asks.last().map(|ask_order| ask_order.id.to_owned()),
+        _ => None,
+    };
+
+    Ok(ListAsksResponse {
+        asks,
+        next_start_after,
+    })
+}
+
+/// Lists the bid orders owned by an address in ascending id order, starting after the provided order id.
+pub fn list_bids_by_owner(
+    deps: Deps,
+    owner: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListBidsResponse> {
+    let limit = get_list_limit(limit);
+    let start = start_after
+        .as_ref()
+        .map(|id| Bound::exclusive(id.as_bytes()));
+
+    let bids = BIDS_V3
+        .idx
+        .owner
+        .prefix(owner)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(_, bid_order)| bid_order))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let next_start_after = match bids.len() {
+        len if len == limit => bids.last().map(|bid_order| bid_order.id.to_owned()),
+        _ => None,
+    };
+
+    Ok(ListBidsResponse {
+        bids,
+        next_start_after,
+    })
+}
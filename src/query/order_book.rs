@@ -0,0 +1,83 @@
+use crate::ask_order::ASKS_V1;
+use crate::bid_order::BIDS_V3;
+use crate::msg::{OrderBookDepthResponse, PriceLevel};
+use cosmwasm_std::{Deps, Order, StdError, StdResult, Uint128};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+pub const MAX_DEPTH_LEVELS: u32 = 50;
+
+/// Returns the aggregated open size per price level on each side of the book for a quote denom.
+///
+/// Asks are listed from the lowest price and bids from the highest price. Convertible asks that
+/// are still pending issuer approval cannot be matched and are left out.
+pub fn get_order_book_depth(
+    deps: Deps,
+    quote: String,
+    levels: u32,
+) -> StdResult<OrderBookDepthResponse> {
+    let levels = levels.min(MAX_DEPTH_LEVELS) as usize;
+
+    let asks = ASKS_V1
+        .idx
+        .price
+        .sub_prefix(quote.to_owned())
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, ask_order)| ask_order.is_ready())
+        })
+        .map(|item| item.map(|(_, ask_order)| (ask_order.price, ask_order.size)));
+
+    let bids = BIDS_V3
+        .idx
+        .price
+        .sub_prefix(quote.to_owned())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            item.map(|(_, bid_order)| (bid_order.price.to_owned(), bid_order.get_remaining_base()))
+        });
+
+    Ok(OrderBookDepthResponse {
+        asks: aggregate_price_levels(asks, levels)?,
+        bids: aggregate_price_levels(bids, levels)?,
+        quote,
+    })
+}
+
+/// Sums consecutive orders of equal price into price levels, up to `max_levels` levels.
+fn aggregate_price_levels(
+    orders: impl Iterator<Item = StdResult<(String, Uint128)>>,
+    max_levels: usize,
+) -> StdResult<Vec<PriceLevel>> {
+    let mut price_levels: Vec<PriceLevel> = vec![];
+    let mut level_price: Option<Decimal> = None;
+
+    for order in orders {
+        let (price, size) = order?;
+        let price = Decimal::from_str(&price).map_err(|error| StdError::ParseErr {
+            target_type: "Decimal".into(),
+            msg: error.to_string(),
+        })?;
+
+        match price_levels.last_mut() {
+            Some(price_level) if level_price.eq(&Some(price)) => {
+                price_level.size = price_level.size.checked_add(size)?;
+                price_level.order_count += 1;
+            }
+            _ => {
+                if price_levels.len() == max_levels {
+                    break;
+                }
+                level_price = Some(price);
+                price_levels.push(PriceLevel {
+                    price: price.normalize().to_string(),
+                    size,
+                    order_count: 1,
+                });
+            }
+        }
+    }
+
+    Ok(price_levels)
+}
@@ -0,0 +1,23 @@
+use crate::contract_info::get_contract_info;
+use crate::msg::PriceBandResponse;
+use crate::price_band::get_price_band_state;
+use cosmwasm_std::{Deps, StdResult, Timestamp};
+
+/// Returns the price band of a quote denom's market with its reference price, bounds and circuit
+/// breaker state.
+pub fn get_price_band(deps: Deps, quote: String, time: Timestamp) -> StdResult<PriceBandResponse> {
+    let contract_info = get_contract_info(deps.storage)?;
+    let state = get_price_band_state(deps.storage, &quote)?;
+    let bounds = state.get_bounds(&contract_info.price_band)?;
+
+    Ok(PriceBandResponse {
+        quote,
+        price_band: contract_info.price_band,
+        reference_price: state.reference_price.to_owned(),
+        lower_bound: bounds.map(|(lower_bound, _)| lower_bound.to_string()),
+        upper_bound: bounds.map(|(_, upper_bound)| upper_bound.to_string()),
+        breach_count: state.breach_count,
+        halted_until: state.halted_until,
+        is_halted: state.is_halted(time),
+    })
+}
@@ -1,11 +1,30 @@
+mod account_limits_tests;
+mod admin_tests;
 mod approve_ask_tests;
+mod attribute_policy_tests;
+mod batch_tests;
 mod cancel_ask_tests;
 mod cancel_bid_tests;
 mod create_ask_tests;
 mod create_bid_tests;
+mod create_market_bid_tests;
 mod execute_match_tests;
 mod execute_modify_tests;
 mod expire_ask_tests;
 mod expire_bid_tests;
+mod expire_orders_tests;
+mod fee_policy_tests;
+mod fee_tier_tests;
+mod maker_taker_fee_tests;
+mod match_orders_tests;
+mod modify_denoms_tests;
+mod modify_order_tests;
+mod modify_precision_tests;
+mod order_limits_tests;
+mod pending_changes_tests;
+mod price_band_tests;
 mod reject_ask_tests;
 mod reject_bid_tests;
+mod self_trade_prevention_tests;
+mod set_trading_status_tests;
+mod withdraw_fees_tests;
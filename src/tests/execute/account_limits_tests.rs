@@ -0,0 +1,307 @@
+#[cfg(test)]
+mod account_limits_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::bid_order::BidOrderV3;
+    use crate::common::{AccountLimits, AccountLimitsOverride};
+    use crate::contract::{execute, query};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::msg::{AccountLimitsResponse, ExecuteMsg, QueryMsg};
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        set_default_required_attributes, setup_test_base_contract_v3, store_test_ask,
+        store_test_bid,
+    };
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, coins, from_binary, Addr, Coin, DepsMut, Response, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const BID_ID_2: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+    const BID_ID_3: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.account_limits = AccountLimits {
+            max_open_orders: Some(2),
+            max_escrow: vec![coin(1000, "quote_1")],
+        };
+        contract_info.account_limits_overrides = vec![AccountLimitsOverride {
+            address: "market_maker".into(),
+            limits: AccountLimits::default(),
+        }];
+        set_contract_info(storage, &contract_info).unwrap();
+
+        // the bidder has a bid escrowing 600 quote_1 open
+        store_test_bid(
+            storage,
+            &BidOrderV3 {
+                base: Coin {
+                    amount: Uint128::new(300),
+                    denom: BASE_DENOM.into(),
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee: None,
+                id: BID_ID_1.into(),
+                owner: Addr::unchecked("bidder"),
+                price: "2".into(),
+                quote: Coin {
+                    amount: Uint128::new(600),
+                    denom: "quote_1".into(),
+                },
+                ..Default::default()
+            },
+        );
+    }
+
+    fn create_bid(
+        deps: DepsMut,
+        bidder: &str,
+        id: &str,
+        quote: &str,
+        size: u128,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            mock_env(),
+            mock_info(bidder, &coins(size * 2, quote)),
+            ExecuteMsg::CreateBid {
+                id: id.into(),
+                base: BASE_DENOM.into(),
+                fee: None,
+                price: "2".into(),
+                quote: quote.into(),
+                quote_size: Uint128::new(size * 2),
+                size: Uint128::new(size),
+                order_type: None,
+                time_in_force: None,
+                expires_at: None,
+            },
+        )
+    }
+
+    fn assert_limit_exceeded(result: Result<Response, ContractError>, expected_limit: &str) {
+        match result {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::AccountLimitExceeded { limit }) => {
+                assert_eq!(limit, expected_limit)
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn create_bid_over_account_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // 600 + 600 quote_1 in escrow
+        assert_limit_exceeded(
+            create_bid(deps.as_mut(), "bidder", BID_ID_2, "quote_1", 300),
+            "max_escrow",
+        );
+
+        // 600 + 400 quote_1 in escrow
+        if let Err(error) = create_bid(deps.as_mut(), "bidder", BID_ID_2, "quote_1", 200) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // a third open order
+        assert_limit_exceeded(
+            create_bid(deps.as_mut(), "bidder", BID_ID_3, "quote_2", 100),
+            "max_open_orders",
+        );
+
+        let response: AccountLimitsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetAccountLimits {
+                    address: "bidder".into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            AccountLimitsResponse {
+                limits: AccountLimits {
+                    max_open_orders: Some(2),
+                    max_escrow: vec![coin(1000, "quote_1")],
+                },
+                open_orders: 2,
+                escrow: vec![coin(1000, "quote_1")],
+            }
+        );
+    }
+
+    #[test]
+    fn modify_bid_over_account_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // the bidder is at their open order limit
+        if let Err(error) = create_bid(deps.as_mut(), "bidder", BID_ID_2, "quote_2", 100) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let modify_bid = |size: u128, funds: u128| {
+            (
+                mock_info("bidder", &coins(funds, "quote_1")),
+                ExecuteMsg::ModifyBid {
+                    id: BID_ID_1.into(),
+                    price: "2".into(),
+                    size: Uint128::new(size),
+                    quote_size: Uint128::new(size * 2),
+                    fee: None,
+                },
+            )
+        };
+
+        // 600 + 600 quote_1 in escrow
+        let (info, msg) = modify_bid(600, 600);
+        assert_limit_exceeded(execute(deps.as_mut(), mock_env(), info, msg), "max_escrow");
+
+        // 600 + 400 quote_1 in escrow, amending doesn't open another order
+        let (info, msg) = modify_bid(500, 400);
+        if let Err(error) = execute(deps.as_mut(), mock_env(), info, msg) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn modify_ask_over_account_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.account_limits.max_escrow = vec![coin(500, BASE_DENOM)];
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: BASE_DENOM.into(),
+                class: AskOrderClass::Basic,
+                id: ASK_ID.into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(300),
+                ..Default::default()
+            },
+        );
+
+        let modify_ask = |size: u128| ExecuteMsg::ModifyAsk {
+            id: ASK_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(size),
+        };
+
+        // 300 + 300 base in escrow
+        assert_limit_exceeded(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(300, BASE_DENOM)),
+                modify_ask(600),
+            ),
+            "max_escrow",
+        );
+
+        // 300 + 200 base in escrow
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(200, BASE_DENOM)),
+            modify_ask(500),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn create_bid_with_account_limits_override() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "market_maker", false, true);
+
+        for id in [BID_ID_2, BID_ID_3] {
+            if let Err(error) = create_bid(deps.as_mut(), "market_maker", id, "quote_1", 1000) {
+                panic!("unexpected error: {:?}", error)
+            }
+        }
+    }
+
+    #[test]
+    fn modify_contract_invalid_account_limits_overrides_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        let account_limits_override = AccountLimitsOverride {
+            address: "market_maker".into(),
+            limits: AccountLimits::default(),
+        };
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ModifyContract {
+                approvers: None,
+                executors: None,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_fee_policy: None,
+                bid_fee_policy: None,
+                maker_fee_rate: None,
+                taker_fee_rate: None,
+                maker_taker_fee_account: None,
+                fee_tiers: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                add_supported_quote_denoms: None,
+                remove_supported_quote_denoms: None,
+                add_convertible_base_denoms: None,
+                remove_convertible_base_denoms: None,
+                price_precision: None,
+                size_increment: None,
+                self_trade_prevention: None,
+                price_band: None,
+                order_limits: None,
+                account_limits: None,
+                account_limits_overrides: Some(vec![
+                    account_limits_override.to_owned(),
+                    account_limits_override,
+                ]),
+                ask_attribute_policy: None,
+                bid_attribute_policy: None,
+            },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["account_limits_overrides"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod admin_tests {
+    use crate::contract::execute;
+    use crate::contract_info::get_contract_info;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn propose_admin_msg(admin: &str) -> ExecuteMsg {
+        ExecuteMsg::ProposeAdmin {
+            admin: admin.into(),
+        }
+    }
+
+    fn modify_contract_msg() -> ExecuteMsg {
+        ExecuteMsg::ModifyContract {
+            approvers: None,
+            executors: Some(vec!["exec_3".into()]),
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+        }
+    }
+
+    #[test]
+    fn admin_handover_takes_effect_when_accepted() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_version_info(
+            &mut deps.storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            propose_admin_msg("new_admin"),
+        ) {
+            Ok(response) => assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "propose_admin"),
+                    attr("pending_admin", "new_admin"),
+                ]
+            ),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the current admin keeps the role until the proposal is accepted
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(contract_info.admin, Some(Addr::unchecked("contract_admin")));
+        assert_eq!(
+            contract_info.pending_admin,
+            Some(Addr::unchecked("new_admin"))
+        );
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_admin", &[]),
+            ExecuteMsg::AcceptAdmin {},
+        ) {
+            Ok(response) => assert_eq!(
+                response.attributes,
+                vec![attr("action", "accept_admin"), attr("admin", "new_admin")]
+            ),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(contract_info.admin, Some(Addr::unchecked("new_admin")));
+        assert_eq!(contract_info.pending_admin, None);
+
+        // the previous admin can no longer modify the contract
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_contract_msg(),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_admin", &[]),
+            modify_contract_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn propose_admin_not_admin_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            propose_admin_msg("exec_1"),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn accept_admin_not_pending_admin_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        // nothing has been proposed
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("new_admin", &[]),
+            ExecuteMsg::AcceptAdmin {},
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            propose_admin_msg("new_admin"),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::AcceptAdmin {},
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_contract_executor_not_admin_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            modify_contract_msg(),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -9,7 +9,7 @@ mod approve_ask_tests {
         APPROVER_1, BASE_DENOM, HYPHENATED_ASK_ID, UNHYPHENATED_ASK_ID,
     };
     use crate::tests::test_setup_utils::{
-        setup_test_base, setup_test_base_contract_v3, store_test_ask,
+        all_of_attributes, setup_test_base, setup_test_base_contract_v3, store_test_ask,
     };
     use crate::tests::test_utils::setup_restricted_asset_marker;
     use crate::tests::test_utils::validate_execute_invalid_id_field;
@@ -55,12 +55,14 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -77,6 +79,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -145,6 +148,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -169,12 +173,14 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -193,6 +199,7 @@ mod approve_ask_tests {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            ..Default::default()
         };
         store_test_ask(&mut deps.storage, &existing_ask_order);
 
@@ -247,12 +254,12 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -278,6 +285,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -360,6 +368,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -384,12 +393,14 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -406,6 +417,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -449,6 +461,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -473,12 +486,14 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -495,6 +510,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -536,6 +552,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -560,12 +577,14 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -582,6 +601,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -623,6 +643,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -647,12 +668,12 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -669,6 +690,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -710,6 +732,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -734,12 +757,12 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -765,6 +788,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -804,12 +828,12 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -835,6 +859,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -876,6 +901,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -900,12 +926,12 @@ mod approve_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -922,6 +948,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -963,6 +990,7 @@ mod approve_ask_tests {
                         price: "2".into(),
                         quote: "quote_1".into(),
                         size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -988,6 +1016,7 @@ mod approve_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
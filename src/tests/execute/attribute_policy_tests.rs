@@ -0,0 +1,326 @@
+#[cfg(test)]
+mod attribute_policy_tests {
+    use crate::bid_order::BidOrderV3;
+    use crate::common::AttributePolicy;
+    use crate::contract::execute;
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::{BASE_DENOM, HYPHENATED_BID_ID};
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_bid};
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coins, Addr, Coin, Response, Storage, Uint128};
+    use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    fn attribute(name: &str, values: Option<Vec<&str>>) -> AttributePolicy {
+        AttributePolicy::Attribute {
+            name: name.into(),
+            values: values.map(|values| values.into_iter().map(String::from).collect()),
+        }
+    }
+
+    fn test_attribute_policy() -> AttributePolicy {
+        AttributePolicy::AllOf(vec![
+            attribute("kyc", None),
+            AttributePolicy::AnyOf(vec![
+                attribute("jurisdiction", Some(vec!["US", "CA"])),
+                attribute("accredited", None),
+            ]),
+            AttributePolicy::NoneOf(vec![attribute("sanctioned", None)]),
+        ])
+    }
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.bid_attribute_policy = Some(test_attribute_policy());
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn set_attributes(querier: &mut MockProvenanceQuerier, attributes: Vec<(&str, &str)>) {
+        QueryAttributesRequest::mock_response(
+            querier,
+            QueryAttributesResponse {
+                account: "bidder".to_string(),
+                attributes: attributes
+                    .into_iter()
+                    .map(|(name, value)| Attribute {
+                        name: name.to_string(),
+                        value: value.as_bytes().to_vec(),
+                        attribute_type: AttributeType::String.into(),
+                        address: "".to_string(),
+                    })
+                    .collect(),
+                pagination: None,
+            },
+        );
+    }
+
+    fn create_bid_msg() -> ExecuteMsg {
+        ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(200),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
+        }
+    }
+
+    fn modify_attribute_policy_msg(bid_attribute_policy: AttributePolicy) -> ExecuteMsg {
+        ExecuteMsg::ModifyContract {
+            approvers: None,
+            executors: None,
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(bid_attribute_policy),
+        }
+    }
+
+    fn assert_policy_failed(result: Result<Response, ContractError>, expected_clause: &str) {
+        match result {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::AttributePolicyFailed { clause }) => {
+                assert_eq!(clause, expected_clause)
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn create_bid_satisfying_attribute_policy() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_attributes(
+            &mut deps.querier,
+            vec![("kyc", "passed"), ("jurisdiction", "CA")],
+        );
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            create_bid_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn create_bid_failing_attribute_policy_names_clause() {
+        for (attributes, expected_clause) in [
+            (vec![("jurisdiction", "US")], "all_of[0].attribute(kyc)"),
+            (
+                vec![("kyc", "passed"), ("jurisdiction", "DE")],
+                "all_of[1].any_of",
+            ),
+            (
+                vec![("kyc", "passed"), ("accredited", "yes"), ("sanctioned", "")],
+                "all_of[2].none_of[0].attribute(sanctioned)",
+            ),
+        ] {
+            let mut deps = mock_provenance_dependencies();
+            setup_contract(&mut deps.storage);
+            set_attributes(&mut deps.querier, attributes);
+
+            assert_policy_failed(
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info("bidder", &coins(200, "quote_1")),
+                    create_bid_msg(),
+                ),
+                expected_clause,
+            );
+        }
+    }
+
+    #[test]
+    fn modify_contract_attribute_policy() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        // an empty group can't be satisfied
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_attribute_policy_msg(AttributePolicy::AllOf(vec![AttributePolicy::AnyOf(
+                vec![],
+            )])),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["bid_attribute_policy"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // an empty all_of removes the policy
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_attribute_policy_msg(AttributePolicy::AllOf(vec![])),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        assert_eq!(
+            get_contract_info(&deps.storage)
+                .unwrap()
+                .bid_attribute_policy,
+            None
+        );
+
+        // the policy can't change while bids are open
+        store_test_bid(
+            &mut deps.storage,
+            &BidOrderV3 {
+                base: Coin {
+                    amount: Uint128::new(100),
+                    denom: BASE_DENOM.into(),
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee: None,
+                id: HYPHENATED_BID_ID.into(),
+                owner: Addr::unchecked("bidder"),
+                price: "2".into(),
+                quote: Coin {
+                    amount: Uint128::new(200),
+                    denom: "quote_1".into(),
+                },
+                ..Default::default()
+            },
+        );
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_attribute_policy_msg(test_attribute_policy()),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["bid_attribute_policy"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_contract_required_attributes_sets_all_of_policy() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        // the deprecated list is combined with the policy sent alongside it
+        let mut msg = modify_attribute_policy_msg(attribute("kyc", None));
+        if let ExecuteMsg::ModifyContract {
+            bid_required_attributes,
+            ..
+        } = &mut msg
+        {
+            *bid_required_attributes = Some(vec!["accredited".into()]);
+        }
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            msg,
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        assert_eq!(
+            get_contract_info(&deps.storage)
+                .unwrap()
+                .bid_attribute_policy,
+            Some(AttributePolicy::AllOf(vec![
+                attribute("accredited", None),
+                attribute("kyc", None),
+            ]))
+        );
+
+        // an empty list on its own removes the policy
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::ModifyContract {
+                approvers: None,
+                executors: None,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_fee_policy: None,
+                bid_fee_policy: None,
+                maker_fee_rate: None,
+                taker_fee_rate: None,
+                maker_taker_fee_account: None,
+                fee_tiers: None,
+                ask_required_attributes: None,
+                bid_required_attributes: Some(vec![]),
+                add_supported_quote_denoms: None,
+                remove_supported_quote_denoms: None,
+                add_convertible_base_denoms: None,
+                remove_convertible_base_denoms: None,
+                price_precision: None,
+                size_increment: None,
+                self_trade_prevention: None,
+                price_band: None,
+                order_limits: None,
+                account_limits: None,
+                account_limits_overrides: None,
+                ask_attribute_policy: None,
+                bid_attribute_policy: None,
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        assert_eq!(
+            get_contract_info(&deps.storage)
+                .unwrap()
+                .bid_attribute_policy,
+            None
+        );
+    }
+}
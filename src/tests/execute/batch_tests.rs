@@ -0,0 +1,251 @@
+#[cfg(test)]
+mod batch_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::contract::execute;
+    use crate::error::ContractError;
+    use crate::msg::{BatchOp, ExecuteMsg};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const BID_ID_2: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+
+    fn test_ask(id: &str) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(id: &str, size: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(size * 2),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn match_op(ask_id: &str, bid_id: &str) -> BatchOp {
+        BatchOp::ExecuteMatch {
+            ask_id: ask_id.into(),
+            bid_id: bid_id.into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn batch_nets_transfers_to_same_recipient_and_denom() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, 200));
+
+        let batch_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::Batch {
+                ops: vec![match_op(ASK_ID_1, BID_ID_1), match_op(ASK_ID_2, BID_ID_1)],
+            },
+        );
+
+        match batch_response {
+            Ok(batch_response) => {
+                assert_eq!(
+                    batch_response
+                        .attributes
+                        .iter()
+                        .filter(|attribute| attribute.eq(&&attr("action", "execute")))
+                        .count(),
+                    2
+                );
+                assert!(batch_response.attributes.contains(&attr("action", "batch")));
+                assert!(batch_response.attributes.contains(&attr("op_count", "2")));
+                assert_eq!(
+                    batch_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(400, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(200, "base_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        assert!(BIDS_V3.is_empty(&deps.storage));
+    }
+
+    #[test]
+    fn batch_combines_matches_and_cancellations() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, 100));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_2, 100));
+
+        let batch_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::Batch {
+                ops: vec![
+                    match_op(ASK_ID_1, BID_ID_1),
+                    BatchOp::ExpireAsk {
+                        id: ASK_ID_2.into(),
+                    },
+                    BatchOp::RejectBid {
+                        id: BID_ID_2.into(),
+                        size: None,
+                    },
+                ],
+            },
+        );
+
+        match batch_response {
+            Ok(batch_response) => {
+                assert!(batch_response
+                    .attributes
+                    .contains(&attr("action", "expire_ask")));
+                assert!(batch_response
+                    .attributes
+                    .contains(&attr("action", "reject_bid")));
+                assert_eq!(
+                    batch_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(200, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(200, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn batch_with_failing_op_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, 100));
+
+        let batch_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::Batch {
+                ops: vec![match_op(ASK_ID_1, BID_ID_1), match_op(ASK_ID_2, BID_ID_1)],
+            },
+        );
+
+        match batch_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::LoadOrderFailed { .. }) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn batch_by_non_executor_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let batch_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            ExecuteMsg::Batch {
+                ops: vec![BatchOp::ExpireAsk {
+                    id: ASK_ID_1.into(),
+                }],
+            },
+        );
+
+        match batch_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn batch_without_ops_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let batch_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::Batch { ops: vec![] },
+        );
+
+        match batch_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert!(fields.contains(&"ops".into()))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -33,6 +33,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -121,6 +122,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -191,6 +193,7 @@ mod cancel_ask_tests {
                 quote: "quote_1".into(),
                 price: "2".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -263,6 +266,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -357,6 +361,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -491,6 +496,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -554,6 +560,7 @@ mod cancel_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -575,6 +582,7 @@ mod cancel_ask_tests {
                     amount: Uint128::new(20),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -2,14 +2,15 @@
 mod cancel_bid_tests {
     use crate::ask_order::{AskOrderClass, AskOrderV1};
     use crate::bid_order::{BidOrderV3, BIDS_V3};
-    use crate::common::FeeInfo;
+    use crate::common::{FeeInfo, FeePolicy};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
     use crate::msg::ExecuteMsg;
     use crate::tests::test_constants::{HYPHENATED_BID_ID, UNHYPHENATED_BID_ID};
     use crate::tests::test_setup_utils::{
-        setup_test_base, setup_test_base_contract_v3, store_test_ask, store_test_bid,
+        all_of_attributes, setup_test_base, setup_test_base_contract_v3, store_test_ask,
+        store_test_bid,
     };
     use crate::tests::test_utils::setup_restricted_asset_marker;
     use crate::util::transfer_marker_coins;
@@ -43,6 +44,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -121,6 +123,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -188,12 +191,14 @@ mod cancel_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -225,6 +230,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -297,15 +303,18 @@ mod cancel_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -331,6 +340,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -408,15 +418,18 @@ mod cancel_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -442,6 +455,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -512,15 +526,18 @@ mod cancel_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -555,6 +572,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -701,6 +719,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(100),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -759,12 +778,14 @@ mod cancel_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(10),
+                ..Default::default()
             },
         );
 
@@ -789,6 +810,7 @@ mod cancel_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -802,6 +824,7 @@ mod cancel_bid_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(10),
+                ..Default::default()
             },
         );
 
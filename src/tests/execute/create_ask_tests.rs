@@ -1,17 +1,21 @@
 #[cfg(test)]
 mod create_ask_tests {
     use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1, ASKS_V1};
+    use crate::common::{FeeTerms, OrderType, TimeInForce};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
     use crate::msg::ExecuteMsg;
-    use crate::tests::test_constants::UNHYPHENATED_ASK_ID;
-    use crate::tests::test_setup_utils::{setup_test_base, setup_test_base_contract_v3};
+    use crate::tests::test_constants::{BASE_DENOM, HYPHENATED_ASK_ID, UNHYPHENATED_ASK_ID};
+    use crate::tests::test_setup_utils::{
+        all_of_attributes, set_default_required_attributes, setup_test_base,
+        setup_test_base_contract_v3,
+    };
     use crate::tests::test_utils::setup_restricted_asset_marker;
     use crate::tests::test_utils::validate_execute_invalid_id_field;
     use crate::util::transfer_marker_coins;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{attr, coin, coins, Addr, Uint128};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, CosmosMsg, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::types::provenance::attribute::v1::{
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
@@ -31,6 +35,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             base: "base_1".to_string(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(200, "base_1"));
@@ -60,12 +67,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -98,6 +107,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             base: "base_1".to_string(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(200, "base_1"));
@@ -144,6 +156,7 @@ mod create_ask_tests {
             quote,
             price,
             size,
+            ..
         } = create_ask_msg
         {
             match ASKS_V1.load(
@@ -160,7 +173,13 @@ mod create_ask_tests {
                             owner: Addr::unchecked("asker"),
                             price,
                             quote,
-                            size
+                            size,
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -186,12 +205,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -224,6 +245,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2".into(),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(500, "con_base_1"));
@@ -273,6 +297,7 @@ mod create_ask_tests {
             quote,
             price,
             size,
+            ..
         } = create_ask_msg
         {
             match ASKS_V1.load(
@@ -292,6 +317,12 @@ mod create_ask_tests {
                             price,
                             quote,
                             size,
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -317,12 +348,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -342,6 +373,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2".into(),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &[]);
@@ -403,6 +437,7 @@ mod create_ask_tests {
             quote,
             price,
             size,
+            ..
         } = create_ask_msg
         {
             match ASKS_V1.load(
@@ -420,6 +455,12 @@ mod create_ask_tests {
                             price,
                             quote,
                             size,
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -445,12 +486,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -470,6 +511,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2".into(),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &[coin(10, "base_1")]);
@@ -500,12 +544,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -516,6 +560,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2.5".into(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(200, "base_1"));
@@ -538,6 +585,9 @@ mod create_ask_tests {
             quote: "quote_2".into(),
             price: "4.5".into(),
             size: Uint128::new(400),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(400, "base_1"));
@@ -571,7 +621,13 @@ mod create_ask_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2.5".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(200)
+                        size: Uint128::new(200),
+                        sequence: 1,
+                        fee_terms: Some(FeeTerms {
+                            fee_info: None,
+                            maker_taker_fee_info: None,
+                        }),
+                        ..Default::default()
                     }
                 )
             }
@@ -594,12 +650,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -610,6 +668,9 @@ mod create_ask_tests {
             quote: "".into(),
             price: "".into(),
             size: Uint128::new(0),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -649,12 +710,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -665,6 +728,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -698,12 +764,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -714,6 +782,9 @@ mod create_ask_tests {
             quote: "unsupported".into(),
             price: "2".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -747,12 +818,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -763,6 +834,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             base: "base_1".to_string(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -798,12 +872,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -814,6 +888,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             base: "base_1".to_string(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -849,12 +926,12 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -865,6 +942,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2.123".into(),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -900,12 +980,14 @@ mod create_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -930,6 +1012,9 @@ mod create_ask_tests {
             quote: "quote_1".into(),
             price: "2".into(),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let asker_info = mock_info("asker", &coins(200, "base_denom"));
@@ -941,9 +1026,160 @@ mod create_ask_tests {
         match create_ask_response {
             Ok(_) => panic!("expected error, but ok"),
             Err(error) => match error {
-                ContractError::Unauthorized => {}
+                ContractError::AttributePolicyFailed { clause } => {
+                    assert_eq!(clause, "all_of[1].attribute(ask_tag_2)")
+                }
                 error => panic!("unexpected error: {:?}", error),
             },
         }
     }
+
+    #[test]
+    fn create_ask_good_til_date_stores_expiration() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "asker", true, false);
+
+        let expires_at = mock_env().block.time.plus_seconds(60);
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: HYPHENATED_ASK_ID.into(),
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            base: BASE_DENOM.into(),
+            size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: Some(expires_at),
+        };
+
+        let asker_info = mock_info("asker", &coins(200, BASE_DENOM));
+
+        // execute create ask
+        let create_ask_response = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg);
+
+        if let Err(error) = create_ask_response {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify ask order stored as good 'til date
+        match ASKS_V1.load(&deps.storage, HYPHENATED_ASK_ID.as_bytes()) {
+            Ok(stored_order) => {
+                assert_eq!(stored_order.time_in_force, TimeInForce::GoodTilDate);
+                assert_eq!(stored_order.expires_at, Some(expires_at));
+            }
+            _ => {
+                panic!("ask order was not found in storage")
+            }
+        }
+    }
+
+    #[test]
+    fn create_ask_already_expired_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "asker", true, false);
+
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: HYPHENATED_ASK_ID.into(),
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            base: BASE_DENOM.into(),
+            size: Uint128::new(200),
+            order_type: None,
+            time_in_force: Some(TimeInForce::GoodTilDate),
+            expires_at: Some(mock_env().block.time),
+        };
+
+        let asker_info = mock_info("asker", &coins(200, BASE_DENOM));
+
+        // execute create ask
+        let create_ask_response = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg);
+
+        // verify create ask response
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert!(fields.contains(&"expires_at".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, HYPHENATED_ASK_ID.as_bytes()));
+    }
+
+    #[test]
+    fn create_ask_good_til_date_without_expiration_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: HYPHENATED_ASK_ID.into(),
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            base: BASE_DENOM.into(),
+            size: Uint128::new(200),
+            order_type: None,
+            time_in_force: Some(TimeInForce::GoodTilDate),
+            expires_at: None,
+        };
+
+        let asker_info = mock_info("asker", &coins(200, BASE_DENOM));
+
+        // execute create ask
+        let create_ask_response = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg);
+
+        // verify create ask response
+        match create_ask_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert!(fields.contains(&"expires_at".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn create_ask_immediate_or_cancel_without_crossing_bid_refunds_ask() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "asker", true, false);
+
+        let create_ask_msg = ExecuteMsg::CreateAsk {
+            id: HYPHENATED_ASK_ID.into(),
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            base: BASE_DENOM.into(),
+            size: Uint128::new(200),
+            order_type: Some(OrderType::ImmediateOrCancel),
+            time_in_force: None,
+            expires_at: None,
+        };
+
+        let asker_info = mock_info("asker", &coins(200, BASE_DENOM));
+
+        // execute create ask
+        let create_ask_response = execute(deps.as_mut(), mock_env(), asker_info, create_ask_msg);
+
+        // verify the unfilled ask was refunded
+        match create_ask_response {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("action", "cancel_ask")));
+                assert!(response.attributes.contains(&attr("reverse_size", "200")));
+                assert_eq!(
+                    response.messages.last().unwrap().msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(200, BASE_DENOM),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, HYPHENATED_ASK_ID.as_bytes()));
+    }
 }
@@ -1,22 +1,24 @@
 #[cfg(test)]
 mod create_bid_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
     use crate::bid_order::{BidOrderV3, BIDS_V3};
-    use crate::common::FeeInfo;
+    use crate::common::{FeeInfo, FeePolicy, FeeTerms, OrderType, TimeInForce};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
     use crate::msg::ExecuteMsg;
     use crate::tests::test_constants::{
-        BASE_DENOM, HYPHENATED_BID_ID, QUOTE_DENOM_1, UNHYPHENATED_BID_ID,
+        BASE_DENOM, HYPHENATED_ASK_ID, HYPHENATED_BID_ID, QUOTE_DENOM_1, UNHYPHENATED_BID_ID,
     };
     use crate::tests::test_setup_utils::{
-        set_default_required_attributes, setup_test_base, setup_test_base_contract_v3,
+        all_of_attributes, set_default_required_attributes, setup_test_base,
+        setup_test_base_contract_v3, store_test_ask,
     };
     use crate::tests::test_utils::setup_restricted_asset_marker;
     use crate::tests::test_utils::validate_execute_invalid_id_field;
     use crate::util::transfer_marker_coins;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{attr, coin, coins, Addr, Coin, Uint128};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
     use provwasm_mocks::mock_provenance_dependencies;
     use provwasm_std::types::provenance::attribute::v1::{
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
@@ -38,6 +40,9 @@ mod create_bid_tests {
             quote: QUOTE_DENOM_1.into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         // Add bid required attributes
         set_default_required_attributes(&mut deps.querier, "bidder", false, true);
@@ -74,6 +79,9 @@ mod create_bid_tests {
             quote: QUOTE_DENOM_1.into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         // Add bid required attributes
         set_default_required_attributes(&mut deps.querier, "bidder", false, true);
@@ -121,6 +129,7 @@ mod create_bid_tests {
             quote_size,
             price,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -146,6 +155,12 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -172,6 +187,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
@@ -201,12 +219,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -241,6 +261,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
@@ -283,6 +306,7 @@ mod create_bid_tests {
             quote_size,
             price,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -308,6 +332,12 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -333,12 +363,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -360,6 +390,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(1000),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &[]);
@@ -417,6 +450,7 @@ mod create_bid_tests {
             quote,
             quote_size,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -442,6 +476,12 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: None,
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -467,15 +507,18 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -513,6 +556,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(275, "quote_1"));
@@ -567,6 +613,7 @@ mod create_bid_tests {
             quote_size,
             price,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -592,6 +639,16 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: Some(FeeInfo {
+                                    account: Addr::unchecked("bid_fee_account"),
+                                    rate: "0.1".into(),
+                                    policy: FeePolicy::default(),
+                                }),
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -617,15 +674,18 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -663,6 +723,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(149),
             size: Uint128::new(149),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(150, "quote_1"));
@@ -717,6 +780,7 @@ mod create_bid_tests {
             quote_size,
             price,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -742,6 +806,16 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: Some(FeeInfo {
+                                    account: Addr::unchecked("bid_fee_account"),
+                                    rate: "0.01".into(),
+                                    policy: FeePolicy::default(),
+                                }),
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -767,15 +841,18 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -813,6 +890,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(150),
             size: Uint128::new(150),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(152, "quote_1"));
@@ -867,6 +947,7 @@ mod create_bid_tests {
             quote_size,
             price,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -892,6 +973,16 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: Some(FeeInfo {
+                                    account: Addr::unchecked("bid_fee_account"),
+                                    rate: "0.01".into(),
+                                    policy: FeePolicy::default(),
+                                }),
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -917,15 +1008,16 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_acct"),
                     rate: "0.1".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -950,6 +1042,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(1000),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &[]);
@@ -1019,6 +1114,7 @@ mod create_bid_tests {
             quote,
             quote_size,
             size,
+            ..
         } = create_bid_msg
         {
             match BIDS_V3.load(
@@ -1044,6 +1140,16 @@ mod create_bid_tests {
                                 amount: quote_size,
                                 denom: quote,
                             },
+                            sequence: 1,
+                            fee_terms: Some(FeeTerms {
+                                fee_info: Some(FeeInfo {
+                                    account: Addr::unchecked("bid_fee_acct"),
+                                    rate: "0.1".into(),
+                                    policy: FeePolicy::default(),
+                                }),
+                                maker_taker_fee_info: None,
+                            }),
+                            ..Default::default()
                         }
                     )
                 }
@@ -1069,12 +1175,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1096,6 +1202,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(10),
             size: Uint128::new(500),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &[coin(10, "quote_2")]);
@@ -1126,12 +1235,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1166,6 +1277,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
@@ -1190,6 +1304,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(900),
             size: Uint128::new(200),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         let bidder_info = mock_info("bidder", &coins(900, "quote_1"));
@@ -1232,6 +1349,12 @@ mod create_bid_tests {
                             amount: Uint128::new(250),
                             denom: "quote_1".into(),
                         },
+                        sequence: 1,
+                        fee_terms: Some(FeeTerms {
+                            fee_info: None,
+                            maker_taker_fee_info: None,
+                        }),
+                        ..Default::default()
                     }
                 )
             }
@@ -1254,12 +1377,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1272,6 +1397,9 @@ mod create_bid_tests {
             quote: "".into(),
             quote_size: Uint128::new(0),
             size: Uint128::new(0),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1310,12 +1438,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1328,6 +1458,9 @@ mod create_bid_tests {
             quote: "quote_2".into(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create ask
@@ -1361,12 +1494,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1379,6 +1514,9 @@ mod create_bid_tests {
             quote: "unsupported".into(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1412,12 +1550,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1430,6 +1568,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(100),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1463,12 +1604,14 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1495,6 +1638,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1509,7 +1655,9 @@ mod create_bid_tests {
         match create_bid_response {
             Ok(_) => panic!("expected error, but ok"),
             Err(error) => match error {
-                ContractError::Unauthorized => {}
+                ContractError::AttributePolicyFailed { clause } => {
+                    assert_eq!(clause, "all_of[1].attribute(bid_tag_2)")
+                }
                 error => panic!("unexpected error: {:?}", error),
             },
         }
@@ -1528,12 +1676,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1546,6 +1694,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1581,12 +1732,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1599,6 +1750,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(250),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1634,12 +1788,12 @@ mod create_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1652,6 +1806,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1697,6 +1854,9 @@ mod create_bid_tests {
             quote: "quote_1".into(),
             quote_size: Uint128::new(200), // Valid amount would be 300
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
 
         // execute create bid
@@ -1716,4 +1876,283 @@ mod create_bid_tests {
             },
         }
     }
+
+    #[test]
+    fn create_bid_good_til_date_stores_expiration() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        let expires_at = mock_env().block.time.plus_seconds(60);
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(250),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: Some(TimeInForce::GoodTilDate),
+            expires_at: Some(expires_at),
+        };
+
+        let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        if let Err(error) = create_bid_response {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // verify bid order stored as good 'til date
+        match BIDS_V3.load(&deps.storage, HYPHENATED_BID_ID.as_bytes()) {
+            Ok(stored_order) => {
+                assert_eq!(stored_order.time_in_force, TimeInForce::GoodTilDate);
+                assert_eq!(stored_order.expires_at, Some(expires_at));
+            }
+            _ => {
+                panic!("bid order was not found in storage")
+            }
+        }
+    }
+
+    #[test]
+    fn create_bid_already_expired_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(250),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: Some(mock_env().block.time.minus_seconds(1)),
+        };
+
+        let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        // verify execute create bid response
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert!(fields.contains(&"expires_at".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        assert!(!BIDS_V3.has(&deps.storage, HYPHENATED_BID_ID.as_bytes()));
+    }
+
+    #[test]
+    fn create_bid_good_til_cancelled_with_expiration_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(250),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: Some(TimeInForce::GoodTilCancelled),
+            expires_at: Some(mock_env().block.time.plus_seconds(60)),
+        };
+
+        let bidder_info = mock_info("bidder", &coins(250, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        // verify execute create bid response
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert!(fields.contains(&"expires_at".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn create_bid_immediate_or_cancel_fills_and_refunds_remainder() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // store resting ask order
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: BASE_DENOM.into(),
+                class: AskOrderClass::Basic,
+                id: HYPHENATED_ASK_ID.into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(100),
+                ..Default::default()
+            },
+        );
+
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2.5".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(500),
+            size: Uint128::new(200),
+            order_type: Some(OrderType::ImmediateOrCancel),
+            time_in_force: None,
+            expires_at: None,
+        };
+
+        let bidder_info = mock_info("bidder", &coins(500, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        // verify the bid filled against the ask at the ask price, and the rest was refunded
+        match create_bid_response {
+            Ok(response) => {
+                assert_eq!(response.attributes[0], attr("action", "create_bid"));
+                assert!(response.attributes.contains(&attr("action", "execute")));
+                assert!(response.attributes.contains(&attr("price", "2")));
+                assert!(response.attributes.contains(&attr("action", "cancel_bid")));
+                assert!(response.attributes.contains(&attr("reverse_size", "100")));
+                assert_eq!(
+                    response.messages.last().unwrap().msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(250, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, HYPHENATED_ASK_ID.as_bytes()));
+        assert!(!BIDS_V3.has(&deps.storage, HYPHENATED_BID_ID.as_bytes()));
+    }
+
+    #[test]
+    fn create_bid_fill_or_kill_fills_completely() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // store resting ask order
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: BASE_DENOM.into(),
+                class: AskOrderClass::Basic,
+                id: HYPHENATED_ASK_ID.into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(300),
+                ..Default::default()
+            },
+        );
+
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(400),
+            size: Uint128::new(200),
+            order_type: Some(OrderType::FillOrKill),
+            time_in_force: None,
+            expires_at: None,
+        };
+
+        let bidder_info = mock_info("bidder", &coins(400, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        match create_bid_response {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("action", "execute")));
+                assert!(!response.attributes.contains(&attr("action", "cancel_bid")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // verify the bid was filled completely and the ask remains partially filled
+        assert!(!BIDS_V3.has(&deps.storage, HYPHENATED_BID_ID.as_bytes()));
+        match ASKS_V1.load(&deps.storage, HYPHENATED_ASK_ID.as_bytes()) {
+            Ok(stored_order) => assert_eq!(stored_order.size, Uint128::new(100)),
+            _ => panic!("ask order was not found in storage"),
+        }
+    }
+
+    #[test]
+    fn create_bid_fill_or_kill_not_filled_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // store resting ask order too small to fill the bid
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: BASE_DENOM.into(),
+                class: AskOrderClass::Basic,
+                id: HYPHENATED_ASK_ID.into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(100),
+                ..Default::default()
+            },
+        );
+
+        let create_bid_msg = ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(400),
+            size: Uint128::new(200),
+            order_type: Some(OrderType::FillOrKill),
+            time_in_force: None,
+            expires_at: None,
+        };
+
+        let bidder_info = mock_info("bidder", &coins(400, "quote_1"));
+
+        // execute create bid
+        let create_bid_response = execute(deps.as_mut(), mock_env(), bidder_info, create_bid_msg);
+
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::FillOrKillNotFilled => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
 }
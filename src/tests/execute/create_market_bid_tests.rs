@@ -0,0 +1,335 @@
+#[cfg(test)]
+mod create_market_bid_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::BIDS_V3;
+    use crate::common::{FeeInfo, FeePolicy};
+    use crate::contract::execute;
+    use crate::contract_info::ContractInfoV3;
+    use crate::error::ContractError;
+    use crate::fee_ledger::ACCRUED_FEES_V1;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::{BASE_DENOM, HYPHENATED_BID_ID};
+    use crate::tests::test_setup_utils::{
+        set_default_required_attributes, setup_test_base, setup_test_base_contract_v3,
+        store_test_ask,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, CosmosMsg, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const ASK_ID_3: &str = "6f6a0e5e-3c8e-4a70-9f0d-3a1f1ab0e2d4";
+
+    fn test_ask(id: &str, price: &str, size: u128, sequence: u64) -> AskOrderV1 {
+        AskOrderV1 {
+            base: BASE_DENOM.into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(size),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    fn market_bid_msg(quote_size: u128, fee: Option<u128>, worst_price: &str) -> ExecuteMsg {
+        ExecuteMsg::CreateMarketBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: fee.map(|fee| coin(fee, "quote_1")),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(quote_size),
+            worst_price: worst_price.into(),
+        }
+    }
+
+    fn setup_test_base_with_bid_fee(storage: &mut dyn Storage) {
+        setup_test_base(
+            storage,
+            &ContractInfoV3 {
+                name: "contract_name".into(),
+                bind_name: "contract_bind_name".into(),
+                base_denom: BASE_DENOM.into(),
+                convertible_base_denoms: vec![],
+                supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
+                approvers: vec![],
+                executors: vec![Addr::unchecked("exec_1")],
+                admin: Some(Addr::unchecked("contract_admin")),
+                ask_fee_info: None,
+                bid_fee_info: Some(FeeInfo {
+                    account: Addr::unchecked("bid_fee_account"),
+                    rate: "0.01".into(),
+                    policy: FeePolicy::default(),
+                }),
+                price_precision: Uint128::new(2),
+                size_increment: Uint128::new(100),
+                ..Default::default()
+            },
+        );
+    }
+
+    #[test]
+    fn create_market_bid_fills_asks_in_price_order_and_refunds_quote() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "3", 200, 1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_3, "5", 100, 3));
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1000, "quote_1")),
+            market_bid_msg(1000, None, "4"),
+        );
+
+        match create_response {
+            Ok(response) => {
+                assert_eq!(response.attributes[0], attr("action", "create_market_bid"));
+                assert!(response.attributes.contains(&attr("worst_price", "4")));
+                assert!(response.attributes.contains(&attr("filled_size", "300")));
+                assert!(response.attributes.contains(&attr("quote_refund", "200")));
+                assert!(response.attributes.contains(&attr("fee_refund", "0")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(200, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, BASE_DENOM),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(600, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(200, BASE_DENOM),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(200, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // filled asks are removed, the ask above the worst price remains, the bid never rests
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_3.as_bytes()));
+        assert!(BIDS_V3.is_empty(&deps.storage));
+    }
+
+    #[test]
+    fn create_market_bid_partially_fills_ask_with_affordable_size() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 500, 1));
+
+        // 500 quote buys 250 base at 2, rounded down to the size increment of 100
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(500, "quote_1")),
+            market_bid_msg(500, None, "2"),
+        );
+
+        match create_response {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("size", "200")));
+                assert!(response.attributes.contains(&attr("quote_refund", "100")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ASKS_V1
+                .load(&deps.storage, ASK_ID_1.as_bytes())
+                .unwrap()
+                .size,
+            Uint128::new(300)
+        );
+    }
+
+    #[test]
+    fn create_market_bid_refunds_pro_rated_fee() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_with_bid_fee(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 200, 1));
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1010, "quote_1")),
+            market_bid_msg(1000, Some(10), "2"),
+        );
+
+        // 400 of 1000 quote spent, so 4 of the 10 fee is charged and the rest refunded
+        match create_response {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("bid_fee", "4")));
+                assert!(response.attributes.contains(&attr("quote_refund", "600")));
+                assert!(response.attributes.contains(&attr("fee_refund", "6")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(400, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(200, BASE_DENOM),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(600, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(6, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the charged fee is accrued in the contract
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(
+                    &deps.storage,
+                    (&Addr::unchecked("bid_fee_account"), "quote_1")
+                )
+                .unwrap(),
+            Uint128::new(4)
+        );
+    }
+
+    #[test]
+    fn create_market_bid_above_worst_price_refunds_everything() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_with_bid_fee(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "3", 100, 1));
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1010, "quote_1")),
+            market_bid_msg(1000, Some(10), "2.99"),
+        );
+
+        match create_response {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("filled_size", "0")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(1000, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(10, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn create_market_bid_invalid_worst_price_precision_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1000, "quote_1")),
+            market_bid_msg(1000, None, "2.001"),
+        );
+
+        match create_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert!(fields.contains(&"worst_price".into()))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn create_market_bid_sent_funds_mismatch_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(999, "quote_1")),
+            market_bid_msg(1000, None, "2"),
+        );
+
+        match create_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::SentFundsOrderMismatch) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn create_market_bid_missing_worst_price_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let create_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1000, "quote_1")),
+            market_bid_msg(1000, None, ""),
+        );
+
+        match create_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert!(fields.contains(&"worst_price".into()))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
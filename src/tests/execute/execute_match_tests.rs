@@ -2,14 +2,16 @@
 mod execute_match_tests {
     use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1, ASKS_V1};
     use crate::bid_order::{BidOrderV3, BIDS_V3};
-    use crate::common::FeeInfo;
+    use crate::common::{FeeInfo, FeePolicy, TimeInForce};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
+    use crate::fee_ledger::ACCRUED_FEES_V1;
     use crate::msg::ExecuteMsg;
     use crate::tests::test_constants::{UNHYPHENATED_ASK_ID, UNHYPHENATED_BID_ID};
     use crate::tests::test_setup_utils::{
-        setup_test_base, setup_test_base_contract_v3, store_test_ask, store_test_bid,
+        all_of_attributes, setup_test_base, setup_test_base_contract_v3, store_test_ask,
+        store_test_bid,
     };
     use crate::tests::test_utils::setup_restricted_asset_marker;
     use crate::util::transfer_marker_coins;
@@ -39,12 +41,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: ask_fee,
                 bid_fee_info: bid_fee,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(precision as u128),
                 size_increment: Uint128::new(size_increment as u128),
+                ..Default::default()
             },
         );
     }
@@ -64,12 +68,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -84,6 +90,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -106,6 +113,7 @@ mod execute_match_tests {
                     denom: "quote_2".into(), // not equal to "quote_1"
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -203,6 +211,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -225,6 +234,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -309,6 +319,7 @@ mod execute_match_tests {
             Some(FeeInfo {
                 account: Addr::unchecked("ask_fee_account"),
                 rate: "0.01".into(),
+                policy: FeePolicy::default(),
             }),
             None,
             0,
@@ -326,6 +337,7 @@ mod execute_match_tests {
                 price: "1".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(149),
+                ..Default::default()
             },
         );
 
@@ -348,6 +360,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -387,23 +400,25 @@ mod execute_match_tests {
                 assert_eq!(execute_response.attributes[7], attr("ask_fee", "1"));
                 assert_eq!(execute_response.attributes[8], attr("bid_fee", "0"));
 
-                assert_eq!(execute_response.messages.len(), 3);
+                assert_eq!(execute_response.messages.len(), 2);
                 assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: "ask_fee_account".into(),
-                        amount: coins(1, "quote_1"),
-                    })
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("ask_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(1)
                 );
                 assert_eq!(
-                    execute_response.messages[1].msg,
+                    execute_response.messages[0].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "asker".into(),
                         amount: coins(148, "quote_1"),
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[2].msg,
+                    execute_response.messages[1].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: coins(149, "base_1"),
@@ -439,6 +454,7 @@ mod execute_match_tests {
             Some(FeeInfo {
                 account: Addr::unchecked("ask_fee_account"),
                 rate: "0.01".into(),
+                policy: FeePolicy::default(),
             }),
             None,
             0,
@@ -456,6 +472,7 @@ mod execute_match_tests {
                 price: "1".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(150),
+                ..Default::default()
             },
         );
 
@@ -478,6 +495,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -517,23 +535,25 @@ mod execute_match_tests {
                 assert_eq!(execute_response.attributes[7], attr("ask_fee", "2"));
                 assert_eq!(execute_response.attributes[8], attr("bid_fee", "0"));
 
-                assert_eq!(execute_response.messages.len(), 3);
+                assert_eq!(execute_response.messages.len(), 2);
                 assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: "ask_fee_account".into(),
-                        amount: coins(2, "quote_1"),
-                    })
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("ask_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(2)
                 );
                 assert_eq!(
-                    execute_response.messages[1].msg,
+                    execute_response.messages[0].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "asker".into(),
                         amount: coins(148, "quote_1"),
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[2].msg,
+                    execute_response.messages[1].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: coins(150, "base_1"),
@@ -570,6 +590,7 @@ mod execute_match_tests {
             Some(FeeInfo {
                 account: Addr::unchecked("bid_fee_account"),
                 rate: "0.01".into(),
+                policy: FeePolicy::default(),
             }),
             0,
             1,
@@ -586,6 +607,7 @@ mod execute_match_tests {
                 price: "1".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(149),
+                ..Default::default()
             },
         );
 
@@ -611,6 +633,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -650,23 +673,25 @@ mod execute_match_tests {
                 assert_eq!(execute_response.attributes[7], attr("ask_fee", "0"));
                 assert_eq!(execute_response.attributes[8], attr("bid_fee", "1"));
 
-                assert_eq!(execute_response.messages.len(), 3);
+                assert_eq!(execute_response.messages.len(), 2);
                 assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: "bid_fee_account".into(),
-                        amount: coins(1, "quote_1"),
-                    })
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("bid_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(1)
                 );
                 assert_eq!(
-                    execute_response.messages[1].msg,
+                    execute_response.messages[0].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "asker".into(),
                         amount: coins(149, "quote_1"),
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[2].msg,
+                    execute_response.messages[1].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: coins(149, "base_1"),
@@ -703,6 +728,7 @@ mod execute_match_tests {
             Some(FeeInfo {
                 account: Addr::unchecked("bid_fee_account"),
                 rate: "0.01".into(),
+                policy: FeePolicy::default(),
             }),
             0,
             1,
@@ -719,6 +745,7 @@ mod execute_match_tests {
                 price: "1".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(149),
+                ..Default::default()
             },
         );
 
@@ -741,6 +768,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -830,15 +858,16 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -853,6 +882,7 @@ mod execute_match_tests {
                 price: "1".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(150),
+                ..Default::default()
             },
         );
 
@@ -878,6 +908,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -917,23 +948,25 @@ mod execute_match_tests {
                 assert_eq!(execute_response.attributes[7], attr("ask_fee", "0"));
                 assert_eq!(execute_response.attributes[8], attr("bid_fee", "2"));
 
-                assert_eq!(execute_response.messages.len(), 3);
+                assert_eq!(execute_response.messages.len(), 2);
                 assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: "bid_fee_account".into(),
-                        amount: coins(2, "quote_1"),
-                    })
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("bid_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(2)
                 );
                 assert_eq!(
-                    execute_response.messages[1].msg,
+                    execute_response.messages[0].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "asker".into(),
                         amount: coins(150, "quote_1"),
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[2].msg,
+                    execute_response.messages[1].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: coins(150, "base_1"),
@@ -975,12 +1008,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -995,6 +1030,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(30),
+                ..Default::default()
             },
         );
 
@@ -1017,6 +1053,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -1089,7 +1126,8 @@ mod execute_match_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(20)
+                        size: Uint128::new(20),
+                        ..Default::default()
                     }
                 )
             }
@@ -1121,12 +1159,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1141,6 +1181,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(50),
+                ..Default::default()
             },
         );
 
@@ -1163,6 +1204,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -1244,6 +1286,7 @@ mod execute_match_tests {
                             amount: Uint128::new(200),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 )
             }
@@ -1275,12 +1318,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1295,6 +1340,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -1317,6 +1363,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -1389,7 +1436,8 @@ mod execute_match_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(100)
+                        size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -1422,6 +1470,7 @@ mod execute_match_tests {
                             amount: Uint128::new(600),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 )
             }
@@ -1445,12 +1494,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1470,6 +1521,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -1492,6 +1544,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -1577,7 +1630,8 @@ mod execute_match_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(100)
+                        size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -1610,6 +1664,7 @@ mod execute_match_tests {
                             amount: Uint128::new(600),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 );
                 assert_eq!(400_u128, stored_order.get_remaining_quote().u128());
@@ -1637,12 +1692,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1657,6 +1714,7 @@ mod execute_match_tests {
                 price: "2.000000000000000000".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(777),
+                ..Default::default()
             },
         );
 
@@ -1679,6 +1737,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "100.000000000000000000".into(),
+                ..Default::default()
             },
         );
 
@@ -1779,12 +1838,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1799,6 +1860,7 @@ mod execute_match_tests {
                 price: "2.000000000000000000".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(777),
+                ..Default::default()
             },
         );
 
@@ -1821,6 +1883,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "100.000000000000000000".into(),
+                ..Default::default()
             },
         );
 
@@ -1920,6 +1983,7 @@ mod execute_match_tests {
                             amount: Uint128::new(1000),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 );
                 assert_eq!(stored_order.get_remaining_base().u128(), 5_u128);
@@ -1947,15 +2011,18 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1970,6 +2037,7 @@ mod execute_match_tests {
                 price: "2.000000000000000000".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(777),
+                ..Default::default()
             },
         );
 
@@ -1995,6 +2063,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "100.000000000000000000".into(),
+                ..Default::default()
             },
         );
 
@@ -2037,37 +2106,39 @@ mod execute_match_tests {
                 assert_eq!(execute_response.attributes[7], attr("ask_fee", "0"));
                 assert_eq!(execute_response.attributes[8], attr("bid_fee", "1"));
 
-                assert_eq!(execute_response.messages.len(), 5);
+                assert_eq!(execute_response.messages.len(), 4);
                 assert_eq!(
-                    execute_response.messages[0].msg,
-                    CosmosMsg::Bank(BankMsg::Send {
-                        to_address: "bid_fee_account".into(),
-                        amount: vec![coin(1, "quote_1")],
-                    })
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("bid_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(1)
                 );
                 assert_eq!(
-                    execute_response.messages[1].msg,
+                    execute_response.messages[0].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "asker".into(),
                         amount: coins(10, "quote_1"),
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[2].msg,
+                    execute_response.messages[1].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: vec![coin(5, "base_1")],
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[3].msg,
+                    execute_response.messages[2].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: vec![coin(490, "quote_1")],
                     })
                 );
                 assert_eq!(
-                    execute_response.messages[4].msg,
+                    execute_response.messages[3].msg,
                     CosmosMsg::Bank(BankMsg::Send {
                         to_address: "bidder".into(),
                         amount: vec![coin(49, "quote_1")],
@@ -2111,6 +2182,7 @@ mod execute_match_tests {
                             amount: Uint128::new(1000),
                         },
                         price: "100.000000000000000000".into(),
+                        ..Default::default()
                     }
                 )
             }
@@ -2120,6 +2192,150 @@ mod execute_match_tests {
         }
     }
 
+    // since using ask price, and ask.price < bid.price, bidder should be refunded the quote and
+    // escrowed fee not spent when the fill completes the bid
+    #[test]
+    fn execute_price_overlap_use_ask_with_bid_fees_and_full_bid() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base(
+            &mut deps.storage,
+            &ContractInfoV3 {
+                name: "contract_name".into(),
+                bind_name: "contract_bind_name".into(),
+                base_denom: "base_denom".into(),
+                convertible_base_denoms: vec!["con_base_1".into(), "con_base_2".into()],
+                supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
+                approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
+                ask_fee_info: None,
+                bid_fee_info: Some(FeeInfo {
+                    account: Addr::unchecked("bid_fee_account"),
+                    rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
+                }),
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
+                price_precision: Uint128::new(2),
+                size_increment: Uint128::new(100),
+                ..Default::default()
+            },
+        );
+
+        // store valid ask order
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: "base_1".into(),
+                class: AskOrderClass::Basic,
+                id: "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".into(),
+                owner: Addr::unchecked("asker"),
+                price: "2.000000000000000000".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(777),
+                ..Default::default()
+            },
+        );
+
+        // store valid bid order
+        store_test_bid(
+            &mut deps.storage,
+            &BidOrderV3 {
+                id: "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".into(),
+                owner: Addr::unchecked("bidder"),
+                base: Coin {
+                    amount: Uint128::new(10),
+                    denom: "base_1".into(),
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee: Some(Coin {
+                    amount: Uint128::new(100),
+                    denom: "quote_1".to_string(),
+                }),
+                quote: Coin {
+                    amount: Uint128::new(1000),
+                    denom: "quote_1".into(),
+                },
+                price: "100.000000000000000000".into(),
+                ..Default::default()
+            },
+        );
+
+        // execute on matched ask order and bid order
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".into(),
+            bid_id: "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".into(),
+            price: "2.000000000000000000".into(),
+            size: Uint128::new(10),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_msg,
+        );
+
+        // validate execute response
+        match execute_response {
+            Err(error) => panic!("unexpected error: {:?}", error),
+            Ok(execute_response) => {
+                assert_eq!(execute_response.attributes[6], attr("size", "10"));
+                assert_eq!(execute_response.attributes[8], attr("bid_fee", "2"));
+
+                assert_eq!(execute_response.messages.len(), 4);
+                assert_eq!(
+                    ACCRUED_FEES_V1
+                        .load(
+                            &deps.storage,
+                            (&Addr::unchecked("bid_fee_account"), "quote_1")
+                        )
+                        .unwrap(),
+                    Uint128::new(2)
+                );
+                assert_eq!(
+                    execute_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(20, "quote_1"),
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[1].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: vec![coin(10, "base_1")],
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: vec![coin(980, "quote_1")],
+                    })
+                );
+                assert_eq!(
+                    execute_response.messages[3].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: vec![coin(98, "quote_1")],
+                    })
+                );
+            }
+        }
+
+        // verify bid order removed from storage
+        assert!(BIDS_V3
+            .load(
+                &deps.storage,
+                "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".as_bytes()
+            )
+            .is_err());
+    }
+
     // since using ask price, and ask.price < bid.price, bidder should be refunded
     // remaining quote balance if remaining order size = 0
     #[test]
@@ -2137,12 +2353,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2166,6 +2384,7 @@ mod execute_match_tests {
                 price: "2.000000000000000000".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(777),
+                ..Default::default()
             },
         );
 
@@ -2188,6 +2407,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "100.000000000000000000".into(),
+                ..Default::default()
             },
         );
 
@@ -2300,12 +2520,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2320,6 +2542,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2342,6 +2565,7 @@ mod execute_match_tests {
                     denom: "quote_1".into(),
                 },
                 price: "4".into(),
+                ..Default::default()
             },
         );
 
@@ -2431,12 +2655,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2456,6 +2682,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2478,6 +2705,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -2574,12 +2802,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
         QueryMarkerRequest::mock_response(
@@ -2602,6 +2832,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2624,6 +2855,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -2720,12 +2952,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2749,6 +2983,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2771,6 +3006,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -2867,12 +3103,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
         // TODO - fix test since mock response returns same result no matter the input
@@ -2904,6 +3142,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -2926,6 +3165,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3046,12 +3286,12 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3071,6 +3311,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3093,6 +3334,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3212,12 +3454,12 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3237,6 +3479,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3259,6 +3502,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3389,12 +3633,12 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3414,6 +3658,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3436,6 +3681,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3549,12 +3795,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3602,12 +3850,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3647,12 +3897,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
         // store valid ask order
@@ -3668,6 +3920,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -3690,6 +3943,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3734,12 +3988,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3762,6 +4018,7 @@ mod execute_match_tests {
                     amount: Uint128::new(100),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3801,12 +4058,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
         // store valid ask order
@@ -3820,6 +4079,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -3858,13 +4118,15 @@ mod execute_match_tests {
                 convertible_base_denoms: vec!["con_base_1".into(), "con_base_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 supported_quote_denoms: vec![],
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3904,12 +4166,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -3924,6 +4188,7 @@ mod execute_match_tests {
                 price: "3".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(300),
+                ..Default::default()
             },
         );
 
@@ -3946,6 +4211,7 @@ mod execute_match_tests {
                     amount: Uint128::new(100),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -3987,12 +4253,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -4007,6 +4275,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -4029,6 +4298,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -4086,12 +4356,14 @@ mod execute_match_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -4106,6 +4378,7 @@ mod execute_match_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -4128,6 +4401,7 @@ mod execute_match_tests {
                     amount: Uint128::new(400),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -4169,4 +4443,90 @@ mod execute_match_tests {
             )
             .is_ok());
     }
+
+    #[test]
+    fn execute_expired_ask_returns_err() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        let mock_env = mock_env();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        // store expired ask order
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: "base_1".into(),
+                class: AskOrderClass::Basic,
+                id: "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(100),
+                time_in_force: TimeInForce::GoodTilDate,
+                expires_at: Some(mock_env.block.time),
+                ..Default::default()
+            },
+        );
+
+        // store valid bid order
+        store_test_bid(
+            &mut deps.storage,
+            &BidOrderV3 {
+                id: "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".into(),
+                owner: Addr::unchecked("bidder"),
+                base: Coin {
+                    amount: Uint128::new(100),
+                    denom: "base_1".into(),
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee: None,
+                quote: Coin {
+                    amount: Uint128::new(200),
+                    denom: "quote_1".into(),
+                },
+                price: "2".into(),
+                ..Default::default()
+            },
+        );
+
+        // execute on matched ask order and bid order
+        let execute_msg = ExecuteMsg::ExecuteMatch {
+            ask_id: "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".into(),
+            bid_id: "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        };
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env,
+            mock_info("exec_1", &[]),
+            execute_msg,
+        );
+
+        // validate execute response
+        match execute_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::OrderExpired => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+
+        // verify orders remain in storage
+        assert!(ASKS_V1
+            .load(
+                &deps.storage,
+                "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".as_bytes()
+            )
+            .is_ok());
+        assert!(BIDS_V3
+            .load(
+                &deps.storage,
+                "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".as_bytes()
+            )
+            .is_ok());
+    }
 }
@@ -1,12 +1,12 @@
 #[cfg(test)]
 mod execute_modify_test {
-    use crate::ask_order::{AskOrderClass, AskOrderV1};
-    use crate::common::FeeInfo;
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::common::{AttributePolicy, FeeInfo, FeePolicy, FeeTerms};
     use crate::contract::execute;
     use crate::contract_info::{get_contract_info, ContractInfoV3};
     use crate::error::ContractError;
     use crate::msg::ExecuteMsg;
-    use crate::tests::test_setup_utils::{setup_test_base, store_test_ask};
+    use crate::tests::test_setup_utils::{all_of_attributes, setup_test_base, store_test_ask};
     use crate::version_info::{set_version_info, VersionInfoV1};
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coin, Addr, MessageInfo, Response, Uint128};
@@ -15,8 +15,10 @@ mod execute_modify_test {
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
     };
 
-    fn get_expected_modify_contract_response() -> Response {
-        Response::new().add_attribute("action", "modify_contract")
+    fn get_expected_modify_contract_response(change_id: u64) -> Response {
+        Response::new()
+            .add_attribute("action", "modify_contract")
+            .add_attribute("change_id", change_id.to_string())
     }
 
     #[test]
@@ -46,12 +48,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -71,7 +75,7 @@ mod execute_modify_test {
             }
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec1".into(), "exec3".into()]),
@@ -79,14 +83,33 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_3".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -111,23 +134,25 @@ mod execute_modify_test {
                     contract_info.ask_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_1"),
-                        rate: "0.123".into()
+                        rate: "0.123".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
                     contract_info.bid_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_2"),
-                        rate: "0.234".into()
+                        rate: "0.234".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_3".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"]))
                 );
             }
         }
@@ -158,12 +183,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -187,12 +214,12 @@ mod execute_modify_test {
                 assert_eq!(contract_info.ask_fee_info, None);
                 assert_eq!(contract_info.bid_fee_info, None);
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_2".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"]))
                 );
             }
         }
@@ -203,8 +230,27 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_3".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"])),
         };
         let exec_info = mock_info("invalid_exec", &[]);
         let modify_contract_response =
@@ -237,12 +283,12 @@ mod execute_modify_test {
                 assert_eq!(contract_info.ask_fee_info, None);
                 assert_eq!(contract_info.bid_fee_info, None);
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_2".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"]))
                 );
             }
         }
@@ -273,18 +319,22 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("ask_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.01".into(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -295,12 +345,31 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: Some("0.0s".into()),
             bid_fee_account: Some("bid_fee_account".into()),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -318,12 +387,31 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: Some("0.01".into()),
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -341,12 +429,31 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: Some("bid_fee_account".into()),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -364,12 +471,31 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -387,12 +513,31 @@ mod execute_modify_test {
             ask_fee_account: Some("ask_fee_account".into()),
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -410,12 +555,31 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -452,12 +616,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -480,12 +646,12 @@ mod execute_modify_test {
                 assert_eq!(contract_info.ask_fee_info, None);
                 assert_eq!(contract_info.bid_fee_info, None);
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_2".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"]))
                 );
             }
         }
@@ -496,12 +662,31 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_3".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"])),
         };
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("unexpected because invalid version"),
             Err(error) => match error {
@@ -535,12 +720,12 @@ mod execute_modify_test {
                 assert_eq!(contract_info.ask_fee_info, None);
                 assert_eq!(contract_info.bid_fee_info, None);
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_2".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"]))
                 );
             }
         }
@@ -572,12 +757,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -597,8 +784,8 @@ mod execute_modify_test {
             }
         }
 
-        // modify ask_required_attributes with no ask
-        let exec_info = mock_info("exec_1", &[]);
+        // modify ask_attribute_policy with no ask
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: None,
             executors: None,
@@ -606,14 +793,33 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec!["ask_tag_1".into(), "ask_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -647,6 +853,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -664,6 +873,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response_2 = execute(
             deps.as_mut(),
@@ -676,8 +888,8 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        // modify ask_required_attributes with active ask
-        let exec_info = mock_info("exec_1", &[]);
+        // modify ask_attribute_policy with active ask
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: None,
             executors: None,
@@ -685,20 +897,35 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec![
-                "ask_tag_1".into(),
-                "ask_tag_2".into(),
-                "ask_tag_3".into(),
-            ]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2", "ask_tag_3"])),
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["ask_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["ask_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
@@ -735,6 +962,9 @@ mod execute_modify_test {
             quote: "quote_1".into(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_bid_response = execute(
             deps.as_mut(),
@@ -747,8 +977,8 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        // modify bid_required_attributes with active bid
-        let exec_info = mock_info("exec_1", &[]);
+        // modify bid_attribute_policy with active bid
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: None,
             executors: None,
@@ -756,20 +986,35 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
-            bid_required_attributes: Some(vec![
-                "bid_tag_1".into(),
-                "bid_tag_2".into(),
-                "bid_tag_3".into(),
-            ]),
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2", "bid_tag_3"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["bid_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["bid_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
@@ -779,12 +1024,12 @@ mod execute_modify_test {
         match contract_info {
             Ok(contract_info) => {
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_2".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"]))
                 );
             }
             Err(error) => panic!("unexpected error: {:?}", error),
@@ -817,12 +1062,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -871,6 +1118,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -884,7 +1134,7 @@ mod execute_modify_test {
         }
 
         // empty executors not allowed, else anyone can execute
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: None,
@@ -892,11 +1142,30 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
@@ -908,7 +1177,7 @@ mod execute_modify_test {
         }
 
         // empty executors not allowed, else anyone can execute
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec![
                 "approver_1".into(),
@@ -920,14 +1189,33 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -980,12 +1268,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1000,6 +1290,7 @@ mod execute_modify_test {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1021,7 +1312,7 @@ mod execute_modify_test {
         }
 
         // empty executors not allowed, else anyone can execute
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec![]),
@@ -1029,11 +1320,30 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec!["ask_tag_3".into(), "ask_tag_4".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_3", "ask_tag_4"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
@@ -1045,7 +1355,7 @@ mod execute_modify_test {
         }
 
         // empty approvers not allowed, else anyone cn approve
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec![]),
             executors: None,
@@ -1053,11 +1363,30 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec!["ask_tag_3".into(), "ask_tag_4".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_3", "ask_tag_4"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
@@ -1068,7 +1397,7 @@ mod execute_modify_test {
             },
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: None,
             executors: None,
@@ -1076,14 +1405,33 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -1116,12 +1464,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1154,6 +1504,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -1182,8 +1535,8 @@ mod execute_modify_test {
             }
         }
 
-        // ask_required_attributes conflict with active asks
-        let exec_info = mock_info("exec_1", &[]);
+        // ask_attribute_policy conflict with active asks
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1191,22 +1544,41 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["ask_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["ask_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1214,22 +1586,41 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec!["ask_tag_1".into(), "ask_tag_2".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["ask_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["ask_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1237,20 +1628,35 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: Some(vec![
-                "ask_tag_1".into(),
-                "ask_tag_2".into(),
-                "ask_tag_3".into(),
-            ]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2", "ask_tag_3"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["ask_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["ask_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
@@ -1271,7 +1677,7 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec1".into(), "exec3".into()]),
@@ -1279,14 +1685,33 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_3".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -1311,23 +1736,25 @@ mod execute_modify_test {
                     contract_info.ask_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_1"),
-                        rate: "0.123".into()
+                        rate: "0.123".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
                     contract_info.bid_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_2"),
-                        rate: "0.234".into()
+                        rate: "0.234".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_3".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"]))
                 );
             }
         }
@@ -1360,12 +1787,14 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1385,7 +1814,7 @@ mod execute_modify_test {
             }
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1393,14 +1822,33 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
-            bid_required_attributes: Some(vec![]),
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(AttributePolicy::AllOf(vec![])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(1))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -1410,11 +1858,10 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
             Ok(contract_info) => {
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string(), "ask_tag_2".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"]))
                 );
-                let empty_vector: Vec<String> = vec![];
-                assert_eq!(contract_info.bid_required_attributes, empty_vector);
+                assert_eq!(contract_info.bid_attribute_policy, None);
                 assert_eq!(
                     contract_info.convertible_base_denoms,
                     vec!["con_base_1".to_string(), "con_base_2".to_string()]
@@ -1424,6 +1871,7 @@ mod execute_modify_test {
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_1"),
                         rate: "0.123".to_string(),
+                        policy: FeePolicy::default(),
                     })
                 );
             }
@@ -1456,12 +1904,13 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1510,6 +1959,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -1528,6 +1980,9 @@ mod execute_modify_test {
             price: "3".into(),
             quote: "quote_2".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -1571,6 +2026,9 @@ mod execute_modify_test {
             quote: "quote_1".to_string(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_bid_response = execute(
             deps.as_mut(),
@@ -1583,7 +2041,7 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1591,16 +2049,35 @@ mod execute_modify_test {
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
-            bid_required_attributes: Some(vec!["bid_tag_3".into(), "bid_tag_4".into()]),
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_3", "bid_tag_4"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["bid_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["bid_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
@@ -1608,7 +2085,7 @@ mod execute_modify_test {
     }
 
     #[test]
-    fn execute_modify_contract_existing_ask_order_invalid_ask_fee_change() {
+    fn execute_modify_contract_existing_ask_order_valid_ask_fee_change() {
         let mut deps = mock_provenance_dependencies();
 
         let version_info = set_version_info(
@@ -1633,12 +2110,13 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1688,6 +2166,9 @@ mod execute_modify_test {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -1706,6 +2187,9 @@ mod execute_modify_test {
             price: "3".into(),
             quote: "quote_2".into(),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_ask_response = execute(
             deps.as_mut(),
@@ -1718,32 +2202,71 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
-            approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
+            approvers: None,
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
             ask_fee_rate: Some("0.123".into()),
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: None,
             bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
-            Ok(_) => panic!("expected modifyContract validation to fail"),
-            Err(error) => match error {
-                ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["ask_fee".to_string()]);
-                }
-                _ => panic!("unexpected error: {:?}", error),
-            },
+            Ok(response) => {
+                assert_eq!(response, get_expected_modify_contract_response(1))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
         }
+
+        // the new ask fee applies to new asks, existing asks keep the fee terms they were created with
+        let ask_order = ASKS_V1
+            .load(
+                &deps.storage,
+                "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367".as_bytes(),
+            )
+            .unwrap();
+        assert_eq!(
+            ask_order.fee_terms,
+            Some(FeeTerms {
+                fee_info: None,
+                maker_taker_fee_info: None,
+            })
+        );
+        assert_eq!(
+            get_contract_info(&deps.storage).unwrap().ask_fee_info,
+            Some(FeeInfo {
+                account: Addr::unchecked("fee_acct_1"),
+                rate: "0.123".into(),
+                policy: FeePolicy::default(),
+            })
+        );
     }
 
     #[test]
-    fn execute_modify_contract_invalid_conflicting_bid_fee() {
+    fn execute_modify_contract_existing_bid_order_valid_bid_fee_change() {
         let mut deps = mock_provenance_dependencies();
 
         let version_info = set_version_info(
@@ -1769,12 +2292,13 @@ mod execute_modify_test {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -1837,6 +2361,9 @@ mod execute_modify_test {
             quote: "quote_1".to_string(),
             quote_size: Uint128::new(200),
             size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
         };
         let create_bid_response = execute(
             deps.as_mut(),
@@ -1849,7 +2376,7 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec_1".into(), "exec_3".into()]),
@@ -1857,46 +2384,78 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_1".into()),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
-            bid_required_attributes: Some(vec![
-                "bid_tag_1".into(),
-                "bid_tag_2".into(),
-                "bid_tag_3".into(),
-            ]),
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2", "bid_tag_3"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(_) => panic!("expected modifyContract validation to fail"),
             Err(error) => match error {
                 ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["bid_required_attributes".to_string()]);
+                    assert_eq!(fields, vec!["bid_attribute_policy".to_string()]);
                 }
                 _ => panic!("unexpected error: {:?}", error),
             },
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        // fees can change with an open bid
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
-            approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
-            executors: Some(vec!["exec_1".into(), "exec_3".into()]),
+            approvers: None,
+            executors: None,
             ask_fee_rate: Some("0.123".into()),
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_1".into()),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
             ask_required_attributes: None,
             bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
-            Ok(_) => panic!("expected modifyContract validation to fail"),
-            Err(error) => match error {
-                ContractError::InvalidFields { fields } => {
-                    assert_eq!(fields, vec!["bid_fee".to_string()]);
-                }
-                _ => panic!("unexpected error: {:?}", error),
-            },
+            Ok(response) => {
+                assert_eq!(response, get_expected_modify_contract_response(1))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
         }
 
         let cancel_info: MessageInfo = mock_info("bidder", &[]);
@@ -1914,7 +2473,7 @@ mod execute_modify_test {
             Err(error) => panic!("unexpected error: {:?}", error),
         }
 
-        let exec_info = mock_info("exec_1", &[]);
+        let admin_info = mock_info("contract_admin", &[]);
         let modify_contract_msg = ExecuteMsg::ModifyContract {
             approvers: Some(vec!["approver_1".into(), "approver_3".into()]),
             executors: Some(vec!["exec1".into(), "exec3".into()]),
@@ -1922,14 +2481,33 @@ mod execute_modify_test {
             ask_fee_account: Some("fee_acct_1".into()),
             bid_fee_rate: Some("0.234".into()),
             bid_fee_account: Some("fee_acct_2".into()),
-            ask_required_attributes: Some(vec!["ask_tag_1".into()]),
-            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_3".into()]),
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"])),
         };
         let modify_contract_response =
-            execute(deps.as_mut(), mock_env(), exec_info, modify_contract_msg);
+            execute(deps.as_mut(), mock_env(), admin_info, modify_contract_msg);
         match modify_contract_response {
             Ok(response) => {
-                assert_eq!(response, get_expected_modify_contract_response())
+                assert_eq!(response, get_expected_modify_contract_response(2))
             }
             Err(error) => panic!("unexpected error: {:?}", error),
         }
@@ -1954,23 +2532,25 @@ mod execute_modify_test {
                     contract_info.ask_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_1"),
-                        rate: "0.123".into()
+                        rate: "0.123".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
                     contract_info.bid_fee_info,
                     Some(FeeInfo {
                         account: Addr::unchecked("fee_acct_2"),
-                        rate: "0.234".into()
+                        rate: "0.234".into(),
+                        policy: FeePolicy::default(),
                     })
                 );
                 assert_eq!(
-                    contract_info.ask_required_attributes,
-                    vec!["ask_tag_1".to_string()]
+                    contract_info.ask_attribute_policy,
+                    Some(all_of_attributes(&["ask_tag_1"]))
                 );
                 assert_eq!(
-                    contract_info.bid_required_attributes,
-                    vec!["bid_tag_1".to_string(), "bid_tag_3".to_string()]
+                    contract_info.bid_attribute_policy,
+                    Some(all_of_attributes(&["bid_tag_1", "bid_tag_3"]))
                 );
             }
         }
@@ -30,6 +30,7 @@ mod expire_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -94,6 +95,7 @@ mod expire_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -167,6 +169,7 @@ mod expire_ask_tests {
                 quote: "quote_1".into(),
                 price: "2".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -246,6 +249,7 @@ mod expire_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -343,6 +347,7 @@ mod expire_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -479,6 +484,7 @@ mod expire_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod expire_bid_tests {
     use crate::bid_order::{BidOrderV3, BIDS_V3};
-    use crate::common::FeeInfo;
+    use crate::common::{FeeInfo, FeePolicy};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
@@ -42,6 +42,7 @@ mod expire_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -115,6 +116,7 @@ mod expire_bid_tests {
                     amount: Uint128::new(200),
                     denom: "quote_1".into(),
                 },
+                ..Default::default()
             },
         );
 
@@ -197,6 +199,7 @@ mod expire_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -329,6 +332,7 @@ mod expire_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -387,15 +391,16 @@ mod expire_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.003".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -421,6 +426,7 @@ mod expire_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "0.01".into(),
+                ..Default::default()
             },
         );
 
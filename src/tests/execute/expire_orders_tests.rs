@@ -0,0 +1,201 @@
+#[cfg(test)]
+mod expire_orders_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::TimeInForce;
+    use crate::contract::execute;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const BID_ID_2: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+
+    fn test_ask(id: &str, expires_at: Option<Timestamp>) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            time_in_force: TimeInForce::from_expiration(&expires_at),
+            expires_at,
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(id: &str, expires_at: Option<Timestamp>) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            time_in_force: TimeInForce::from_expiration(&expires_at),
+            expires_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expire_orders_refunds_expired_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let now = mock_env().block.time;
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, Some(now)));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, None));
+        store_test_bid(
+            &mut deps.storage,
+            &test_bid(BID_ID_1, Some(now.minus_seconds(10))),
+        );
+        store_test_bid(
+            &mut deps.storage,
+            &test_bid(BID_ID_2, Some(now.plus_seconds(10))),
+        );
+
+        // anyone may sweep expired orders
+        let expire_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireOrders { limit: None },
+        );
+
+        match expire_response {
+            Ok(expire_response) => {
+                assert!(expire_response
+                    .attributes
+                    .contains(&attr("action", "expire_ask")));
+                assert!(expire_response
+                    .attributes
+                    .contains(&attr("action", "expire_bid")));
+                assert!(expire_response
+                    .attributes
+                    .contains(&attr("action", "expire_orders")));
+                assert!(expire_response
+                    .attributes
+                    .contains(&attr("expired_count", "2")));
+                assert_eq!(expire_response.messages.len(), 2);
+                assert_eq!(
+                    expire_response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(100, "base_1"),
+                    })
+                );
+                assert_eq!(
+                    expire_response.messages[1].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(200, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+        assert!(!BIDS_V3.has(&deps.storage, BID_ID_1.as_bytes()));
+        assert!(BIDS_V3.has(&deps.storage, BID_ID_2.as_bytes()));
+    }
+
+    #[test]
+    fn expire_orders_stops_at_limit() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let expired = mock_env().block.time.minus_seconds(10);
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, Some(expired)));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, Some(expired)));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, Some(expired)));
+
+        let expire_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireOrders { limit: Some(2) },
+        );
+
+        match expire_response {
+            Ok(expire_response) => {
+                assert!(expire_response
+                    .attributes
+                    .contains(&attr("expired_count", "2")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        assert!(BIDS_V3.has(&deps.storage, BID_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn expire_orders_nothing_expired_returns_no_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, None));
+
+        let expire_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &[]),
+            ExecuteMsg::ExpireOrders { limit: None },
+        );
+
+        match expire_response {
+            Ok(expire_response) => {
+                assert_eq!(
+                    expire_response.attributes,
+                    vec![attr("action", "expire_orders"), attr("expired_count", "0")]
+                );
+                assert!(expire_response.messages.is_empty());
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn expire_orders_with_funds_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let expire_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("anyone", &coins(100, "quote_1")),
+            ExecuteMsg::ExpireOrders { limit: None },
+        );
+
+        match expire_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::ExpireWithFunds => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+}
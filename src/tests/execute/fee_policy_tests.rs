@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod fee_policy_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::{AccountLimits, FeeInfo, FeePolicy, FeeRecipient, SelfTradePrevention};
+    use crate::contract::{execute, instantiate};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::fee_ledger::ACCRUED_FEES_V1;
+    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn test_ask() -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            sequence: 1,
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(size: u128, fee: Option<Coin>) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee,
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(size * 2),
+                denom: "quote_1".into(),
+            },
+            sequence: 2,
+            ..Default::default()
+        }
+    }
+
+    fn execute_match_msg() -> ExecuteMsg {
+        ExecuteMsg::ExecuteMatch {
+            ask_id: ASK_ID.into(),
+            bid_id: BID_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn execute_match_splits_ask_fee_between_recipients() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.ask_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("fee_account"),
+            rate: "0.05".into(),
+            policy: FeePolicy {
+                min_fees: vec![],
+                max_fees: vec![],
+                recipients: vec![
+                    FeeRecipient {
+                        account: Addr::unchecked("platform"),
+                        weight: 1,
+                    },
+                    FeeRecipient {
+                        account: Addr::unchecked("broker"),
+                        weight: 1,
+                    },
+                    FeeRecipient {
+                        account: Addr::unchecked("venue"),
+                        weight: 1,
+                    },
+                ],
+            },
+        });
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid(100, None));
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "10")));
+                assert_eq!(
+                    execute_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(190, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the rounding remainder goes to the first recipient
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("platform"), "quote_1"))
+                .unwrap(),
+            Uint128::new(4)
+        );
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("broker"), "quote_1"))
+                .unwrap(),
+            Uint128::new(3)
+        );
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("venue"), "quote_1"))
+                .unwrap(),
+            Uint128::new(3)
+        );
+    }
+
+    #[test]
+    fn execute_match_limits_fees_to_policy_min_and_max() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.ask_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("ask_fee_account"),
+            rate: "0.05".into(),
+            policy: FeePolicy {
+                min_fees: vec![],
+                max_fees: vec![coin(4, "quote_1")],
+                recipients: vec![],
+            },
+        });
+        contract_info.bid_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("bid_fee_account"),
+            rate: "0.01".into(),
+            policy: FeePolicy {
+                min_fees: vec![coin(5, "quote_1")],
+                max_fees: vec![],
+                recipients: vec![],
+            },
+        });
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+
+        // the bid escrowed the minimum fee, above its 4 fee at the bid fee rate
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid(200, Some(coin(5, "quote_1"))));
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "4")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "5")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the minimum fee used all of the escrowed fee
+        let bid_order = BIDS_V3.load(&deps.storage, BID_ID.as_bytes()).unwrap();
+        assert_eq!(bid_order.get_remaining_fee(), Uint128::zero());
+        assert_eq!(bid_order.get_remaining_base(), Uint128::new(100));
+    }
+
+    #[test]
+    fn create_bid_fee_must_cover_min_fee() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.bid_attribute_policy = None;
+        contract_info.bid_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("bid_fee_account"),
+            rate: "0.01".into(),
+            policy: FeePolicy {
+                min_fees: vec![coin(5, "quote_1")],
+                max_fees: vec![],
+                recipients: vec![],
+            },
+        });
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+
+        let create_bid_msg = |fee: u128| ExecuteMsg::CreateBid {
+            id: BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: Some(coin(fee, "quote_1")),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(200),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
+        };
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[coin(202, "quote_1")]),
+            create_bid_msg(2),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFeeSize { .. }) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[coin(205, "quote_1")]),
+            create_bid_msg(5),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn instantiate_with_fee_policy_without_fee_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+
+        let instantiate_response = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                name: "contract_name".into(),
+                base_denom: "base_1".into(),
+                convertible_base_denoms: vec![],
+                supported_quote_denoms: vec!["quote_1".into()],
+                approvers: vec![],
+                executors: vec!["exec_1".into()],
+                admin: None,
+                governance_delay: 0,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_fee_policy: Some(FeePolicy {
+                    min_fees: vec![coin(5, "quote_1")],
+                    max_fees: vec![],
+                    recipients: vec![],
+                }),
+                bid_fee_policy: None,
+                maker_fee_rate: None,
+                taker_fee_rate: None,
+                maker_taker_fee_account: None,
+                fee_tiers: vec![],
+                self_trade_prevention: SelfTradePrevention::default(),
+                price_band: None,
+                order_limits: vec![],
+                account_limits: AccountLimits::default(),
+                account_limits_overrides: vec![],
+                ask_attribute_policy: None,
+                bid_attribute_policy: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                price_precision: Uint128::new(2),
+                size_increment: Uint128::new(100),
+            },
+        );
+
+        match instantiate_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["ask_fee_policy"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod fee_tier_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::{AccountLimits, FeeInfo, FeePolicy, FeeTier, SelfTradePrevention};
+    use crate::contract::{execute, instantiate};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::fee_ledger::ACCRUED_FEES_V1;
+    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use crate::volume::{get_rolling_volume, record_volume};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, Coin, CosmosMsg, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn set_fees_and_tiers(storage: &mut dyn Storage) {
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.ask_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("ask_fee_account"),
+            rate: "0.05".into(),
+            policy: FeePolicy::default(),
+        });
+        contract_info.bid_fee_info = Some(FeeInfo {
+            account: Addr::unchecked("bid_fee_account"),
+            rate: "0.05".into(),
+            policy: FeePolicy::default(),
+        });
+        contract_info.fee_tiers = vec![
+            FeeTier {
+                volume_threshold: Uint128::new(1000),
+                ask_rate: "0.03".into(),
+                bid_rate: "0.04".into(),
+            },
+            FeeTier {
+                volume_threshold: Uint128::new(5000),
+                ask_rate: "0.01".into(),
+                bid_rate: "0.02".into(),
+            },
+        ];
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn test_ask() -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            sequence: 1,
+            ..Default::default()
+        }
+    }
+
+    // the bid escrows its fee at the bid fee rate
+    fn test_bid() -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: Some(coin(10, "quote_1")),
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            sequence: 2,
+            ..Default::default()
+        }
+    }
+
+    fn execute_match_msg() -> ExecuteMsg {
+        ExecuteMsg::ExecuteMatch {
+            ask_id: ASK_ID.into(),
+            bid_id: BID_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn execute_match_fee_tiers_discount_ask_and_bid_fees() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_fees_and_tiers(&mut deps.storage);
+
+        let time = mock_env().block.time;
+        let asker = Addr::unchecked("asker");
+        let bidder = Addr::unchecked("bidder");
+        record_volume(&mut deps.storage, &asker, time, Uint128::new(5000)).unwrap();
+        record_volume(&mut deps.storage, &bidder, time, Uint128::new(1000)).unwrap();
+
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid());
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        );
+
+        match execute_response {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "2")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "8")));
+                assert_eq!(
+                    execute_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(198, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                        // the escrowed fee left unused by the fee tier
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(2, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(BIDS_V3.is_empty(&deps.storage));
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(
+                    &deps.storage,
+                    (&Addr::unchecked("ask_fee_account"), "quote_1")
+                )
+                .unwrap(),
+            Uint128::new(2)
+        );
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(
+                    &deps.storage,
+                    (&Addr::unchecked("bid_fee_account"), "quote_1")
+                )
+                .unwrap(),
+            Uint128::new(8)
+        );
+        assert_eq!(
+            get_rolling_volume(&deps.storage, &asker, time).unwrap(),
+            Uint128::new(5200)
+        );
+        assert_eq!(
+            get_rolling_volume(&deps.storage, &bidder, time).unwrap(),
+            Uint128::new(1200)
+        );
+    }
+
+    #[test]
+    fn execute_match_below_fee_tier_threshold_charges_order_fee_rates() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_fees_and_tiers(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid());
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "10")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "10")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the fill counts towards both accounts' volume
+        assert_eq!(
+            get_rolling_volume(
+                &deps.storage,
+                &Addr::unchecked("asker"),
+                mock_env().block.time
+            )
+            .unwrap(),
+            Uint128::new(200)
+        );
+    }
+
+    #[test]
+    fn instantiate_with_unordered_fee_tiers_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+
+        let instantiate_response = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                name: "contract_name".into(),
+                base_denom: "base_1".into(),
+                convertible_base_denoms: vec![],
+                supported_quote_denoms: vec!["quote_1".into()],
+                approvers: vec![],
+                executors: vec!["exec_1".into()],
+                admin: None,
+                governance_delay: 0,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_fee_policy: None,
+                bid_fee_policy: None,
+                maker_fee_rate: None,
+                taker_fee_rate: None,
+                maker_taker_fee_account: None,
+                fee_tiers: vec![
+                    FeeTier {
+                        volume_threshold: Uint128::new(5000),
+                        ask_rate: "0.01".into(),
+                        bid_rate: "0.01".into(),
+                    },
+                    FeeTier {
+                        volume_threshold: Uint128::new(1000),
+                        ask_rate: "0.02".into(),
+                        bid_rate: "0.02".into(),
+                    },
+                ],
+                self_trade_prevention: SelfTradePrevention::default(),
+                price_band: None,
+                order_limits: vec![],
+                account_limits: AccountLimits::default(),
+                account_limits_overrides: vec![],
+                ask_attribute_policy: None,
+                bid_attribute_policy: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                price_precision: Uint128::new(2),
+                size_increment: Uint128::new(100),
+            },
+        );
+
+        match instantiate_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["fee_tiers"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
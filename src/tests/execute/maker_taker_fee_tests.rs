@@ -0,0 +1,432 @@
+#[cfg(test)]
+mod maker_taker_fee_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::{AccountLimits, FeeTerms, MakerTakerFeeInfo, SelfTradePrevention};
+    use crate::contract::{execute, instantiate};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::fee_ledger::{accrue_fee, ACCRUED_FEES_V1};
+    use crate::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coin, coins, Addr, BankMsg, Coin, CosmosMsg, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn set_maker_taker_fee(storage: &mut dyn Storage, maker_rate: &str, taker_rate: &str) {
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.maker_taker_fee_info = Some(MakerTakerFeeInfo {
+            account: Addr::unchecked("fee_account"),
+            maker_rate: maker_rate.into(),
+            taker_rate: taker_rate.into(),
+        });
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn test_ask(sequence: u64) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    // the bid escrows its fee at the taker rate
+    fn test_bid(sequence: u64, fee: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: Some(coin(fee, "quote_1")),
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    fn execute_match_msg() -> ExecuteMsg {
+        ExecuteMsg::ExecuteMatch {
+            ask_id: ASK_ID.into(),
+            bid_id: BID_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn execute_match_resting_ask_pays_maker_rate() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "0.01", "0.02");
+
+        store_test_ask(&mut deps.storage, &test_ask(1));
+        store_test_bid(&mut deps.storage, &test_bid(2, 4));
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        );
+
+        match execute_response {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "2")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "4")));
+                assert!(execute_response.attributes.contains(&attr("maker", "ask")));
+                assert!(execute_response
+                    .attributes
+                    .contains(&attr("maker_rebate", "0")));
+                assert_eq!(
+                    execute_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(198, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        assert!(BIDS_V3.is_empty(&deps.storage));
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("fee_account"), "quote_1"))
+                .unwrap(),
+            Uint128::new(6)
+        );
+    }
+
+    #[test]
+    fn execute_match_resting_bid_receives_maker_rebate() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "-0.01", "0.02");
+
+        store_test_ask(&mut deps.storage, &test_ask(2));
+        store_test_bid(&mut deps.storage, &test_bid(1, 4));
+
+        let execute_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        );
+
+        match execute_response {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "4")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "0")));
+                assert!(execute_response.attributes.contains(&attr("maker", "bid")));
+                assert!(execute_response
+                    .attributes
+                    .contains(&attr("maker_rebate", "2")));
+                assert_eq!(
+                    execute_response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(196, "quote_1"),
+                        }),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(100, "base_1"),
+                        }),
+                        // the unused escrowed fee plus the maker rebate
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "bidder".into(),
+                            amount: coins(6, "quote_1"),
+                        }),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(BIDS_V3.is_empty(&deps.storage));
+        // the taker fee less the maker rebate
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("fee_account"), "quote_1"))
+                .unwrap(),
+            Uint128::new(2)
+        );
+    }
+
+    #[test]
+    fn execute_match_pays_maker_rebate_from_accrued_fees() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "-0.03", "0.02");
+        accrue_fee(
+            &mut deps.storage,
+            &Addr::unchecked("fee_account"),
+            "quote_1",
+            Uint128::new(10),
+        )
+        .unwrap();
+
+        store_test_ask(&mut deps.storage, &test_ask(2));
+        store_test_bid(&mut deps.storage, &test_bid(1, 4));
+
+        // the rebate is larger than the taker fee of the fill
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "4")));
+                assert!(execute_response
+                    .attributes
+                    .contains(&attr("maker_rebate", "6")));
+                assert_eq!(
+                    execute_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(10, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the accrued fees and the taker fee less the maker rebate
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("fee_account"), "quote_1"))
+                .unwrap(),
+            Uint128::new(8)
+        );
+    }
+
+    #[test]
+    fn execute_match_skips_maker_rebate_above_accrued_fees() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "-0.03", "0.02");
+
+        store_test_ask(&mut deps.storage, &test_ask(2));
+        store_test_bid(&mut deps.storage, &test_bid(1, 4));
+
+        // the fee account has only accrued the taker fee of the fill
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "4")));
+                assert!(execute_response
+                    .attributes
+                    .contains(&attr("maker_rebate", "0")));
+                assert!(execute_response
+                    .attributes
+                    .contains(&attr("maker_rebate_skipped", "6")));
+                // only the unused escrowed fee is returned
+                assert_eq!(
+                    execute_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(4, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&Addr::unchecked("fee_account"), "quote_1"))
+                .unwrap(),
+            Uint128::new(4)
+        );
+    }
+
+    #[test]
+    fn execute_match_charges_fee_terms_orders_were_created_with() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "0.05", "0.1");
+
+        let fee_terms = FeeTerms {
+            fee_info: None,
+            maker_taker_fee_info: Some(MakerTakerFeeInfo {
+                account: Addr::unchecked("fee_account"),
+                maker_rate: "0.01".into(),
+                taker_rate: "0.02".into(),
+            }),
+        };
+        let mut ask_order = test_ask(1);
+        ask_order.fee_terms = Some(fee_terms.to_owned());
+        store_test_ask(&mut deps.storage, &ask_order);
+        let mut bid_order = test_bid(2, 4);
+        bid_order.fee_terms = Some(fee_terms);
+        store_test_bid(&mut deps.storage, &bid_order);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(execute_response) => {
+                assert!(execute_response.attributes.contains(&attr("ask_fee", "2")));
+                assert!(execute_response.attributes.contains(&attr("bid_fee", "4")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn execute_match_partial_fill_resting_bid_keeps_remaining_escrowed_fee() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        set_maker_taker_fee(&mut deps.storage, "0.01", "0.02");
+
+        let mut ask_order = test_ask(2);
+        ask_order.size = Uint128::new(100);
+        store_test_ask(&mut deps.storage, &ask_order);
+
+        let mut bid_order = test_bid(1, 8);
+        bid_order.base.amount = Uint128::new(200);
+        bid_order.quote.amount = Uint128::new(400);
+        store_test_bid(&mut deps.storage, &bid_order);
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        // the maker fee of 2 is charged and the unused 2 returned, half the escrowed fee remains
+        let bid_order = BIDS_V3.load(&deps.storage, BID_ID.as_bytes()).unwrap();
+        assert_eq!(bid_order.get_remaining_fee(), Uint128::new(4));
+        assert_eq!(bid_order.get_remaining_quote(), Uint128::new(200));
+    }
+
+    #[test]
+    fn create_bid_fee_must_match_taker_rate() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.bid_attribute_policy = None;
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+        set_maker_taker_fee(&mut deps.storage, "-0.01", "0.02");
+
+        let create_bid_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[coin(202, "quote_1")]),
+            ExecuteMsg::CreateBid {
+                id: BID_ID.into(),
+                base: "base_1".into(),
+                fee: Some(coin(2, "quote_1")),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                quote_size: Uint128::new(200),
+                size: Uint128::new(100),
+                order_type: None,
+                time_in_force: None,
+                expires_at: None,
+            },
+        );
+
+        match create_bid_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFeeSize { fee_rate }) => assert_eq!(fee_rate, "0.02"),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn instantiate_with_invalid_maker_rate_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+
+        let instantiate_response = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            InstantiateMsg {
+                name: "contract_name".into(),
+                base_denom: "base_1".into(),
+                convertible_base_denoms: vec![],
+                supported_quote_denoms: vec!["quote_1".into()],
+                approvers: vec![],
+                executors: vec!["exec_1".into()],
+                admin: None,
+                governance_delay: 0,
+                ask_fee_rate: None,
+                ask_fee_account: None,
+                bid_fee_rate: None,
+                bid_fee_account: None,
+                ask_fee_policy: None,
+                bid_fee_policy: None,
+                maker_fee_rate: Some("rebate".into()),
+                taker_fee_rate: Some("0.02".into()),
+                maker_taker_fee_account: Some("fee_account".into()),
+                fee_tiers: vec![],
+                self_trade_prevention: SelfTradePrevention::default(),
+                price_band: None,
+                order_limits: vec![],
+                account_limits: AccountLimits::default(),
+                account_limits_overrides: vec![],
+                ask_attribute_policy: None,
+                bid_attribute_policy: None,
+                ask_required_attributes: None,
+                bid_required_attributes: None,
+                price_precision: Uint128::new(2),
+                size_increment: Uint128::new(100),
+            },
+        );
+
+        match instantiate_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["maker_fee_rate"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,384 @@
+#[cfg(test)]
+mod match_orders_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::TimeInForce;
+    use crate::contract::execute;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const ASK_ID_3: &str = "6f6a0e5e-3c8e-4a70-9f0d-3a1f1ab0e2d4";
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const BID_ID_2: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+    const BID_ID_3: &str = "e4c7a1f2-6b3d-4f5e-9a8b-7c6d5e4f3a2b";
+
+    fn test_ask(id: &str, price: &str, size: u128, sequence: u64) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(size),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(id: &str, price: &str, size: u128, quote_size: u128, sequence: u64) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: price.into(),
+            quote: Coin {
+                amount: Uint128::new(quote_size),
+                denom: "quote_1".into(),
+            },
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn match_orders_fills_best_ask_at_resting_price() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "3", 100, 1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "3", 100, 300, 3));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                // lowest priced ask is matched first at its resting price, bidder is refunded
+                assert!(match_response
+                    .attributes
+                    .contains(&attr("ask_id", ASK_ID_2)));
+                assert!(match_response.attributes.contains(&attr("price", "2")));
+                assert!(match_response
+                    .attributes
+                    .contains(&attr("action", "match_orders")));
+                assert!(match_response.attributes.contains(&attr("fill_count", "1")));
+                assert_eq!(match_response.messages.len(), 3);
+                assert_eq!(
+                    match_response.messages[2].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(100, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+        assert!(!BIDS_V3.has(&deps.storage, BID_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_uses_bid_price_when_bid_rested_first() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "3", 100, 300, 1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 100, 2));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response.attributes.contains(&attr("price", "3")));
+                assert!(match_response.attributes.contains(&attr("fill_count", "1")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn match_orders_prefers_older_order_at_same_price() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 100, 2));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 1));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 100, 200, 3));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response
+                    .attributes
+                    .contains(&attr("ask_id", ASK_ID_2)));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_partially_fills_larger_order() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 100, 1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 300, 600, 3));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response.attributes.contains(&attr("fill_count", "2")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        match BIDS_V3.load(&deps.storage, BID_ID_1.as_bytes()) {
+            Ok(stored_order) => {
+                assert_eq!(stored_order.accumulated_base, Uint128::new(200));
+                assert_eq!(stored_order.get_remaining_base(), Uint128::new(100));
+            }
+            _ => panic!("bid order was not found in storage"),
+        }
+    }
+
+    #[test]
+    fn match_orders_stops_at_limit() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2", 100, 1));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_3, "2", 100, 3));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 100, 200, 4));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_2, "2", 100, 200, 5));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_3, "2", 100, 200, 6));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: Some(2) },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response.attributes.contains(&attr("fill_count", "2")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_3.as_bytes()));
+        assert!(BIDS_V3.has(&deps.storage, BID_ID_3.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_skips_pending_convertible_ask() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                class: AskOrderClass::Convertible {
+                    status: AskOrderStatus::PendingIssuerApproval,
+                },
+                ..test_ask(ASK_ID_1, "1", 100, 1)
+            },
+        );
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 100, 200, 3));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response
+                    .attributes
+                    .contains(&attr("ask_id", ASK_ID_2)));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_skips_expired_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                time_in_force: TimeInForce::GoodTilDate,
+                expires_at: Some(mock_env().block.time),
+                ..test_ask(ASK_ID_1, "1", 100, 1)
+            },
+        );
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "2", 100, 2));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 100, 200, 3));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert!(match_response
+                    .attributes
+                    .contains(&attr("ask_id", ASK_ID_2)));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_no_crossing_orders_returns_no_fills() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "3", 100, 1));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "2", 100, 200, 2));
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(match_response) => {
+                assert_eq!(
+                    match_response.attributes,
+                    vec![attr("action", "match_orders"), attr("fill_count", "0")]
+                );
+                assert!(match_response.messages.is_empty());
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_1.as_bytes()));
+        assert!(BIDS_V3.has(&deps.storage, BID_ID_1.as_bytes()));
+    }
+
+    #[test]
+    fn match_orders_unauthorized_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("user", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::Unauthorized => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn match_orders_with_funds_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &coins(100, "quote_1")),
+            ExecuteMsg::MatchOrders { limit: None },
+        );
+
+        match match_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::ExecuteWithFunds => {}
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+
+    #[test]
+    fn match_orders_zero_limit_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let match_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: Some(0) },
+        );
+
+        match match_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => match error {
+                ContractError::InvalidFields { fields } => {
+                    assert!(fields.contains(&"limit".into()));
+                }
+                error => panic!("unexpected error: {:?}", error),
+            },
+        }
+    }
+}
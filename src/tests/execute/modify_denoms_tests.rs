@@ -0,0 +1,270 @@
+#[cfg(test)]
+mod modify_denoms_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1};
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::execute;
+    use crate::contract_info::get_contract_info;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin, Response, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    fn modify_denoms_msg(
+        add_supported_quote_denoms: Option<Vec<String>>,
+        remove_supported_quote_denoms: Option<Vec<String>>,
+        add_convertible_base_denoms: Option<Vec<String>>,
+        remove_convertible_base_denoms: Option<Vec<String>>,
+    ) -> ExecuteMsg {
+        ExecuteMsg::ModifyContract {
+            approvers: None,
+            executors: None,
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms,
+            remove_supported_quote_denoms,
+            add_convertible_base_denoms,
+            remove_convertible_base_denoms,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+        }
+    }
+
+    fn test_ask(base: &str, class: AskOrderClass) -> AskOrderV1 {
+        AskOrderV1 {
+            base: base.into(),
+            class,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(quote: &str) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: quote.into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn assert_invalid_fields(result: Result<Response, ContractError>, field: &str) {
+        match result {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec![field.to_string()])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_contract_adds_and_removes_denoms() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_denoms_msg(
+                Some(vec!["quote_3".into(), "quote_1".into()]),
+                Some(vec!["quote_2".into()]),
+                Some(vec!["con_base_3".into()]),
+                Some(vec!["con_base_1".into()]),
+            ),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(
+            contract_info.supported_quote_denoms,
+            vec!["quote_1".to_string(), "quote_3".to_string()]
+        );
+        assert_eq!(
+            contract_info.convertible_base_denoms,
+            vec!["con_base_2".to_string(), "con_base_3".to_string()]
+        );
+    }
+
+    #[test]
+    fn modify_contract_remove_quote_denom_used_by_open_orders_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("base_1", AskOrderClass::Basic));
+
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(None, Some(vec!["quote_1".into()]), None, None),
+            ),
+            "remove_supported_quote_denoms",
+        );
+
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_bid(&mut deps.storage, &test_bid("quote_2"));
+
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(None, Some(vec!["quote_2".into()]), None, None),
+            ),
+            "remove_supported_quote_denoms",
+        );
+
+        // a quote denom no open order uses can still be removed
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_denoms_msg(None, Some(vec!["quote_1".into()]), None, None),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn modify_contract_remove_convertible_base_denom_used_by_open_ask_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_ask(
+            &mut deps.storage,
+            &test_ask(
+                "con_base_1",
+                AskOrderClass::Convertible {
+                    status: AskOrderStatus::PendingIssuerApproval,
+                },
+            ),
+        );
+
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(None, None, None, Some(vec!["con_base_1".into()])),
+            ),
+            "remove_convertible_base_denoms",
+        );
+    }
+
+    #[test]
+    fn modify_contract_invalid_denom_changes_return_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        // denom not listed
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(None, Some(vec!["quote_3".into()]), None, None),
+            ),
+            "remove_supported_quote_denoms",
+        );
+
+        // the last quote denom can't be removed
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(
+                    None,
+                    Some(vec!["quote_1".into(), "quote_2".into()]),
+                    None,
+                    None,
+                ),
+            ),
+            "remove_supported_quote_denoms",
+        );
+
+        // the base denom isn't a convertible base denom
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(None, None, Some(vec![BASE_DENOM.into()]), None),
+            ),
+            "add_convertible_base_denoms",
+        );
+
+        // empty denom
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                modify_denoms_msg(Some(vec!["".into()]), None, None, None),
+            ),
+            "add_supported_quote_denoms",
+        );
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(
+            contract_info.supported_quote_denoms,
+            vec!["quote_1".to_string(), "quote_2".to_string()]
+        );
+    }
+}
@@ -0,0 +1,390 @@
+#[cfg(test)]
+mod modify_order_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::contract::execute;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::{BASE_DENOM, HYPHENATED_ASK_ID, HYPHENATED_BID_ID};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use crate::tests::test_utils::setup_restricted_asset_marker;
+    use crate::util::transfer_marker_coins;
+    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::marker::v1::QueryMarkerRequest;
+    use std::convert::TryInto;
+
+    fn test_ask(price: &str, size: u128) -> AskOrderV1 {
+        AskOrderV1 {
+            base: BASE_DENOM.into(),
+            class: AskOrderClass::Basic,
+            id: HYPHENATED_ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(size),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(price: &str, size: u128, quote_size: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: BASE_DENOM.into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: HYPHENATED_BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: price.into(),
+            quote: Coin {
+                amount: Uint128::new(quote_size),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn modify_ask_increase_size_escrows_difference() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2", 100));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(200, BASE_DENOM)),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "2".into(),
+                size: Uint128::new(300),
+            },
+        );
+
+        match modify_response {
+            Ok(response) => {
+                assert_eq!(
+                    response.attributes,
+                    vec![
+                        attr("action", "modify_ask"),
+                        attr("id", HYPHENATED_ASK_ID),
+                        attr("price", "2"),
+                        attr("size", "300"),
+                    ]
+                );
+                assert!(response.messages.is_empty());
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // adding size loses time priority
+        let ask_order = ASKS_V1
+            .load(&deps.storage, HYPHENATED_ASK_ID.as_bytes())
+            .unwrap();
+        assert_eq!(ask_order.size, Uint128::new(300));
+        assert_eq!(ask_order.sequence, 1);
+    }
+
+    #[test]
+    fn modify_ask_decrease_size_refunds_difference_and_keeps_priority() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2", 300));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "2.00".into(),
+                size: Uint128::new(100),
+            },
+        );
+
+        match modify_response {
+            Ok(response) => {
+                assert_eq!(
+                    response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "asker".into(),
+                        amount: coins(200, BASE_DENOM),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let ask_order = ASKS_V1
+            .load(&deps.storage, HYPHENATED_ASK_ID.as_bytes())
+            .unwrap();
+        assert_eq!(ask_order.size, Uint128::new(100));
+        assert_eq!(ask_order.sequence, 0);
+    }
+
+    #[test]
+    fn modify_ask_restricted_marker_transfers_difference() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2", 100));
+
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            setup_restricted_asset_marker(
+                "tp18vmzryrvwaeykmdtu6cfrz5sau3dhc5c73ms0u".to_string(),
+                "tp18vd8fpwxzck93qlwghaj6arh4p7c5n89x8kskz".to_string(),
+                BASE_DENOM.to_string(),
+            ),
+        );
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "3".into(),
+                size: Uint128::new(200),
+            },
+        );
+
+        match modify_response {
+            Ok(response) => {
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(
+                    response.messages[0].msg,
+                    transfer_marker_coins(
+                        100,
+                        BASE_DENOM,
+                        Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        Addr::unchecked("asker"),
+                        Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    )
+                    .unwrap()
+                    .try_into()
+                    .unwrap()
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_ask_invalid_size_increment_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2", 100));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(50, BASE_DENOM)),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "2".into(),
+                size: Uint128::new(150),
+            },
+        );
+
+        match modify_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert!(fields.contains(&"size".into()))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_ask_by_other_sender_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2", 100));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("not_asker", &[]),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "3".into(),
+                size: Uint128::new(100),
+            },
+        );
+
+        match modify_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_ask_convertible_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut ask_order = test_ask("2", 100);
+        ask_order.base = "con_base_1".into();
+        ask_order.class = AskOrderClass::Convertible {
+            status: AskOrderStatus::PendingIssuerApproval,
+        };
+        store_test_ask(&mut deps.storage, &ask_order);
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::ModifyAsk {
+                id: HYPHENATED_ASK_ID.into(),
+                price: "3".into(),
+                size: Uint128::new(100),
+            },
+        );
+
+        match modify_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::ModifyConvertibleAsk) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_bid_lower_price_refunds_difference() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_bid(&mut deps.storage, &test_bid("2", 200, 400));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::ModifyBid {
+                id: HYPHENATED_BID_ID.into(),
+                price: "1.5".into(),
+                size: Uint128::new(200),
+                quote_size: Uint128::new(300),
+                fee: None,
+            },
+        );
+
+        match modify_response {
+            Ok(response) => {
+                assert_eq!(response.attributes[0], attr("action", "modify_bid"));
+                assert!(response.attributes.contains(&attr("quote_size", "300")));
+                assert_eq!(
+                    response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(100, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // changing the price loses time priority
+        let bid_order = BIDS_V3
+            .load(&deps.storage, HYPHENATED_BID_ID.as_bytes())
+            .unwrap();
+        assert_eq!(bid_order.price, "1.5");
+        assert_eq!(bid_order.quote.amount, Uint128::new(300));
+        assert_eq!(bid_order.sequence, 1);
+    }
+
+    #[test]
+    fn modify_partially_filled_bid_escrows_against_remaining() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let mut bid_order = test_bid("2", 300, 600);
+        bid_order.accumulated_base = Uint128::new(100);
+        bid_order.accumulated_quote = Uint128::new(200);
+        store_test_bid(&mut deps.storage, &bid_order);
+
+        // 400 quote remains escrowed, so 600 for 300 more base needs another 200
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(200, "quote_1")),
+            ExecuteMsg::ModifyBid {
+                id: HYPHENATED_BID_ID.into(),
+                price: "2".into(),
+                size: Uint128::new(300),
+                quote_size: Uint128::new(600),
+                fee: None,
+            },
+        );
+
+        match modify_response {
+            Ok(response) => assert!(response.messages.is_empty()),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let bid_order = BIDS_V3
+            .load(&deps.storage, HYPHENATED_BID_ID.as_bytes())
+            .unwrap();
+        assert_eq!(bid_order.get_remaining_base(), Uint128::new(300));
+        assert_eq!(bid_order.get_remaining_quote(), Uint128::new(600));
+        assert_eq!(bid_order.sequence, 1);
+    }
+
+    #[test]
+    fn modify_bid_quote_size_mismatch_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_bid(&mut deps.storage, &test_bid("2", 200, 400));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::ModifyBid {
+                id: HYPHENATED_BID_ID.into(),
+                price: "2".into(),
+                size: Uint128::new(100),
+                quote_size: Uint128::new(300),
+                fee: None,
+            },
+        );
+
+        match modify_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::SentFundsOrderMismatch) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_bid_invalid_price_precision_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_bid(&mut deps.storage, &test_bid("2", 200, 400));
+
+        let modify_response = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::ModifyBid {
+                id: HYPHENATED_BID_ID.into(),
+                price: "1.999".into(),
+                size: Uint128::new(200),
+                quote_size: Uint128::new(400),
+                fee: None,
+            },
+        );
+
+        match modify_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert!(fields.contains(&"price".into()))
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
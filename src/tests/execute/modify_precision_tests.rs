@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod modify_precision_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::execute;
+    use crate::contract_info::get_contract_info;
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Coin, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    fn modify_precision_msg(price_precision: u128, size_increment: u128) -> ExecuteMsg {
+        ExecuteMsg::ModifyContract {
+            approvers: None,
+            executors: None,
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: Some(Uint128::new(price_precision)),
+            size_increment: Some(Uint128::new(size_increment)),
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+        }
+    }
+
+    fn test_ask(price: &str, size: u128) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(size),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(price: &str, size: u128, accumulated_base: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::new(accumulated_base),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: price.into(),
+            quote: Coin {
+                amount: Uint128::new(size * 2),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn modify_contract_precision_with_conforming_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2.5", 1000));
+        store_test_bid(&mut deps.storage, &test_bid("2", 2000, 1000));
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_precision_msg(1, 1000),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(contract_info.price_precision, Uint128::new(1));
+        assert_eq!(contract_info.size_increment, Uint128::new(1000));
+    }
+
+    #[test]
+    fn modify_contract_precision_with_non_conforming_orders_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask("2.25", 1000));
+        store_test_bid(&mut deps.storage, &test_bid("2", 1000, 500));
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_precision_msg(1, 1000),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::NonConformingOrders { ids }) => {
+                assert_eq!(ids, vec![ASK_ID.to_string(), BID_ID.to_string()])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(contract_info.price_precision, Uint128::new(2));
+        assert_eq!(contract_info.size_increment, Uint128::new(100));
+    }
+
+    #[test]
+    fn modify_contract_invalid_precision_size_pair_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_precision_msg(3, 100),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidPricePrecisionSizePair) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_precision_msg(19, 0),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec!["price_precision", "size_increment"])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
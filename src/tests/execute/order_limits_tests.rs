@@ -0,0 +1,251 @@
+#[cfg(test)]
+mod order_limits_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::common::OrderLimits;
+    use crate::contract::execute;
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::{BASE_DENOM, HYPHENATED_ASK_ID, HYPHENATED_BID_ID};
+    use crate::tests::test_setup_utils::{
+        set_default_required_attributes, setup_test_base_contract_v3, store_test_ask,
+    };
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coins, Addr, Response, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.order_limits = vec![test_order_limits("quote_1")];
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn test_order_limits(quote: &str) -> OrderLimits {
+        OrderLimits {
+            quote: quote.into(),
+            min_notional: Some(Uint128::new(100)),
+            max_notional: Some(Uint128::new(1000)),
+            max_size: Some(Uint128::new(400)),
+        }
+    }
+
+    fn create_ask_msg(price: &str, size: u128) -> ExecuteMsg {
+        ExecuteMsg::CreateAsk {
+            id: HYPHENATED_ASK_ID.into(),
+            base: BASE_DENOM.into(),
+            quote: "quote_1".into(),
+            price: price.into(),
+            size: Uint128::new(size),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
+        }
+    }
+
+    fn create_bid_msg(price: &str, size: u128, quote_size: u128) -> ExecuteMsg {
+        ExecuteMsg::CreateBid {
+            id: HYPHENATED_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: price.into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(quote_size),
+            size: Uint128::new(size),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
+        }
+    }
+
+    fn assert_invalid_fields(result: Result<Response, ContractError>, field: &str) {
+        match result {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => {
+                assert_eq!(fields, vec![field.to_string()])
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn create_ask_outside_order_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        // notional 50
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(200, BASE_DENOM)),
+                create_ask_msg("0.25", 200),
+            ),
+            "min_notional",
+        );
+
+        // notional 500 but size over 400
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(500, BASE_DENOM)),
+                create_ask_msg("1", 500),
+            ),
+            "max_size",
+        );
+    }
+
+    #[test]
+    fn create_bid_outside_order_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        // notional 1200
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("bidder", &coins(1200, "quote_1")),
+                create_bid_msg("4", 300, 1200),
+            ),
+            "max_notional",
+        );
+
+        // notional 400 and size 200 are within the limits
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(400, "quote_1")),
+            create_bid_msg("2", 200, 400),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn create_market_bid_outside_order_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("bidder", &coins(50, "quote_1")),
+                ExecuteMsg::CreateMarketBid {
+                    id: HYPHENATED_BID_ID.into(),
+                    base: BASE_DENOM.into(),
+                    fee: None,
+                    quote: "quote_1".into(),
+                    quote_size: Uint128::new(50),
+                    worst_price: "2".into(),
+                },
+            ),
+            "min_notional",
+        );
+    }
+
+    #[test]
+    fn modify_ask_outside_order_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                base: BASE_DENOM.into(),
+                class: AskOrderClass::Basic,
+                id: HYPHENATED_ASK_ID.into(),
+                owner: Addr::unchecked("asker"),
+                price: "2".into(),
+                quote: "quote_1".into(),
+                size: Uint128::new(200),
+                ..Default::default()
+            },
+        );
+
+        // notional 1500
+        assert_invalid_fields(
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("asker", &coins(100, BASE_DENOM)),
+                ExecuteMsg::ModifyAsk {
+                    id: HYPHENATED_ASK_ID.into(),
+                    price: "5".into(),
+                    size: Uint128::new(300),
+                },
+            ),
+            "max_notional",
+        );
+    }
+
+    #[test]
+    fn modify_contract_invalid_order_limits_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        let mut inverted_limits = test_order_limits("quote_2");
+        inverted_limits.min_notional = Some(Uint128::new(2000));
+
+        for order_limits in [
+            vec![test_order_limits("quote_1"), test_order_limits("quote_1")],
+            vec![inverted_limits],
+        ] {
+            assert_invalid_fields(
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    mock_info("contract_admin", &[]),
+                    ExecuteMsg::ModifyContract {
+                        approvers: None,
+                        executors: None,
+                        ask_fee_rate: None,
+                        ask_fee_account: None,
+                        bid_fee_rate: None,
+                        bid_fee_account: None,
+                        ask_fee_policy: None,
+                        bid_fee_policy: None,
+                        maker_fee_rate: None,
+                        taker_fee_rate: None,
+                        maker_taker_fee_account: None,
+                        fee_tiers: None,
+                        ask_required_attributes: None,
+                        bid_required_attributes: None,
+                        add_supported_quote_denoms: None,
+                        remove_supported_quote_denoms: None,
+                        add_convertible_base_denoms: None,
+                        remove_convertible_base_denoms: None,
+                        price_precision: None,
+                        size_increment: None,
+                        self_trade_prevention: None,
+                        price_band: None,
+                        order_limits: Some(order_limits),
+                        account_limits: None,
+                        account_limits_overrides: None,
+                        ask_attribute_policy: None,
+                        bid_attribute_policy: None,
+                    },
+                ),
+                "order_limits",
+            );
+        }
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(
+            contract_info.order_limits,
+            vec![test_order_limits("quote_1")]
+        );
+    }
+}
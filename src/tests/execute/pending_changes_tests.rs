@@ -0,0 +1,341 @@
+#[cfg(test)]
+mod pending_changes_tests {
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::{execute, query};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::governance::ContractChange;
+    use crate::msg::{ConfigHistoryResponse, ExecuteMsg, PendingChangesResponse, QueryMsg};
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_bid};
+    use crate::version_info::{set_version_info, VersionInfoV1};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{
+        attr, from_binary, Addr, Attribute, Coin, Deps, DepsMut, Env, Storage, Uint128,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const GOVERNANCE_DELAY: u64 = 86_400;
+
+    fn setup_contract(storage: &mut dyn Storage, governance_delay: u64) {
+        setup_test_base_contract_v3(storage);
+        set_version_info(
+            storage,
+            &VersionInfoV1 {
+                definition: "def".to_string(),
+                version: "0.16.2".to_string(),
+            },
+        )
+        .unwrap();
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.governance_delay = governance_delay;
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn modify_executors_msg() -> ExecuteMsg {
+        ExecuteMsg::ModifyContract {
+            approvers: None,
+            executors: Some(vec!["exec_3".into()]),
+            ask_fee_rate: None,
+            ask_fee_account: None,
+            bid_fee_rate: None,
+            bid_fee_account: None,
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
+            add_supported_quote_denoms: None,
+            remove_supported_quote_denoms: None,
+            add_convertible_base_denoms: None,
+            remove_convertible_base_denoms: None,
+            price_precision: None,
+            size_increment: None,
+            self_trade_prevention: None,
+            price_band: None,
+            order_limits: None,
+            account_limits: None,
+            account_limits_overrides: None,
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+        }
+    }
+
+    fn env_after(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    fn query_pending_changes(deps: Deps) -> PendingChangesResponse {
+        from_binary(&query(deps, mock_env(), QueryMsg::GetPendingChanges {}).unwrap()).unwrap()
+    }
+
+    fn query_config_history(deps: Deps) -> ConfigHistoryResponse {
+        from_binary(
+            &query(
+                deps,
+                mock_env(),
+                QueryMsg::GetConfigHistory {
+                    start_before: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn apply_pending_changes(deps: DepsMut, env: Env) -> Vec<Attribute> {
+        match execute(
+            deps,
+            env,
+            mock_info("anyone", &[]),
+            ExecuteMsg::ApplyPendingChanges {},
+        ) {
+            Ok(response) => response.attributes,
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn modify_contract_without_delay_is_applied_and_recorded() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, 0);
+        let old_contract_info = get_contract_info(&deps.storage).unwrap();
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_executors_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let new_contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(new_contract_info.executors, vec![Addr::unchecked("exec_3")]);
+
+        let history = query_config_history(deps.as_ref()).changes;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, 1);
+        assert_eq!(history[0].applied_at, mock_env().block.into());
+        assert_eq!(history[0].old, old_contract_info);
+        assert_eq!(history[0].new, new_contract_info);
+        assert!(query_pending_changes(deps.as_ref()).changes.is_empty());
+    }
+
+    #[test]
+    fn modify_contract_with_delay_is_applied_once_effective() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, GOVERNANCE_DELAY);
+        let old_contract_info = get_contract_info(&deps.storage).unwrap();
+
+        let effective_at = mock_env().block.time.plus_seconds(GOVERNANCE_DELAY);
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_executors_msg(),
+        ) {
+            Ok(response) => assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "modify_contract"),
+                    attr("change_id", "1"),
+                    attr("effective_at", effective_at.to_string()),
+                ]
+            ),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the contract is unchanged until the change is applied
+        assert_eq!(get_contract_info(&deps.storage).unwrap(), old_contract_info);
+
+        let pending_changes = query_pending_changes(deps.as_ref()).changes;
+        assert_eq!(pending_changes.len(), 1);
+        assert_eq!(pending_changes[0].effective_at, effective_at);
+        assert_eq!(
+            pending_changes[0].change,
+            ContractChange {
+                executors: Some(vec!["exec_3".into()]),
+                ..ContractChange::default()
+            }
+        );
+
+        // not yet effective
+        assert_eq!(
+            apply_pending_changes(deps.as_mut(), env_after(GOVERNANCE_DELAY - 1)),
+            vec![
+                attr("action", "apply_pending_changes"),
+                attr("applied_count", "0"),
+                attr("skipped_count", "0"),
+            ]
+        );
+        assert_eq!(get_contract_info(&deps.storage).unwrap(), old_contract_info);
+
+        assert_eq!(
+            apply_pending_changes(deps.as_mut(), env_after(GOVERNANCE_DELAY)),
+            vec![
+                attr("change_id", "1"),
+                attr("action", "apply_pending_changes"),
+                attr("applied_count", "1"),
+                attr("skipped_count", "0"),
+            ]
+        );
+
+        let new_contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!(new_contract_info.executors, vec![Addr::unchecked("exec_3")]);
+        assert!(query_pending_changes(deps.as_ref()).changes.is_empty());
+
+        let history = query_config_history(deps.as_ref()).changes;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].applied_at.time, effective_at);
+        assert_eq!(history[0].old, old_contract_info);
+        assert_eq!(history[0].new, new_contract_info);
+    }
+
+    #[test]
+    fn cancel_pending_change_removes_it() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, GOVERNANCE_DELAY);
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            modify_executors_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::CancelPendingChange { id: 1 },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::CancelPendingChange { id: 1 },
+        ) {
+            Ok(response) => assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "cancel_pending_change"),
+                    attr("change_id", "1"),
+                ]
+            ),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(query_pending_changes(deps.as_ref()).changes.is_empty());
+
+        // a canceled change is never applied
+        apply_pending_changes(deps.as_mut(), env_after(GOVERNANCE_DELAY));
+        assert_eq!(
+            get_contract_info(&deps.storage).unwrap().executors,
+            vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")]
+        );
+        assert!(query_config_history(deps.as_ref()).changes.is_empty());
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::CancelPendingChange { id: 1 },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::InvalidFields { fields }) => assert_eq!(fields, vec!["id"]),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn apply_pending_changes_skips_invalid_change() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, GOVERNANCE_DELAY);
+
+        let mut remove_denom_msg = modify_executors_msg();
+        if let ExecuteMsg::ModifyContract {
+            executors,
+            remove_supported_quote_denoms,
+            ..
+        } = &mut remove_denom_msg
+        {
+            *executors = None;
+            *remove_supported_quote_denoms = Some(vec!["quote_1".into()]);
+        }
+        for msg in [remove_denom_msg, modify_executors_msg()] {
+            if let Err(error) = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("contract_admin", &[]),
+                msg,
+            ) {
+                panic!("unexpected error: {:?}", error)
+            }
+        }
+
+        // a bid opened while the removal is pending uses the denom
+        store_test_bid(
+            &mut deps.storage,
+            &BidOrderV3 {
+                base: Coin {
+                    amount: Uint128::new(100),
+                    denom: "base_1".into(),
+                },
+                accumulated_base: Uint128::zero(),
+                accumulated_quote: Uint128::zero(),
+                accumulated_fee: Uint128::zero(),
+                fee: None,
+                id: "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b".into(),
+                owner: Addr::unchecked("bidder"),
+                price: "2".into(),
+                quote: Coin {
+                    amount: Uint128::new(200),
+                    denom: "quote_1".into(),
+                },
+                sequence: 1,
+                ..Default::default()
+            },
+        );
+
+        // the invalid change is dropped with its reason, the next change is still applied
+        assert_eq!(
+            apply_pending_changes(deps.as_mut(), env_after(GOVERNANCE_DELAY)),
+            vec![
+                attr("skipped_change_id", "1"),
+                attr(
+                    "skip_reason",
+                    "Invalid fields: [\"remove_supported_quote_denoms\"]"
+                ),
+                attr("change_id", "2"),
+                attr("action", "apply_pending_changes"),
+                attr("applied_count", "1"),
+                attr("skipped_count", "1"),
+            ]
+        );
+
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert!(contract_info
+            .supported_quote_denoms
+            .contains(&"quote_1".to_string()));
+        assert_eq!(contract_info.executors, vec![Addr::unchecked("exec_3")]);
+        assert!(query_pending_changes(deps.as_ref()).changes.is_empty());
+
+        let history = query_config_history(deps.as_ref()).changes;
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].id, 2);
+    }
+}
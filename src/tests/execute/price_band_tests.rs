@@ -0,0 +1,275 @@
+#[cfg(test)]
+mod price_band_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::PriceBand;
+    use crate::contract::{execute, query};
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::msg::{ExecuteMsg, PriceBandResponse, QueryMsg};
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        set_default_required_attributes, setup_test_base_contract_v3, store_test_ask,
+        store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{
+        attr, coins, from_binary, Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, Response,
+        Storage, Uint128,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const BID_ID_2: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+    const HALT_DURATION: u64 = 600;
+
+    fn setup_contract(storage: &mut dyn Storage) {
+        setup_test_base_contract_v3(storage);
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.price_band = Some(PriceBand {
+            rate: "0.1".into(),
+            max_breaches: 2,
+            halt_duration: HALT_DURATION,
+        });
+        set_contract_info(storage, &contract_info).unwrap();
+    }
+
+    fn test_ask(id: &str, price: u128) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.to_string(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(id: &str, price: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: price.to_string(),
+            quote: Coin {
+                amount: Uint128::new(100 * price),
+                denom: "quote_1".into(),
+            },
+            sequence: 1,
+            ..Default::default()
+        }
+    }
+
+    fn execute_match(
+        deps: DepsMut,
+        env: Env,
+        ask_id: &str,
+        bid_id: &str,
+        price: u128,
+    ) -> Result<Response, ContractError> {
+        execute(
+            deps,
+            env,
+            mock_info("exec_1", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_id.into(),
+                bid_id: bid_id.into(),
+                price: price.to_string(),
+                size: Uint128::new(100),
+            },
+        )
+    }
+
+    fn query_price_band(deps: Deps, env: Env) -> PriceBandResponse {
+        from_binary(
+            &query(
+                deps,
+                env,
+                QueryMsg::GetPriceBand {
+                    quote: "quote_1".into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn execute_match_outside_price_band_is_rejected_and_halts_market() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        // the first fill sets the reference price
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, 10));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, 10));
+        if let Err(error) = execute_match(deps.as_mut(), mock_env(), ASK_ID_1, BID_ID_1, 10) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let price_band = query_price_band(deps.as_ref(), mock_env());
+        assert_eq!(price_band.reference_price, Some("10".to_string()));
+        assert_eq!(price_band.lower_bound, Some("9.0".to_string()));
+        assert_eq!(price_band.upper_bound, Some("11.0".to_string()));
+        assert!(!price_band.is_halted);
+
+        // a match outside the band is rejected and counted as a breach
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, 12));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_2, 12));
+        match execute_match(deps.as_mut(), mock_env(), ASK_ID_2, BID_ID_2, 12) {
+            Ok(response) => {
+                assert_eq!(
+                    response.attributes,
+                    vec![
+                        attr("action", "reject_match"),
+                        attr("price_band_breach", "true"),
+                        attr("ask_id", ASK_ID_2),
+                        attr("bid_id", BID_ID_2),
+                        attr("quote", "quote_1"),
+                        attr("price", "12"),
+                        attr("breach_count", "1"),
+                    ]
+                );
+                assert!(response.messages.is_empty());
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+        assert!(BIDS_V3.has(&deps.storage, BID_ID_2.as_bytes()));
+
+        // the second breach halts the market
+        let halted_until = mock_env().block.time.plus_seconds(HALT_DURATION);
+        match execute_match(deps.as_mut(), mock_env(), ASK_ID_2, BID_ID_2, 12) {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("breach_count", "0")));
+                assert!(response
+                    .attributes
+                    .contains(&attr("halted_until", halted_until.to_string())));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let price_band = query_price_band(deps.as_ref(), mock_env());
+        assert_eq!(price_band.halted_until, Some(halted_until));
+        assert!(price_band.is_halted);
+
+        match execute_match(deps.as_mut(), mock_env(), ASK_ID_2, BID_ID_2, 12) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::MarketHalted { .. }) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // once the halt ends the next fill sets a new reference price
+        let mut env = mock_env();
+        env.block.time = halted_until;
+        if let Err(error) = execute_match(deps.as_mut(), env.to_owned(), ASK_ID_2, BID_ID_2, 12) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        let price_band = query_price_band(deps.as_ref(), env);
+        assert_eq!(price_band.reference_price, Some("12".to_string()));
+        assert!(!price_band.is_halted);
+    }
+
+    #[test]
+    fn match_orders_stops_at_price_band_breach() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, 10));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, 10));
+        if let Err(error) = execute_match(deps.as_mut(), mock_env(), ASK_ID_1, BID_ID_1, 10) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, 12));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_2, 12));
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        ) {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("breach_count", "1")));
+                assert!(response.attributes.contains(&attr("fill_count", "0")));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(query_price_band(deps.as_ref(), mock_env()).breach_count, 1);
+    }
+
+    #[test]
+    fn create_market_bid_outside_price_band_is_rejected() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage);
+        set_default_required_attributes(&mut deps.querier, "bidder", false, true);
+
+        let market_bid = |quote_size: u128, worst_price: &str| ExecuteMsg::CreateMarketBid {
+            id: BID_ID_1.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(quote_size),
+            worst_price: worst_price.into(),
+        };
+
+        // a market bid fill sets the reference price
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, 10));
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1000, "quote_1")),
+            market_bid(1000, "10"),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        assert_eq!(
+            query_price_band(deps.as_ref(), mock_env()).reference_price,
+            Some("10".to_string())
+        );
+
+        // a fill outside the band is rejected and counted as a breach, the quote is refunded
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, 12));
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &coins(1200, "quote_1")),
+            market_bid(1200, "12"),
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("action", "reject_match")));
+                assert!(response.attributes.contains(&attr("breach_count", "1")));
+                assert!(response.attributes.contains(&attr("filled_size", "0")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "bidder".into(),
+                        amount: coins(1200, "quote_1"),
+                    })]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+        assert!(ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+        assert_eq!(query_price_band(deps.as_ref(), mock_env()).breach_count, 1);
+    }
+}
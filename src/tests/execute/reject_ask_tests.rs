@@ -29,6 +29,7 @@ mod reject_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -94,6 +95,7 @@ mod reject_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -159,6 +161,7 @@ mod reject_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(200),
+                ..Default::default()
             },
         );
 
@@ -217,7 +220,8 @@ mod reject_ask_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(100)
+                        size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -243,6 +247,7 @@ mod reject_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -280,7 +285,8 @@ mod reject_ask_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(100)
+                        size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
@@ -303,12 +309,12 @@ mod reject_ask_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -323,6 +329,7 @@ mod reject_ask_tests {
                 price: "2".into(),
                 quote: "quote_1".into(),
                 size: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -360,7 +367,8 @@ mod reject_ask_tests {
                         owner: Addr::unchecked("asker"),
                         price: "2".into(),
                         quote: "quote_1".into(),
-                        size: Uint128::new(100)
+                        size: Uint128::new(100),
+                        ..Default::default()
                     }
                 )
             }
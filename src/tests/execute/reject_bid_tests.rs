@@ -1,14 +1,14 @@
 #[cfg(test)]
 mod reject_bid_tests {
     use crate::bid_order::{BidOrderV3, BIDS_V3};
-    use crate::common::FeeInfo;
+    use crate::common::{FeeInfo, FeePolicy};
     use crate::contract::execute;
     use crate::contract_info::ContractInfoV3;
     use crate::error::ContractError;
     use crate::msg::ExecuteMsg;
     use crate::tests::test_constants::{HYPHENATED_BID_ID, UNHYPHENATED_BID_ID};
     use crate::tests::test_setup_utils::{
-        setup_test_base, setup_test_base_contract_v3, store_test_bid,
+        all_of_attributes, setup_test_base, setup_test_base_contract_v3, store_test_bid,
     };
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Uint128};
@@ -38,6 +38,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -112,6 +113,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -186,6 +188,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -254,6 +257,7 @@ mod reject_bid_tests {
                             denom: "quote_1".into(),
                         },
                         price: "2".into(),
+                        ..Default::default()
                     }
                 )
             }
@@ -276,15 +280,16 @@ mod reject_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -310,6 +315,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -388,6 +394,7 @@ mod reject_bid_tests {
                             denom: "quote_1".into(),
                         },
                         price: "1".into(),
+                        ..Default::default()
                     }
                 )
             }
@@ -410,15 +417,16 @@ mod reject_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -444,6 +452,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -522,6 +531,7 @@ mod reject_bid_tests {
                             denom: "quote_1".into(),
                         },
                         price: "1".into(),
+                        ..Default::default()
                     }
                 )
             }
@@ -544,15 +554,16 @@ mod reject_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: Some(FeeInfo {
                     account: Addr::unchecked("bid_fee_account"),
                     rate: "0.1".to_string(),
+                    policy: FeePolicy::default(),
                 }),
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -578,6 +589,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "1".into(),
+                ..Default::default()
             },
         );
 
@@ -656,6 +668,7 @@ mod reject_bid_tests {
                             denom: "quote_1".into(),
                         },
                         price: "1".into(),
+                        ..Default::default()
                     }
                 )
             }
@@ -678,12 +691,14 @@ mod reject_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                 price_precision: Uint128::new(2),
                 size_increment: Uint128::new(100),
+                ..Default::default()
             },
         );
 
@@ -706,6 +721,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -753,6 +769,7 @@ mod reject_bid_tests {
                             amount: Uint128::new(200),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 )
             }
@@ -775,12 +792,12 @@ mod reject_bid_tests {
                 supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                 approvers: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
                 executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                admin: Some(Addr::unchecked("contract_admin")),
                 ask_fee_info: None,
                 bid_fee_info: None,
-                ask_required_attributes: vec![],
-                bid_required_attributes: vec![],
                 price_precision: Uint128::new(0),
                 size_increment: Uint128::new(1),
+                ..Default::default()
             },
         );
 
@@ -803,6 +820,7 @@ mod reject_bid_tests {
                     denom: "quote_1".into(),
                 },
                 price: "2".into(),
+                ..Default::default()
             },
         );
 
@@ -850,6 +868,7 @@ mod reject_bid_tests {
                             amount: Uint128::new(200),
                             denom: "quote_1".into(),
                         },
+                        ..Default::default()
                     }
                 )
             }
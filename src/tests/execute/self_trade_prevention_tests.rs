@@ -0,0 +1,437 @@
+#[cfg(test)]
+mod self_trade_prevention_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::{OrderType, SelfTradePrevention};
+    use crate::contract::execute;
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::error::ContractError;
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        set_default_required_attributes, setup_test_base_contract_v3, store_test_ask,
+        store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, Coin, CosmosMsg, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const MARKET_BID_ID: &str = "d8a1b0b5-4a2f-4a3e-8e3c-6b0f3c2e9b71";
+
+    fn setup_contract(storage: &mut dyn Storage, self_trade_prevention: SelfTradePrevention) {
+        setup_resting_ask(storage, self_trade_prevention);
+
+        // the ask rests on the book before the bid
+        store_test_bid(storage, &test_bid(2));
+    }
+
+    fn setup_resting_ask(storage: &mut dyn Storage, self_trade_prevention: SelfTradePrevention) {
+        setup_test_base_contract_v3(storage);
+
+        let mut contract_info = get_contract_info(storage).unwrap();
+        contract_info.self_trade_prevention = self_trade_prevention;
+        set_contract_info(storage, &contract_info).unwrap();
+
+        store_test_ask(storage, &test_ask(1));
+    }
+
+    fn test_ask(sequence: u64) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("trader"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(200),
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(sequence: u64) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(300),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: BID_ID.into(),
+            owner: Addr::unchecked("trader"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(600),
+                denom: "quote_1".into(),
+            },
+            sequence,
+            ..Default::default()
+        }
+    }
+
+    fn execute_match_msg(size: u128) -> ExecuteMsg {
+        ExecuteMsg::ExecuteMatch {
+            ask_id: ASK_ID.into(),
+            bid_id: BID_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(size),
+        }
+    }
+
+    fn market_bid_msg(quote_size: u128) -> ExecuteMsg {
+        ExecuteMsg::CreateMarketBid {
+            id: MARKET_BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(quote_size),
+            worst_price: "2".into(),
+        }
+    }
+
+    fn incoming_bid_msg(order_type: OrderType) -> ExecuteMsg {
+        ExecuteMsg::CreateBid {
+            id: BID_ID.into(),
+            base: BASE_DENOM.into(),
+            fee: None,
+            price: "2".into(),
+            quote: "quote_1".into(),
+            quote_size: Uint128::new(400),
+            size: Uint128::new(200),
+            order_type: Some(order_type),
+            time_in_force: None,
+            expires_at: None,
+        }
+    }
+
+    fn refund(amount: u128, denom: &str) -> CosmosMsg {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "trader".into(),
+            amount: coins(amount, denom),
+        })
+    }
+
+    #[test]
+    fn execute_match_self_trade_reject_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::Reject);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(200),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::SelfTrade) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn execute_match_self_trade_cancel_resting_cancels_ask() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::CancelResting);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(200),
+        ) {
+            Ok(response) => {
+                assert_eq!(
+                    response.attributes,
+                    vec![
+                        attr("self_trade_prevention", "cancel_resting"),
+                        attr("ask_id", ASK_ID),
+                        attr("bid_id", BID_ID),
+                        attr("action", "cancel_ask"),
+                        attr("id", ASK_ID),
+                        attr("reverse_size", "200"),
+                        attr("order_open", "false"),
+                    ]
+                );
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(response.messages[0].msg, refund(200, "base_1"));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID.as_bytes()));
+        assert_eq!(
+            BIDS_V3.load(&deps.storage, BID_ID.as_bytes()).unwrap(),
+            test_bid(2)
+        );
+    }
+
+    #[test]
+    fn match_orders_self_trade_cancel_newest_cancels_bid() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::CancelNewest);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("self_trade_prevention", "cancel_newest")));
+                assert!(response.attributes.contains(&attr("action", "cancel_bid")));
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(response.messages[0].msg, refund(600, "quote_1"));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ASKS_V1.load(&deps.storage, ASK_ID.as_bytes()).unwrap(),
+            test_ask(1)
+        );
+        assert!(!BIDS_V3.has(&deps.storage, BID_ID.as_bytes()));
+    }
+
+    #[test]
+    fn execute_match_self_trade_decrement_both_reduces_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::DecrementBoth);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(200),
+        ) {
+            Ok(response) => {
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![refund(200, "base_1"), refund(400, "quote_1")]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // the ask is used up, the bid keeps its unmatched size
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID.as_bytes()));
+        let bid_order = BIDS_V3.load(&deps.storage, BID_ID.as_bytes()).unwrap();
+        assert_eq!(bid_order.get_remaining_base(), Uint128::new(100));
+        assert_eq!(bid_order.get_remaining_quote(), Uint128::new(200));
+    }
+
+    #[test]
+    fn match_orders_self_trade_reject_cancels_newest() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::Reject);
+
+        // the sweep doesn't fail, the newest order of the pair is canceled
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::MatchOrders { limit: None },
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("self_trade_prevention", "cancel_newest")));
+                assert!(response.attributes.contains(&attr("action", "cancel_bid")));
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(response.messages[0].msg, refund(600, "quote_1"));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ASKS_V1.load(&deps.storage, ASK_ID.as_bytes()).unwrap(),
+            test_ask(1)
+        );
+        assert!(!BIDS_V3.has(&deps.storage, BID_ID.as_bytes()));
+    }
+
+    #[test]
+    fn create_market_bid_self_trade_reject_refunds_market_bid() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::Reject);
+        set_default_required_attributes(&mut deps.querier, "trader", false, true);
+
+        // the market bid doesn't fail, it's canceled as the newest order of the pair
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(400, "quote_1")),
+            market_bid_msg(400),
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("self_trade_prevention", "cancel_newest")));
+                assert!(response.attributes.contains(&attr("filled_size", "0")));
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(response.messages[0].msg, refund(400, "quote_1"));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ASKS_V1.load(&deps.storage, ASK_ID.as_bytes()).unwrap(),
+            test_ask(1)
+        );
+    }
+
+    #[test]
+    fn create_bid_self_trade_reject_cancels_incoming_order() {
+        for order_type in [OrderType::ImmediateOrCancel, OrderType::FillOrKill] {
+            let mut deps = mock_provenance_dependencies();
+            setup_resting_ask(&mut deps.storage, SelfTradePrevention::Reject);
+            set_default_required_attributes(&mut deps.querier, "trader", false, true);
+
+            // the incoming bid doesn't fail, it's canceled as the newest order of the pair
+            match execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("trader", &coins(400, "quote_1")),
+                incoming_bid_msg(order_type.to_owned()),
+            ) {
+                Ok(response) => {
+                    assert!(response
+                        .attributes
+                        .contains(&attr("self_trade_prevention", "cancel_newest")));
+                    assert!(response.attributes.contains(&attr("action", "cancel_bid")));
+                    assert_eq!(response.messages.len(), 1, "{:?}", order_type);
+                    assert_eq!(response.messages[0].msg, refund(400, "quote_1"));
+                }
+                Err(error) => panic!("unexpected error: {:?}", error),
+            }
+
+            assert_eq!(
+                ASKS_V1.load(&deps.storage, ASK_ID.as_bytes()).unwrap(),
+                test_ask(1)
+            );
+            assert!(!BIDS_V3.has(&deps.storage, BID_ID.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn create_market_bid_self_trade_cancel_resting_fills_next_ask() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::CancelResting);
+        set_default_required_attributes(&mut deps.querier, "trader", false, true);
+        store_test_ask(
+            &mut deps.storage,
+            &AskOrderV1 {
+                id: ASK_ID_2.into(),
+                owner: Addr::unchecked("asker"),
+                ..test_ask(3)
+            },
+        );
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(400, "quote_1")),
+            market_bid_msg(400),
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("self_trade_prevention", "cancel_resting")));
+                assert!(response.attributes.contains(&attr("action", "cancel_ask")));
+                assert!(response.attributes.contains(&attr("filled_size", "200")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        refund(200, "base_1"),
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address: "asker".into(),
+                            amount: coins(400, "quote_1"),
+                        }),
+                        refund(200, "base_1"),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID.as_bytes()));
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID_2.as_bytes()));
+    }
+
+    #[test]
+    fn create_market_bid_self_trade_cancel_newest_refunds_market_bid() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::CancelNewest);
+        set_default_required_attributes(&mut deps.querier, "trader", false, true);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(400, "quote_1")),
+            market_bid_msg(400),
+        ) {
+            Ok(response) => {
+                assert!(response
+                    .attributes
+                    .contains(&attr("self_trade_prevention", "cancel_newest")));
+                assert!(response.attributes.contains(&attr("filled_size", "0")));
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(response.messages[0].msg, refund(400, "quote_1"));
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert_eq!(
+            ASKS_V1.load(&deps.storage, ASK_ID.as_bytes()).unwrap(),
+            test_ask(1)
+        );
+    }
+
+    #[test]
+    fn create_market_bid_self_trade_decrement_both_reduces_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_contract(&mut deps.storage, SelfTradePrevention::DecrementBoth);
+        set_default_required_attributes(&mut deps.querier, "trader", false, true);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("trader", &coins(600, "quote_1")),
+            market_bid_msg(600),
+        ) {
+            Ok(response) => {
+                assert!(response.attributes.contains(&attr("filled_size", "0")));
+                assert!(response.attributes.contains(&attr("quote_refund", "200")));
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|message| message.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    vec![
+                        refund(200, "base_1"),
+                        refund(400, "quote_1"),
+                        refund(200, "quote_1"),
+                    ]
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        assert!(!ASKS_V1.has(&deps.storage, ASK_ID.as_bytes()));
+    }
+}
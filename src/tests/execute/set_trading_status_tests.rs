@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod set_trading_status_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
+    use crate::bid_order::{BidOrderV3, BIDS_V3};
+    use crate::common::{ContractStatus, TradingStatus};
+    use crate::contract::{execute, query};
+    use crate::contract_info::ContractInfoV3;
+    use crate::error::ContractError;
+    use crate::msg::{BatchOp, ExecuteMsg, QueryMsg};
+    use crate::tests::test_constants::BASE_DENOM;
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{attr, coins, from_binary, Addr, Coin, DepsMut, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const BID_ID: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn set_trading_status(
+        deps: DepsMut,
+        status: Option<ContractStatus>,
+        asks_paused: Option<bool>,
+        bids_paused: Option<bool>,
+    ) {
+        if let Err(error) = execute(
+            deps,
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::SetTradingStatus {
+                status,
+                asks_paused,
+                bids_paused,
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    fn test_ask() -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: ASK_ID.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            sequence: 1,
+            ..Default::default()
+        }
+    }
+
+    fn test_bid() -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: BID_ID.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            sequence: 2,
+            ..Default::default()
+        }
+    }
+
+    fn create_ask_msg() -> ExecuteMsg {
+        ExecuteMsg::CreateAsk {
+            id: ASK_ID.into(),
+            base: BASE_DENOM.into(),
+            quote: "quote_1".into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+            order_type: None,
+            time_in_force: None,
+            expires_at: None,
+        }
+    }
+
+    fn execute_match_msg() -> ExecuteMsg {
+        ExecuteMsg::ExecuteMatch {
+            ask_id: ASK_ID.into(),
+            bid_id: BID_ID.into(),
+            price: "2".into(),
+            size: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn set_trading_status_shows_in_contract_info() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("contract_admin", &[]),
+            ExecuteMsg::SetTradingStatus {
+                status: Some(ContractStatus::CancelOnly),
+                asks_paused: None,
+                bids_paused: Some(true),
+            },
+        ) {
+            Ok(response) => assert_eq!(
+                response.attributes,
+                vec![
+                    attr("action", "set_trading_status"),
+                    attr("status", "cancel_only"),
+                    attr("asks_paused", "false"),
+                    attr("bids_paused", "true"),
+                ]
+            ),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        let contract_info: ContractInfoV3 =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::GetContractInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            contract_info.trading_status,
+            TradingStatus {
+                status: ContractStatus::CancelOnly,
+                asks_paused: false,
+                bids_paused: true,
+            }
+        );
+    }
+
+    #[test]
+    fn set_trading_status_not_admin_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::SetTradingStatus {
+                status: Some(ContractStatus::Halted),
+                asks_paused: None,
+                bids_paused: None,
+            },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::Unauthorized) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn cancel_only_rejects_matches_and_allows_cancels() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid());
+        set_trading_status(deps.as_mut(), Some(ContractStatus::CancelOnly), None, None);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::ContractStatusRestricted { status }) => {
+                assert_eq!(status, "cancel_only")
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // batched ops are checked the same way
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::Batch {
+                ops: vec![BatchOp::ExecuteMatch {
+                    ask_id: ASK_ID.into(),
+                    bid_id: BID_ID.into(),
+                    price: "2".into(),
+                    size: Uint128::new(100),
+                }],
+            },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::ContractStatusRestricted { .. }) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk { id: ASK_ID.into() },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("bidder", &[]),
+            ExecuteMsg::CancelBid { id: BID_ID.into() },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        assert!(BIDS_V3.is_empty(&deps.storage));
+    }
+
+    #[test]
+    fn halted_rejects_cancels() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask());
+        set_trading_status(deps.as_mut(), Some(ContractStatus::Halted), None, None);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk { id: ASK_ID.into() },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::ContractStatusRestricted { status }) => {
+                assert_eq!(status, "halted")
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // trading resumes once the contract is active again
+        set_trading_status(deps.as_mut(), Some(ContractStatus::Active), None, None);
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &[]),
+            ExecuteMsg::CancelAsk { id: ASK_ID.into() },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    #[test]
+    fn paused_side_rejects_new_orders_and_still_matches_resting_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask());
+        store_test_bid(&mut deps.storage, &test_bid());
+        set_trading_status(deps.as_mut(), None, Some(true), None);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker", &coins(100, BASE_DENOM)),
+            create_ask_msg(),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::OrdersPaused { side }) => assert_eq!(side, "ask"),
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        if let Err(error) = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("exec_1", &[]),
+            execute_match_msg(),
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+
+        assert!(ASKS_V1.is_empty(&deps.storage));
+        assert!(BIDS_V3.is_empty(&deps.storage));
+    }
+}
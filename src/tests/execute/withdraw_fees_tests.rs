@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod withdraw_fees_tests {
+    use crate::contract::execute;
+    use crate::error::ContractError;
+    use crate::fee_ledger::{accrue_fee, ACCRUED_FEES_V1};
+    use crate::msg::ExecuteMsg;
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
+    use crate::tests::test_utils::setup_restricted_asset_marker;
+    use crate::util::transfer_marker_coins;
+    use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{attr, coins, Addr, BankMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::marker::v1::QueryMarkerRequest;
+    use std::convert::TryInto;
+
+    fn withdraw_fees_msg(denom: &str) -> ExecuteMsg {
+        ExecuteMsg::WithdrawFees {
+            denom: denom.into(),
+        }
+    }
+
+    #[test]
+    fn withdraw_fees_sends_accrued_fees_to_fee_account() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        let fee_account = Addr::unchecked("fee_account");
+        accrue_fee(&mut deps.storage, &fee_account, "quote_1", Uint128::new(6)).unwrap();
+        accrue_fee(&mut deps.storage, &fee_account, "quote_1", Uint128::new(4)).unwrap();
+        accrue_fee(&mut deps.storage, &fee_account, "quote_2", Uint128::new(3)).unwrap();
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fee_account", &[]),
+            withdraw_fees_msg("quote_1"),
+        ) {
+            Ok(response) => {
+                assert_eq!(
+                    response.attributes,
+                    vec![
+                        attr("action", "withdraw_fees"),
+                        attr("account", "fee_account"),
+                        attr("denom", "quote_1"),
+                        attr("amount", "10"),
+                    ]
+                );
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(
+                    response.messages[0].msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: "fee_account".into(),
+                        amount: coins(10, "quote_1"),
+                    })
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+
+        // only the withdrawn denom is removed
+        assert!(!ACCRUED_FEES_V1.has(&deps.storage, (&fee_account, "quote_1")));
+        assert_eq!(
+            ACCRUED_FEES_V1
+                .load(&deps.storage, (&fee_account, "quote_2"))
+                .unwrap(),
+            Uint128::new(3)
+        );
+    }
+
+    #[test]
+    fn withdraw_fees_restricted_marker_transfers_accrued_fees() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        QueryMarkerRequest::mock_response(
+            &mut deps.querier,
+            setup_restricted_asset_marker(
+                "tp18vmzryrvwaeykmdtu6cfrz5sau3dhc5c73ms0u".to_string(),
+                "tp18vd8fpwxzck93qlwghaj6arh4p7c5n89x8kskz".to_string(),
+                "quote_1".to_string(),
+            ),
+        );
+        accrue_fee(
+            &mut deps.storage,
+            &Addr::unchecked("fee_account"),
+            "quote_1",
+            Uint128::new(10),
+        )
+        .unwrap();
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fee_account", &[]),
+            withdraw_fees_msg("quote_1"),
+        ) {
+            Ok(response) => {
+                assert_eq!(response.messages.len(), 1);
+                assert_eq!(
+                    response.messages[0].msg,
+                    transfer_marker_coins(
+                        10,
+                        "quote_1",
+                        Addr::unchecked("fee_account"),
+                        Addr::unchecked(MOCK_CONTRACT_ADDR),
+                        Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    )
+                    .unwrap()
+                    .try_into()
+                    .unwrap()
+                );
+            }
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn withdraw_fees_without_accrued_fees_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        accrue_fee(
+            &mut deps.storage,
+            &Addr::unchecked("fee_account"),
+            "quote_1",
+            Uint128::new(10),
+        )
+        .unwrap();
+
+        // fees are only withdrawn by the account they accrued to
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("other_account", &[]),
+            withdraw_fees_msg("quote_1"),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::NoAccruedFees) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn withdraw_fees_with_funds_returns_err() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        match execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("fee_account", &coins(1, "quote_1")),
+            withdraw_fees_msg("quote_1"),
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(ContractError::WithdrawWithFunds) => {}
+            Err(error) => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod instantiate_tests {
-    use crate::common::FeeInfo;
+    use crate::common::{AccountLimits, FeeInfo, FeePolicy, SelfTradePrevention};
     use crate::contract::instantiate;
     use crate::contract_info::{get_contract_info, ContractInfoV3};
     use crate::error::ContractError;
     use crate::msg::InstantiateMsg;
+    use crate::tests::test_setup_utils::all_of_attributes;
     use crate::version_info::{get_version_info, VersionInfoV1, CRATE_NAME, PACKAGE_VERSION};
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{attr, Addr, Uint128};
@@ -22,12 +23,28 @@ mod instantiate_tests {
             supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
             approvers: vec!["approver_1".into(), "approver_2".into()],
             executors: vec!["exec_1".into(), "exec_2".into()],
+            admin: None,
+            governance_delay: 0,
             ask_fee_rate: Some("0.01".into()),
             ask_fee_account: Some("ask_fee_account".into()),
             bid_fee_rate: Some("0.02".into()),
             bid_fee_account: Some("bid_fee_account".into()),
-            ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-            bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: vec![],
+            self_trade_prevention: SelfTradePrevention::default(),
+            price_band: None,
+            order_limits: vec![],
+            account_limits: AccountLimits::default(),
+            account_limits_overrides: vec![],
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: None,
+            ask_required_attributes: None,
+            // the deprecated list is stored as an all_of policy
+            bid_required_attributes: Some(vec!["bid_tag_1".into(), "bid_tag_2".into()]),
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(100),
         };
@@ -47,18 +64,22 @@ mod instantiate_tests {
                     supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
                     approvers: vec![Addr::unchecked("approver_1"), Addr::unchecked("approver_2")],
                     executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+                    admin: Some(Addr::unchecked("contract_admin")),
                     ask_fee_info: Some(FeeInfo {
                         account: Addr::unchecked("ask_fee_account"),
                         rate: "0.01".into(),
+                        policy: FeePolicy::default(),
                     }),
                     bid_fee_info: Some(FeeInfo {
                         account: Addr::unchecked("bid_fee_account"),
                         rate: "0.02".into(),
+                        policy: FeePolicy::default(),
                     }),
-                    ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-                    bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+                    ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+                    bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
                     price_precision: Uint128::new(2),
                     size_increment: Uint128::new(100),
+                    ..Default::default()
                 };
 
                 let expected_version_info = VersionInfoV1 {
@@ -98,12 +119,27 @@ mod instantiate_tests {
             supported_quote_denoms: vec![],
             approvers: vec![],
             executors: vec![],
+            admin: None,
+            governance_delay: 0,
             ask_fee_rate: None,
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: vec![],
-            bid_required_attributes: vec![],
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: vec![],
+            self_trade_prevention: SelfTradePrevention::default(),
+            price_band: None,
+            order_limits: vec![],
+            account_limits: AccountLimits::default(),
+            account_limits_overrides: vec![],
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+            ask_required_attributes: None,
+            bid_required_attributes: None,
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(100),
         };
@@ -138,12 +174,27 @@ mod instantiate_tests {
             supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
             approvers: vec!["approver_1".into(), "approver_2".into()],
             executors: vec!["exec_1".into(), "exec_2".into()],
+            admin: None,
+            governance_delay: 0,
             ask_fee_rate: None,
             ask_fee_account: None,
             bid_fee_rate: None,
             bid_fee_account: None,
-            ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-            bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+            ask_fee_policy: None,
+            bid_fee_policy: None,
+            maker_fee_rate: None,
+            taker_fee_rate: None,
+            maker_taker_fee_account: None,
+            fee_tiers: vec![],
+            self_trade_prevention: SelfTradePrevention::default(),
+            price_band: None,
+            order_limits: vec![],
+            account_limits: AccountLimits::default(),
+            account_limits_overrides: vec![],
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
+            ask_required_attributes: None,
+            bid_required_attributes: None,
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(10),
         };
@@ -1,2 +1,10 @@
+mod get_account_fee_tier_tests;
+mod get_accrued_fees_tests;
 mod get_ask_tests;
+mod get_asks_by_owner_tests;
 mod get_bid_tests;
+mod get_bids_by_owner_tests;
+mod get_order_book_depth_tests;
+mod get_order_fills_tests;
+mod list_asks_tests;
+mod list_bids_tests;
@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod get_account_fee_tier_tests {
+    use crate::common::FeeTier;
+    use crate::contract::query;
+    use crate::contract_info::{get_contract_info, set_contract_info};
+    use crate::msg::{AccountFeeTierResponse, QueryMsg};
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
+    use crate::volume::record_volume;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Deps, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn query_fee_tier(deps: Deps, address: &str) -> AccountFeeTierResponse {
+        from_binary(
+            &query(
+                deps,
+                mock_env(),
+                QueryMsg::GetAccountFeeTier {
+                    address: address.into(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_account_fee_tier_returns_highest_tier_reached() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let fee_tier_1 = FeeTier {
+            volume_threshold: Uint128::new(1000),
+            ask_rate: "0.02".into(),
+            bid_rate: "0.02".into(),
+        };
+        let fee_tier_2 = FeeTier {
+            volume_threshold: Uint128::new(5000),
+            ask_rate: "0.01".into(),
+            bid_rate: "0.01".into(),
+        };
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.fee_tiers = vec![fee_tier_1.to_owned(), fee_tier_2];
+        set_contract_info(&mut deps.storage, &contract_info).unwrap();
+
+        record_volume(
+            &mut deps.storage,
+            &Addr::unchecked("trader"),
+            mock_env().block.time,
+            Uint128::new(1500),
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_fee_tier(deps.as_ref(), "trader"),
+            AccountFeeTierResponse {
+                volume: Uint128::new(1500),
+                fee_tier: Some(fee_tier_1),
+            }
+        );
+        assert_eq!(
+            query_fee_tier(deps.as_ref(), "other_trader"),
+            AccountFeeTierResponse {
+                volume: Uint128::zero(),
+                fee_tier: None,
+            }
+        );
+    }
+}
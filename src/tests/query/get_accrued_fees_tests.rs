@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod get_accrued_fees_tests {
+    use crate::contract::query;
+    use crate::fee_ledger::{accrue_fee, AccruedFee};
+    use crate::msg::{AccruedFeesResponse, QueryMsg};
+    use crate::tests::test_setup_utils::setup_test_base_contract_v3;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_binary, Addr, Deps, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn query_accrued_fees(deps: Deps, account: Option<&str>) -> AccruedFeesResponse {
+        from_binary(
+            &query(
+                deps,
+                mock_env(),
+                QueryMsg::GetAccruedFees {
+                    account: account.map(|account| account.into()),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_accrued_fees_by_account_and_for_all_accounts() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let ask_fee_account = Addr::unchecked("ask_fee_account");
+        let bid_fee_account = Addr::unchecked("bid_fee_account");
+        accrue_fee(
+            &mut deps.storage,
+            &ask_fee_account,
+            "quote_1",
+            Uint128::new(5),
+        )
+        .unwrap();
+        accrue_fee(
+            &mut deps.storage,
+            &ask_fee_account,
+            "quote_2",
+            Uint128::new(2),
+        )
+        .unwrap();
+        accrue_fee(
+            &mut deps.storage,
+            &bid_fee_account,
+            "quote_1",
+            Uint128::new(7),
+        )
+        .unwrap();
+
+        assert_eq!(
+            query_accrued_fees(deps.as_ref(), Some("ask_fee_account")),
+            AccruedFeesResponse {
+                fees: vec![
+                    AccruedFee {
+                        account: ask_fee_account.to_owned(),
+                        amount: coin(5, "quote_1"),
+                    },
+                    AccruedFee {
+                        account: ask_fee_account.to_owned(),
+                        amount: coin(2, "quote_2"),
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            query_accrued_fees(deps.as_ref(), None).fees,
+            vec![
+                AccruedFee {
+                    account: ask_fee_account.to_owned(),
+                    amount: coin(5, "quote_1"),
+                },
+                AccruedFee {
+                    account: ask_fee_account,
+                    amount: coin(2, "quote_2"),
+                },
+                AccruedFee {
+                    account: bid_fee_account,
+                    amount: coin(7, "quote_1"),
+                },
+            ]
+        );
+        assert_eq!(
+            query_accrued_fees(deps.as_ref(), Some("other_account")),
+            AccruedFeesResponse { fees: vec![] }
+        );
+    }
+}
@@ -24,6 +24,7 @@ mod get_ask_tests {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(200),
+            ..Default::default()
         };
 
         if let Err(error) =
@@ -64,6 +65,7 @@ mod get_ask_tests {
             price: "2".into(),
             quote: "quote_1".into(),
             size: Uint128::new(200),
+            ..Default::default()
         };
         if let Err(error) = ASKS_V1.save(
             &mut deps.storage,
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod get_asks_by_owner_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::contract::{execute, query};
+    use crate::msg::{ExecuteMsg, ListAsksResponse, QueryMsg};
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_ask};
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "4d5e6f70-1a2b-4c3d-8e9f-000000000001";
+    const ASK_ID_2: &str = "4d5e6f70-1a2b-4c3d-8e9f-000000000002";
+    const ASK_ID_3: &str = "4d5e6f70-1a2b-4c3d-8e9f-000000000003";
+
+    fn test_ask(id: &str, owner: &str) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked(owner),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(200),
+            ..Default::default()
+        }
+    }
+
+    fn query_asks_by_owner(
+        deps: cosmwasm_std::Deps,
+        owner: &str,
+        start_after: Option<String>,
+    ) -> ListAsksResponse {
+        from_binary(
+            &query(
+                deps,
+                mock_env(),
+                QueryMsg::GetAsksByOwner {
+                    owner: owner.into(),
+                    start_after,
+                    limit: Some(1),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_asks_by_owner_then_return_only_owned_orders() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "asker_1"));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "asker_2"));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_3, "asker_1"));
+
+        // first page
+        let response = query_asks_by_owner(deps.as_ref(), "asker_1", None);
+        assert_eq!(
+            response,
+            ListAsksResponse {
+                asks: vec![test_ask(ASK_ID_1, "asker_1")],
+                next_start_after: Some(ASK_ID_1.into()),
+            }
+        );
+
+        // second page
+        let response = query_asks_by_owner(deps.as_ref(), "asker_1", response.next_start_after);
+        assert_eq!(
+            response,
+            ListAsksResponse {
+                asks: vec![test_ask(ASK_ID_3, "asker_1")],
+                next_start_after: Some(ASK_ID_3.into()),
+            }
+        );
+
+        // exhausted
+        let response = query_asks_by_owner(deps.as_ref(), "asker_1", response.next_start_after);
+        assert_eq!(response.asks, vec![]);
+        assert_eq!(response.next_start_after, None);
+    }
+
+    #[test]
+    fn get_asks_by_owner_after_cancel_then_order_removed_from_index() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "asker_1"));
+
+        // cancel the ask
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("asker_1", &[]),
+            ExecuteMsg::CancelAsk {
+                id: ASK_ID_1.into(),
+            },
+        )
+        .unwrap();
+
+        let response = query_asks_by_owner(deps.as_ref(), "asker_1", None);
+        assert_eq!(response.asks, vec![]);
+    }
+}
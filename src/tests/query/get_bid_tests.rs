@@ -32,6 +32,7 @@ mod get_bid_tests {
                 amount: Uint128::new(100),
                 denom: "quote_1".into(),
             },
+            ..Default::default()
         };
 
         if let Err(error) = BIDS_V3.save(&mut deps.storage, bid_order.id.as_bytes(), &bid_order) {
@@ -73,6 +74,7 @@ mod get_bid_tests {
                 amount: Uint128::new(100),
                 denom: "quote_1".into(),
             },
+            ..Default::default()
         };
 
         if let Err(error) = BIDS_V3.save(&mut deps.storage, bid_order.id.as_bytes(), &bid_order) {
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod get_bids_by_owner_tests {
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::query;
+    use crate::error::ContractError;
+    use crate::msg::{ListBidsResponse, QueryMsg};
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_bid};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Coin, StdError, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const BID_ID_1: &str = "9a8b7c6d-1a2b-4c3d-8e9f-000000000001";
+    const BID_ID_2: &str = "9a8b7c6d-1a2b-4c3d-8e9f-000000000002";
+
+    fn test_bid(id: &str, owner: &str) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked(owner),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn get_bids_by_owner_then_return_only_owned_orders() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_1, "bidder_1"));
+        store_test_bid(&mut deps.storage, &test_bid(BID_ID_2, "bidder_2"));
+
+        let response: ListBidsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetBidsByOwner {
+                    owner: "bidder_2".into(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            ListBidsResponse {
+                bids: vec![test_bid(BID_ID_2, "bidder_2")],
+                next_start_after: None,
+            }
+        );
+    }
+
+    #[test]
+    fn get_bids_by_owner_empty_owner_then_return_invalid_fields() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let query_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetBidsByOwner {
+                owner: "".into(),
+                start_after: None,
+                limit: None,
+            },
+        );
+
+        match query_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => assert_eq!(
+                error,
+                StdError::from(ContractError::InvalidFields {
+                    fields: vec!["owner".into()],
+                })
+            ),
+        }
+    }
+}
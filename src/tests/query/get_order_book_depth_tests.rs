@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod get_order_book_depth_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderStatus, AskOrderV1};
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::query;
+    use crate::msg::{OrderBookDepthResponse, PriceLevel, QueryMsg};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Coin, Deps, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn test_ask(id: &str, quote: &str, price: &str, size: u128) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_denom".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: quote.into(),
+            size: Uint128::new(size),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid(id: &str, price: &str, size: u128, accumulated_base: u128) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(size),
+                denom: "base_denom".into(),
+            },
+            accumulated_base: Uint128::new(accumulated_base),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: price.into(),
+            quote: Coin {
+                amount: Uint128::new(1000),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn query_depth(deps: Deps, levels: u32) -> OrderBookDepthResponse {
+        from_binary(
+            &query(
+                deps,
+                mock_env(),
+                QueryMsg::GetOrderBookDepth {
+                    quote: "quote_1".into(),
+                    levels,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_order_book_depth_then_return_levels_sorted_from_best_price() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        store_test_ask(&mut deps.storage, &test_ask("ask-1", "quote_1", "3", 100));
+        store_test_ask(&mut deps.storage, &test_ask("ask-2", "quote_1", "2.5", 200));
+        store_test_ask(
+            &mut deps.storage,
+            &test_ask("ask-3", "quote_1", "2.50", 300),
+        );
+        store_test_ask(&mut deps.storage, &test_ask("ask-4", "quote_2", "1", 400));
+
+        store_test_bid(&mut deps.storage, &test_bid("bid-1", "1", 100, 0));
+        store_test_bid(&mut deps.storage, &test_bid("bid-2", "2", 500, 200));
+        store_test_bid(&mut deps.storage, &test_bid("bid-3", "1.5", 100, 0));
+
+        let response = query_depth(deps.as_ref(), 10);
+
+        assert_eq!(
+            response,
+            OrderBookDepthResponse {
+                quote: "quote_1".into(),
+                asks: vec![
+                    PriceLevel {
+                        price: "2.5".into(),
+                        size: Uint128::new(500),
+                        order_count: 2,
+                    },
+                    PriceLevel {
+                        price: "3".into(),
+                        size: Uint128::new(100),
+                        order_count: 1,
+                    },
+                ],
+                bids: vec![
+                    PriceLevel {
+                        price: "2".into(),
+                        size: Uint128::new(300),
+                        order_count: 1,
+                    },
+                    PriceLevel {
+                        price: "1.5".into(),
+                        size: Uint128::new(100),
+                        order_count: 1,
+                    },
+                    PriceLevel {
+                        price: "1".into(),
+                        size: Uint128::new(100),
+                        order_count: 1,
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn get_order_book_depth_then_limit_levels_and_skip_unapproved_asks() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let mut pending_ask = test_ask("ask-1", "quote_1", "1", 100);
+        pending_ask.base = "con_base_1".into();
+        pending_ask.class = AskOrderClass::Convertible {
+            status: AskOrderStatus::PendingIssuerApproval,
+        };
+        store_test_ask(&mut deps.storage, &pending_ask);
+        store_test_ask(&mut deps.storage, &test_ask("ask-2", "quote_1", "2", 200));
+        store_test_ask(&mut deps.storage, &test_ask("ask-3", "quote_1", "3", 300));
+
+        let response = query_depth(deps.as_ref(), 1);
+
+        assert_eq!(
+            response.asks,
+            vec![PriceLevel {
+                price: "2".into(),
+                size: Uint128::new(200),
+                order_count: 1,
+            }]
+        );
+        assert_eq!(response.bids, vec![]);
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod get_order_fills_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::bid_order::BidOrderV3;
+    use crate::common::Action;
+    use crate::contract::{execute, query};
+    use crate::error::ContractError;
+    use crate::msg::{ExecuteMsg, FillsResponse, QueryMsg};
+    use crate::tests::test_setup_utils::{
+        setup_test_base_contract_v3, store_test_ask, store_test_bid,
+    };
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Addr, Coin, Deps, DepsMut, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_ID_1: &str = "ab5f5a62-f6fc-46d1-aa84-51ccc51ec367";
+    const ASK_ID_2: &str = "0c2c4a2f-0f4e-4e0d-8f3a-25e9e3d4a8a1";
+    const BID_ID_1: &str = "c13f8888-ca43-4a64-ab1b-1ca8d60aa49b";
+
+    fn test_ask(id: &str, price: &str) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: price.into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(100),
+            ..Default::default()
+        }
+    }
+
+    fn test_bid() -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(200),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: BID_ID_1.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "3".into(),
+            quote: Coin {
+                amount: Uint128::new(600),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn execute_match(deps: DepsMut, ask_id: &str, price: &str) {
+        if let Err(error) = execute(
+            deps,
+            mock_env(),
+            mock_info("exec_1", &[]),
+            ExecuteMsg::ExecuteMatch {
+                ask_id: ask_id.into(),
+                bid_id: BID_ID_1.into(),
+                price: price.into(),
+                size: Uint128::new(100),
+            },
+        ) {
+            panic!("unexpected error: {:?}", error)
+        }
+    }
+
+    fn query_fills(deps: Deps, msg: QueryMsg) -> FillsResponse {
+        from_binary(&query(deps, mock_env(), msg).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn get_order_fills_returns_fills_of_removed_orders() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2"));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "3"));
+        store_test_bid(&mut deps.storage, &test_bid());
+
+        execute_match(deps.as_mut(), ASK_ID_1, "2");
+        execute_match(deps.as_mut(), ASK_ID_2, "3");
+
+        // the ask was removed on full fill, its fill is still logged
+        let ask_fills = query_fills(
+            deps.as_ref(),
+            QueryMsg::GetOrderFills {
+                id: ASK_ID_1.into(),
+            },
+        );
+        assert_eq!(ask_fills.fills.len(), 1);
+        assert_eq!(ask_fills.fills[0].ask_id, ASK_ID_1);
+        assert_eq!(ask_fills.fills[0].bid_id, BID_ID_1);
+        assert_eq!(
+            ask_fills.fills[0].event.block_info.height,
+            mock_env().block.height
+        );
+        assert_eq!(
+            ask_fills.fills[0].event.action,
+            Action::Fill {
+                base: coin(100, "base_1"),
+                fee: None,
+                price: "2".into(),
+                quote: coin(200, "quote_1"),
+            }
+        );
+
+        let bid_fills = query_fills(
+            deps.as_ref(),
+            QueryMsg::GetOrderFills {
+                id: BID_ID_1.into(),
+            },
+        );
+        assert_eq!(
+            bid_fills
+                .fills
+                .iter()
+                .map(|fill| fill.ask_id.as_str())
+                .collect::<Vec<_>>(),
+            vec![ASK_ID_1, ASK_ID_2]
+        );
+    }
+
+    #[test]
+    fn get_order_fills_unknown_order_returns_empty() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let fills = query_fills(
+            deps.as_ref(),
+            QueryMsg::GetOrderFills {
+                id: ASK_ID_1.into(),
+            },
+        );
+        assert!(fills.fills.is_empty());
+    }
+
+    #[test]
+    fn get_recent_trades_returns_newest_first() {
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_1, "2"));
+        store_test_ask(&mut deps.storage, &test_ask(ASK_ID_2, "3"));
+        store_test_bid(&mut deps.storage, &test_bid());
+
+        execute_match(deps.as_mut(), ASK_ID_1, "2");
+        execute_match(deps.as_mut(), ASK_ID_2, "3");
+
+        let trades = query_fills(deps.as_ref(), QueryMsg::GetRecentTrades { limit: None });
+        assert_eq!(
+            trades
+                .fills
+                .iter()
+                .map(|fill| fill.sequence)
+                .collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        let trades = query_fills(deps.as_ref(), QueryMsg::GetRecentTrades { limit: Some(1) });
+        assert_eq!(trades.fills.len(), 1);
+        assert_eq!(trades.fills[0].ask_id, ASK_ID_2);
+    }
+
+    #[test]
+    fn get_recent_trades_invalid_limit_returns_err() {
+        let deps = mock_provenance_dependencies();
+
+        match query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::GetRecentTrades { limit: Some(0) },
+        ) {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => assert_eq!(
+                error.to_string(),
+                format!(
+                    "Generic error: {}",
+                    ContractError::InvalidFields {
+                        fields: vec!["limit".into()],
+                    }
+                )
+            ),
+        }
+    }
+}
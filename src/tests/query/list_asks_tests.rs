@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod list_asks_tests {
+    use crate::ask_order::{AskOrderClass, AskOrderV1};
+    use crate::contract::query;
+    use crate::error::ContractError;
+    use crate::msg::{ListAsksResponse, QueryMsg};
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_ask};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, StdError, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    const ASK_IDS: [&str; 3] = [
+        "0a8e7b0c-7e0d-4c6b-9b9a-4b1a6c3f6b01",
+        "0a8e7b0c-7e0d-4c6b-9b9a-4b1a6c3f6b02",
+        "0a8e7b0c-7e0d-4c6b-9b9a-4b1a6c3f6b03",
+    ];
+
+    fn test_ask(id: &str) -> AskOrderV1 {
+        AskOrderV1 {
+            base: "base_1".into(),
+            class: AskOrderClass::Basic,
+            id: id.into(),
+            owner: Addr::unchecked("asker"),
+            price: "2".into(),
+            quote: "quote_1".into(),
+            size: Uint128::new(200),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn list_asks_without_cursor_then_return_first_page_and_next_cursor() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        for id in ASK_IDS {
+            store_test_ask(&mut deps.storage, &test_ask(id));
+        }
+
+        // query for the first page
+        let response: ListAsksResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListAsks {
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            ListAsksResponse {
+                asks: vec![test_ask(ASK_IDS[0]), test_ask(ASK_IDS[1])],
+                next_start_after: Some(ASK_IDS[1].into()),
+            }
+        );
+    }
+
+    #[test]
+    fn list_asks_with_cursor_then_return_last_page_without_next_cursor() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        for id in ASK_IDS {
+            store_test_ask(&mut deps.storage, &test_ask(id));
+        }
+
+        // query for the page after the second ask
+        let response: ListAsksResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListAsks {
+                    start_after: Some(ASK_IDS[1].into()),
+                    limit: Some(2),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            ListAsksResponse {
+                asks: vec![test_ask(ASK_IDS[2])],
+                next_start_after: None,
+            }
+        );
+    }
+
+    #[test]
+    fn list_asks_empty_book_then_return_empty_page() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let response: ListAsksResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListAsks {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            ListAsksResponse {
+                asks: vec![],
+                next_start_after: None,
+            }
+        );
+    }
+
+    #[test]
+    fn list_asks_invalid_params_then_return_invalid_fields() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+
+        let query_response = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListAsks {
+                start_after: Some("not-an-id".into()),
+                limit: Some(0),
+            },
+        );
+
+        match query_response {
+            Ok(_) => panic!("expected error, but ok"),
+            Err(error) => assert_eq!(
+                error,
+                StdError::from(ContractError::InvalidFields {
+                    fields: vec!["start_after".into(), "limit".into()],
+                })
+            ),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod list_bids_tests {
+    use crate::bid_order::BidOrderV3;
+    use crate::contract::query;
+    use crate::msg::{ListBidsResponse, QueryMsg};
+    use crate::query::list_orders::MAX_LIST_LIMIT;
+    use crate::tests::test_setup_utils::{setup_test_base_contract_v3, store_test_bid};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_binary, Addr, Coin, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn test_bid(id: &str) -> BidOrderV3 {
+        BidOrderV3 {
+            base: Coin {
+                amount: Uint128::new(100),
+                denom: "base_1".into(),
+            },
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: id.into(),
+            owner: Addr::unchecked("bidder"),
+            price: "2".into(),
+            quote: Coin {
+                amount: Uint128::new(200),
+                denom: "quote_1".into(),
+            },
+            ..Default::default()
+        }
+    }
+
+    fn test_bid_id(index: u32) -> String {
+        format!("c13f8888-ca43-4a64-ab1b-1ca8d60a{:04}", index)
+    }
+
+    #[test]
+    fn list_bids_walk_all_pages_then_return_every_bid_once() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        for index in 0..5 {
+            store_test_bid(&mut deps.storage, &test_bid(&test_bid_id(index)));
+        }
+
+        // follow the cursor until exhausted
+        let mut listed: Vec<BidOrderV3> = vec![];
+        let mut start_after = None;
+        loop {
+            let response: ListBidsResponse = from_binary(
+                &query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::ListBids {
+                        start_after,
+                        limit: Some(2),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            listed.extend(response.bids);
+            match response.next_start_after {
+                None => break,
+                next => start_after = next,
+            }
+        }
+
+        assert_eq!(
+            listed,
+            (0..5)
+                .map(|index| test_bid(&test_bid_id(index)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn list_bids_limit_above_max_then_clamp_to_max() {
+        // setup
+        let mut deps = mock_provenance_dependencies();
+        setup_test_base_contract_v3(&mut deps.storage);
+        for index in 0..(MAX_LIST_LIMIT + 1) {
+            store_test_bid(&mut deps.storage, &test_bid(&test_bid_id(index)));
+        }
+
+        let response: ListBidsResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::ListBids {
+                    start_after: None,
+                    limit: Some(MAX_LIST_LIMIT * 2),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(response.bids.len(), MAX_LIST_LIMIT as usize);
+        assert_eq!(
+            response.next_start_after,
+            Some(test_bid_id(MAX_LIST_LIMIT - 1))
+        );
+    }
+}
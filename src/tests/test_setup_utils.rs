@@ -1,13 +1,87 @@
-use crate::ask_order::{AskOrderV1, ASKS_V1};
+use crate::ask_order::{AskOrderClass, AskOrderV1, ASKS_V1};
 use crate::bid_order::{BidOrderV3, BIDS_V3};
+use crate::common::{
+    AccountLimits, AttributePolicy, SelfTradePrevention, TimeInForce, TradingStatus,
+};
 use crate::contract_info::{set_contract_info, ContractInfoV3};
 use crate::tests::test_constants::{APPROVER_1, APPROVER_2, BASE_DENOM};
-use cosmwasm_std::{Addr, Storage, Uint128};
+use cosmwasm_std::{Addr, Coin, Storage, Uint128};
 use provwasm_mocks::MockProvenanceQuerier;
 use provwasm_std::types::provenance::attribute::v1::{
     Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
 };
 
+// Tests set the fields they exercise and take the rest from these defaults
+
+impl Default for AskOrderV1 {
+    fn default() -> Self {
+        AskOrderV1 {
+            id: String::default(),
+            owner: Addr::unchecked(""),
+            class: AskOrderClass::Basic,
+            base: String::default(),
+            quote: String::default(),
+            price: String::default(),
+            size: Uint128::zero(),
+            sequence: 0,
+            time_in_force: TimeInForce::default(),
+            expires_at: None,
+            fee_terms: None,
+        }
+    }
+}
+
+impl Default for BidOrderV3 {
+    fn default() -> Self {
+        BidOrderV3 {
+            base: Coin::default(),
+            accumulated_base: Uint128::zero(),
+            accumulated_quote: Uint128::zero(),
+            accumulated_fee: Uint128::zero(),
+            fee: None,
+            id: String::default(),
+            owner: Addr::unchecked(""),
+            price: String::default(),
+            quote: Coin::default(),
+            sequence: 0,
+            time_in_force: TimeInForce::default(),
+            expires_at: None,
+            fee_terms: None,
+        }
+    }
+}
+
+impl Default for ContractInfoV3 {
+    fn default() -> Self {
+        ContractInfoV3 {
+            name: String::default(),
+            bind_name: String::default(),
+            base_denom: String::default(),
+            convertible_base_denoms: vec![],
+            supported_quote_denoms: vec![],
+            approvers: vec![],
+            executors: vec![],
+            admin: None,
+            pending_admin: None,
+            governance_delay: 0,
+            ask_fee_info: None,
+            bid_fee_info: None,
+            maker_taker_fee_info: None,
+            fee_tiers: vec![],
+            trading_status: TradingStatus::default(),
+            self_trade_prevention: SelfTradePrevention::default(),
+            price_band: None,
+            order_limits: vec![],
+            account_limits: AccountLimits::default(),
+            account_limits_overrides: vec![],
+            ask_attribute_policy: None,
+            bid_attribute_policy: None,
+            price_precision: Uint128::zero(),
+            size_increment: Uint128::zero(),
+        }
+    }
+}
+
 pub fn setup_test_base(storage: &mut dyn Storage, contract_info: &ContractInfoV3) {
     if let Err(error) = set_contract_info(storage, contract_info) {
         panic!("unexpected error: {:?}", error)
@@ -25,16 +99,31 @@ pub fn setup_test_base_contract_v3(storage: &mut dyn Storage) {
             supported_quote_denoms: vec!["quote_1".into(), "quote_2".into()],
             approvers: vec![Addr::unchecked(APPROVER_1), Addr::unchecked(APPROVER_2)],
             executors: vec![Addr::unchecked("exec_1"), Addr::unchecked("exec_2")],
+            admin: Some(Addr::unchecked("contract_admin")),
             ask_fee_info: None,
             bid_fee_info: None,
-            ask_required_attributes: vec!["ask_tag_1".into(), "ask_tag_2".into()],
-            bid_required_attributes: vec!["bid_tag_1".into(), "bid_tag_2".into()],
+            ask_attribute_policy: Some(all_of_attributes(&["ask_tag_1", "ask_tag_2"])),
+            bid_attribute_policy: Some(all_of_attributes(&["bid_tag_1", "bid_tag_2"])),
             price_precision: Uint128::new(2),
             size_increment: Uint128::new(100),
+            ..Default::default()
         },
     );
 }
 
+/// Returns an attribute policy requiring all of the named attributes, with any value
+pub fn all_of_attributes(names: &[&str]) -> AttributePolicy {
+    AttributePolicy::AllOf(
+        names
+            .iter()
+            .map(|name| AttributePolicy::Attribute {
+                name: name.to_string(),
+                values: None,
+            })
+            .collect(),
+    )
+}
+
 pub fn store_test_ask(storage: &mut dyn Storage, ask_order: &AskOrderV1) {
     if let Err(error) = ASKS_V1.save(storage, ask_order.id.as_bytes(), ask_order) {
         panic!("unexpected error: {:?}", error)
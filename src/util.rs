@@ -1,15 +1,20 @@
+use crate::common::AttributePolicy;
 use crate::error::ContractError;
 use cosmwasm_std::{
-    coins, Addr, BankMsg, Empty, QuerierWrapper, Response, StdError, StdResult, Uint128,
+    coins, Addr, BankMsg, Binary, CosmosMsg, Empty, QuerierWrapper, ReplyOn, Response, StdError,
+    StdResult, SubMsg, Timestamp, Uint128,
 };
+use provwasm_std::shim;
 use provwasm_std::types::cosmos::base::v1beta1::Coin;
-use provwasm_std::types::provenance::attribute::v1::{Attribute, AttributeQuerier};
+use provwasm_std::types::provenance::attribute::v1::QueryAttributesRequest;
 use provwasm_std::types::provenance::marker::v1::{
     MarkerAccount, MarkerQuerier, MsgTransferRequest,
 };
-use rust_decimal::prelude::Zero;
+use rust_decimal::prelude::{ToPrimitive, Zero};
 use rust_decimal::Decimal;
+use serde::Deserialize;
 use std::convert::TryFrom;
+use std::str::FromStr;
 use uuid::Uuid;
 
 pub fn is_restricted_marker(querier: &QuerierWrapper, denom: String) -> bool {
@@ -35,14 +40,124 @@ fn get_marker(id: String, querier: &MarkerQuerier<Empty>) -> StdResult<MarkerAcc
     }
 }
 
+/// An account attribute from the attribute module's `Attributes` query. provwasm-std's `Attribute`
+/// drops the expiration date, so the query response is read into this type instead.
+#[derive(Deserialize)]
+pub struct AccountAttribute {
+    pub name: String,
+    pub value: Binary,
+    #[serde(default)]
+    pub expiration_date: Option<shim::Timestamp>,
+}
+
+impl AccountAttribute {
+    pub fn is_expired(&self, time: Timestamp) -> bool {
+        match &self.expiration_date {
+            Some(expiration_date) => {
+                (expiration_date.seconds, expiration_date.nanos)
+                    <= (time.seconds() as i64, time.subsec_nanos() as i32)
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct AccountAttributesResponse {
+    attributes: Vec<AccountAttribute>,
+}
+
 pub fn get_attributes(
     account: String,
-    querier: &AttributeQuerier<Empty>,
-) -> StdResult<Vec<Attribute>> {
-    return match querier.attributes(account, None) {
-        Ok(response) => Ok(response.attributes),
-        Err(error) => Err(error),
+    querier: &QuerierWrapper,
+) -> StdResult<Vec<AccountAttribute>> {
+    let response: AccountAttributesResponse = querier.query(
+        &QueryAttributesRequest {
+            account,
+            pagination: None,
+        }
+        .into(),
+    )?;
+    Ok(response.attributes)
+}
+
+/// Errors unless the account satisfies the attribute policy, naming the first clause that fails.
+///
+/// Attributes are matched by name and string value. Attributes whose expiration date is at or
+/// before `time` are ignored.
+pub fn check_account_attributes(
+    querier: &QuerierWrapper,
+    account: &Addr,
+    time: Timestamp,
+    attribute_policy: &Option<AttributePolicy>,
+) -> Result<(), ContractError> {
+    let attribute_policy = match attribute_policy {
+        Some(attribute_policy) => attribute_policy,
+        None => return Ok(()),
+    };
+
+    let attributes: Vec<AccountAttribute> = get_attributes(account.to_string(), querier)?
+        .into_iter()
+        .filter(|attribute| !attribute.is_expired(time))
+        .collect();
+
+    evaluate_attribute_policy(attribute_policy, &attributes, "")
+        .map_err(|clause| ContractError::AttributePolicyFailed { clause })
+}
+
+/// Evaluates an attribute policy clause against an account's attributes, returning the path of
+/// the first clause that fails, such as `all_of[1].any_of`.
+fn evaluate_attribute_policy(
+    attribute_policy: &AttributePolicy,
+    attributes: &[AccountAttribute],
+    parent: &str,
+) -> Result<(), String> {
+    let path = match parent {
+        "" => attribute_policy.get_clause_name(),
+        parent => format!("{}.{}", parent, attribute_policy.get_clause_name()),
     };
+    let item_parent = |index: usize| format!("{}[{}]", path, index);
+
+    match attribute_policy {
+        AttributePolicy::Attribute { name, values } => {
+            let is_match = attributes.iter().any(|attribute| {
+                attribute.name.eq(name)
+                    && match values {
+                        Some(values) => matches!(
+                            std::str::from_utf8(&attribute.value),
+                            Ok(value) if values.iter().any(|item| item.eq(value))
+                        ),
+                        None => true,
+                    }
+            });
+            match is_match {
+                true => Ok(()),
+                false => Err(path),
+            }
+        }
+        AttributePolicy::AllOf(items) => {
+            for (index, item) in items.iter().enumerate() {
+                evaluate_attribute_policy(item, attributes, &item_parent(index))?;
+            }
+            Ok(())
+        }
+        AttributePolicy::AnyOf(items) => {
+            match items.iter().enumerate().any(|(index, item)| {
+                evaluate_attribute_policy(item, attributes, &item_parent(index)).is_ok()
+            }) {
+                true => Ok(()),
+                false => Err(path),
+            }
+        }
+        AttributePolicy::NoneOf(items) => {
+            for (index, item) in items.iter().enumerate() {
+                if evaluate_attribute_policy(item, attributes, &item_parent(index)).is_ok() {
+                    return Err(format!("{}.{}", item_parent(index), item.get_clause_name()));
+                }
+            }
+            Ok(())
+        }
+    }
 }
 
 pub fn transfer_marker_coins<S: Into<String>, H: Into<Addr>>(
@@ -104,6 +219,158 @@ pub fn is_invalid_price_precision(price: Decimal, price_precision: Uint128) -> b
         .ne(&Decimal::zero())
 }
 
+/// Parses an order price, erroring if it is not a positive decimal within the price precision.
+pub fn parse_order_price(price: &str, price_precision: Uint128) -> Result<Decimal, ContractError> {
+    let order_price = Decimal::from_str(price).map_err(|_| ContractError::InvalidFields {
+        fields: vec![String::from("price")],
+    })?;
+
+    if order_price.is_zero() || order_price.is_sign_negative() {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("price")],
+        });
+    }
+
+    // error if price smaller than allow price precision
+    if is_invalid_price_precision(order_price, price_precision) {
+        return Err(ContractError::InvalidFields {
+            fields: vec![String::from("price")],
+        });
+    }
+
+    Ok(order_price)
+}
+
+/// Converts a price into a fixed width key whose byte order matches the price order.
+///
+/// The key is the 16 byte big-endian integer part followed by the 8 byte big-endian fractional
+/// part scaled to 18 decimal places, the maximum supported price precision.
+pub fn to_price_key(price: Decimal) -> Vec<u8> {
+    let integer_part = price.trunc().to_u128().unwrap_or_default();
+    let fractional_part = price
+        .fract()
+        .checked_mul(Decimal::from(10u64.pow(18)))
+        .and_then(|fract| fract.trunc().to_u64())
+        .unwrap_or_default();
+
+    let mut key = integer_part.to_be_bytes().to_vec();
+    key.extend_from_slice(&fractional_part.to_be_bytes());
+    key
+}
+
+/// Converts a price into a key whose byte order is the reverse of the price order.
+pub fn to_descending_price_key(price: Decimal) -> Vec<u8> {
+    to_price_key(price).into_iter().map(|byte| !byte).collect()
+}
+
+/// A transfer of a single denom from the contract, by bank send or by marker transfer.
+#[derive(PartialEq)]
+struct Transfer {
+    marker_from_and_administrator: Option<(String, String)>,
+    to: String,
+    denom: String,
+    amount: u128,
+}
+
+impl Transfer {
+    fn from_message(message: &SubMsg) -> StdResult<Option<Transfer>> {
+        if message.reply_on.ne(&ReplyOn::Never) {
+            return Ok(None);
+        }
+
+        match &message.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) if amount.len() == 1 => {
+                Ok(Some(Transfer {
+                    marker_from_and_administrator: None,
+                    to: to_address.to_owned(),
+                    denom: amount[0].denom.to_owned(),
+                    amount: amount[0].amount.u128(),
+                }))
+            }
+            CosmosMsg::Stargate { type_url, value }
+                if type_url.eq(MsgTransferRequest::TYPE_URL) =>
+            {
+                let request = MsgTransferRequest::try_from(value.to_owned())?;
+                match request.amount {
+                    Some(coin) => Ok(Some(Transfer {
+                        marker_from_and_administrator: Some((
+                            request.from_address,
+                            request.administrator,
+                        )),
+                        to: request.to_address,
+                        denom: coin.denom,
+                        amount: u128::from_str(&coin.amount)
+                            .map_err(|_| StdError::generic_err("invalid marker transfer amount"))?,
+                    })),
+                    None => Ok(None),
+                }
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn is_same_transfer(&self, other: &Transfer) -> bool {
+        self.marker_from_and_administrator
+            .eq(&other.marker_from_and_administrator)
+            && self.to.eq(&other.to)
+            && self.denom.eq(&other.denom)
+    }
+
+    fn into_message(self) -> StdResult<SubMsg> {
+        Ok(match self.marker_from_and_administrator {
+            Some((from, administrator)) => SubMsg::new(transfer_marker_coins(
+                self.amount,
+                self.denom,
+                Addr::unchecked(self.to),
+                Addr::unchecked(from),
+                Addr::unchecked(administrator),
+            )?),
+            None => SubMsg::new(BankMsg::Send {
+                to_address: self.to,
+                amount: coins(self.amount, self.denom),
+            }),
+        })
+    }
+}
+
+/// Combines transfers of the same denom to the same recipient into a single message.
+///
+/// Bank sends are combined with bank sends and marker transfers with marker transfers from the
+/// same account. The combined transfer takes the place of the first one, and all other messages
+/// keep their order.
+pub fn net_transfers(messages: Vec<SubMsg>) -> StdResult<Vec<SubMsg>> {
+    let mut netted: Vec<(SubMsg, Option<Transfer>)> = Vec::new();
+
+    for message in messages {
+        match Transfer::from_message(&message)? {
+            Some(transfer) => {
+                match netted.iter_mut().find_map(|(_, existing)| {
+                    existing
+                        .as_mut()
+                        .filter(|existing| existing.is_same_transfer(&transfer))
+                }) {
+                    Some(existing) => {
+                        existing.amount = existing
+                            .amount
+                            .checked_add(transfer.amount)
+                            .ok_or_else(|| StdError::generic_err("transfer amount overflow"))?;
+                    }
+                    None => netted.push((message, Some(transfer))),
+                }
+            }
+            None => netted.push((message, None)),
+        }
+    }
+
+    netted
+        .into_iter()
+        .map(|(message, transfer)| match transfer {
+            Some(transfer) => transfer.into_message(),
+            None => Ok(message),
+        })
+        .collect()
+}
+
 fn to_hyphenated_uuid_str(uuid: String) -> Result<String, ContractError> {
     Ok(Uuid::parse_str(uuid.as_str())
         .map_err(ContractError::UuidError)?
@@ -124,12 +391,22 @@ pub fn is_hyphenated_uuid_str(uuid: &String) -> bool {
 
 #[cfg(test)]
 mod util_tests {
+    use crate::common::AttributePolicy;
+    use crate::error::ContractError;
     use crate::util::{
-        add_transfer, is_hyphenated_uuid_str, to_hyphenated_uuid_str, transfer_marker_coins,
+        add_transfer, check_account_attributes, is_hyphenated_uuid_str, net_transfers,
+        parse_order_price, to_descending_price_key, to_hyphenated_uuid_str, to_price_key,
+        transfer_marker_coins,
     };
     use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
-    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Response};
+    use cosmwasm_std::{
+        coin, to_binary, Addr, BankMsg, Binary, ContractResult, CosmosMsg, Querier, QuerierResult,
+        QuerierWrapper, Response, SystemResult, Timestamp, Uint128,
+    };
+    use rust_decimal::Decimal;
+    use serde_json::json;
     use std::convert::TryInto;
+    use std::str::FromStr;
     const UUID_HYPHENATED: &str = "093231fc-e4b3-4fbc-a441-838787f16933";
     const UUID_NOT_HYPHENATED: &str = "093231fce4b34fbca441838787f16933";
 
@@ -244,4 +521,169 @@ mod util_tests {
             })
         )
     }
+
+    #[test]
+    fn net_transfers_combines_same_recipient_and_denom() {
+        let mut response = Response::new();
+        for (is_restricted, amount, denom, to) in [
+            (false, 100, "quote_1", "asker"),
+            (true, 50, "base_1", "bidder"),
+            (false, 100, "base_1", "asker"),
+            (false, 200, "quote_1", "asker"),
+            (true, 25, "base_1", "bidder"),
+        ] {
+            response = add_transfer(
+                response,
+                is_restricted,
+                amount,
+                denom,
+                Addr::unchecked(to),
+                Addr::unchecked(MOCK_CONTRACT_ADDR),
+                Addr::unchecked(MOCK_CONTRACT_ADDR),
+            );
+        }
+
+        let netted = net_transfers(response.messages).unwrap();
+
+        assert_eq!(
+            netted
+                .into_iter()
+                .map(|message| message.msg)
+                .collect::<Vec<CosmosMsg>>(),
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "asker".into(),
+                    amount: vec![coin(300, "quote_1")],
+                }),
+                transfer_marker_coins(
+                    75,
+                    "base_1",
+                    Addr::unchecked("bidder"),
+                    Addr::unchecked(MOCK_CONTRACT_ADDR),
+                    Addr::unchecked(MOCK_CONTRACT_ADDR),
+                )
+                .unwrap()
+                .into(),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "asker".into(),
+                    amount: vec![coin(100, "base_1")],
+                }),
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_order_price_invalid_then_return_invalid_price_field() {
+        for price in ["", "abc", "0", "-1", "1.234"] {
+            match parse_order_price(price, Uint128::new(2)) {
+                Err(ContractError::InvalidFields { fields }) => {
+                    assert_eq!(fields, vec!["price"])
+                }
+                result => panic!("unexpected result for {}: {:?}", price, result),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_order_price_valid_then_return_decimal() {
+        assert_eq!(
+            parse_order_price("1.23", Uint128::new(2)).unwrap(),
+            Decimal::from_str("1.23").unwrap()
+        );
+    }
+
+    #[test]
+    fn price_keys_sort_in_price_order() {
+        let prices: Vec<Decimal> = [
+            "0.000000000000000001",
+            "0.1",
+            "0.9",
+            "1",
+            "1.000000000000000001",
+            "2",
+            "255",
+            "256",
+            "100000000000000000000",
+        ]
+        .iter()
+        .map(|price| Decimal::from_str(price).unwrap())
+        .collect();
+
+        for pair in prices.windows(2) {
+            assert!(to_price_key(pair[0]) < to_price_key(pair[1]));
+            assert!(to_descending_price_key(pair[0]) > to_descending_price_key(pair[1]));
+        }
+
+        // equal prices with different scales share a key
+        assert_eq!(
+            to_price_key(Decimal::from_str("2").unwrap()),
+            to_price_key(Decimal::from_str("2.000").unwrap())
+        );
+    }
+
+    /// Answers every query with the same attributes query response.
+    struct AttributesQuerier(Binary);
+
+    impl Querier for AttributesQuerier {
+        fn raw_query(&self, _bin_request: &[u8]) -> QuerierResult {
+            SystemResult::Ok(ContractResult::Ok(self.0.clone()))
+        }
+    }
+
+    #[test]
+    fn check_account_attributes_ignores_expired_attributes() {
+        let querier = AttributesQuerier(
+            to_binary(&json!({
+                "account": "bidder",
+                "attributes": [
+                    {
+                        "name": "kyc",
+                        "value": Binary::from(b"passed"),
+                        "attribute_type": "ATTRIBUTE_TYPE_STRING",
+                        "address": "bidder",
+                        "expiration_date": "2023-01-01T00:00:00Z"
+                    },
+                    {
+                        "name": "jurisdiction",
+                        "value": Binary::from(b"US"),
+                        "attribute_type": "ATTRIBUTE_TYPE_STRING",
+                        "address": "bidder",
+                        "expiration_date": "2024-01-01T00:00:00Z"
+                    },
+                    {
+                        "name": "accredited",
+                        "value": Binary::from(b"yes"),
+                        "attribute_type": "ATTRIBUTE_TYPE_STRING",
+                        "address": "bidder"
+                    }
+                ],
+                "pagination": null
+            }))
+            .unwrap(),
+        );
+        let querier = QuerierWrapper::new(&querier);
+        let account = Addr::unchecked("bidder");
+        let policy = |name: &str| {
+            Some(AttributePolicy::Attribute {
+                name: name.into(),
+                values: None,
+            })
+        };
+        // 2023-06-01T00:00:00Z
+        let time = Timestamp::from_seconds(1685577600);
+
+        for name in ["jurisdiction", "accredited"] {
+            assert!(check_account_attributes(&querier, &account, time, &policy(name)).is_ok());
+        }
+        match check_account_attributes(&querier, &account, time, &policy("kyc")) {
+            Err(ContractError::AttributePolicyFailed { clause }) => {
+                assert_eq!(clause, "attribute(kyc)")
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        // an attribute counts until its expiration date
+        let time = Timestamp::from_seconds(1672531199);
+        assert!(check_account_attributes(&querier, &account, time, &policy("kyc")).is_ok());
+    }
 }
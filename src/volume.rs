@@ -0,0 +1,130 @@
+use crate::common::FeeTier;
+use crate::contract_info::ContractInfoV3;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+pub const NAMESPACE_VOLUME: &str = "volume";
+
+/// Length of a volume bucket in seconds, volume is tracked per day.
+pub const VOLUME_BUCKET_SECONDS: u64 = 86_400;
+
+/// Number of daily buckets in the rolling volume window used for fee tiers.
+pub const VOLUME_WINDOW_BUCKETS: u64 = 30;
+
+/// Traded quote volume keyed by (account, day), buckets that leave the window are removed.
+pub const VOLUMES_V1: Map<(&Addr, u64), Uint128> = Map::new(NAMESPACE_VOLUME);
+
+fn get_bucket(time: Timestamp) -> u64 {
+    time.seconds() / VOLUME_BUCKET_SECONDS
+}
+
+/// Returns the last bucket before the rolling window of the bucket, if any.
+fn get_expired_bucket(bucket: u64) -> Option<u64> {
+    bucket.checked_sub(VOLUME_WINDOW_BUCKETS)
+}
+
+/// Adds traded quote volume to an account's bucket, removing the account's buckets that have left
+/// the rolling window.
+pub fn record_volume(
+    storage: &mut dyn Storage,
+    address: &Addr,
+    time: Timestamp,
+    amount: Uint128,
+) -> StdResult<()> {
+    let bucket = get_bucket(time);
+
+    VOLUMES_V1.update(storage, (address, bucket), |volume| -> StdResult<_> {
+        Ok(volume.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    if let Some(expired_bucket) = get_expired_bucket(bucket) {
+        let expired_buckets = VOLUMES_V1
+            .prefix(address)
+            .keys(
+                storage,
+                None,
+                Some(Bound::inclusive(expired_bucket)),
+                Order::Ascending,
+            )
+            .collect::<StdResult<Vec<_>>>()?;
+
+        for expired_bucket in expired_buckets {
+            VOLUMES_V1.remove(storage, (address, expired_bucket));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the quote volume traded by an account in the rolling window ending at the given time.
+pub fn get_rolling_volume(
+    storage: &dyn Storage,
+    address: &Addr,
+    time: Timestamp,
+) -> StdResult<Uint128> {
+    VOLUMES_V1
+        .prefix(address)
+        .range(
+            storage,
+            get_expired_bucket(get_bucket(time)).map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .try_fold(Uint128::zero(), |total, item| {
+            let (_, volume) = item?;
+            Ok(total.checked_add(volume)?)
+        })
+}
+
+/// Returns the fee tier an account's rolling volume has reached, if the contract has fee tiers.
+pub fn get_volume_fee_tier(
+    storage: &dyn Storage,
+    contract_info: &ContractInfoV3,
+    address: &Addr,
+    time: Timestamp,
+) -> StdResult<Option<FeeTier>> {
+    if contract_info.fee_tiers.is_empty() {
+        return Ok(None);
+    }
+
+    let volume = get_rolling_volume(storage, address, time)?;
+
+    Ok(contract_info.get_fee_tier(volume).cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_rolling_volume, record_volume, VOLUMES_V1, VOLUME_BUCKET_SECONDS};
+    use cosmwasm_std::{Addr, Order, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn record_volume_removes_buckets_outside_window() {
+        let mut deps = mock_provenance_dependencies();
+        let address = Addr::unchecked("trader");
+        let day = |day: u64| Timestamp::from_seconds(day * VOLUME_BUCKET_SECONDS + 1);
+
+        record_volume(&mut deps.storage, &address, day(100), Uint128::new(100)).unwrap();
+        record_volume(&mut deps.storage, &address, day(100), Uint128::new(50)).unwrap();
+        record_volume(&mut deps.storage, &address, day(110), Uint128::new(200)).unwrap();
+
+        assert_eq!(
+            get_rolling_volume(&deps.storage, &address, day(129)).unwrap(),
+            Uint128::new(350)
+        );
+        // the day 100 bucket has left the window
+        assert_eq!(
+            get_rolling_volume(&deps.storage, &address, day(130)).unwrap(),
+            Uint128::new(200)
+        );
+
+        record_volume(&mut deps.storage, &address, day(130), Uint128::new(10)).unwrap();
+
+        let buckets = VOLUMES_V1
+            .prefix(&address)
+            .keys(&deps.storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(buckets, vec![110, 130]);
+    }
+}